@@ -2,7 +2,7 @@
 
 use alloy_dyn_abi::{DynSolType, DynSolValue};
 use alloy_primitives::{hex, U256};
-use alloy_sol_types::{sol, sol_data, SolType, SolValue};
+use alloy_sol_types::{sol, sol_data, SolType, SolTypeRef, SolValue};
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
@@ -66,6 +66,17 @@ fn dyn_abi_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode_sequence());
     });
 
+    g.bench_function("struct_to", |b| {
+        let input = encode_struct_sol_values();
+        let input = DynSolValue::Tuple(input.to_vec());
+        let mut buf = Vec::with_capacity(input.abi_encoded_size());
+        b.iter(|| {
+            buf.clear();
+            black_box(&input).abi_encode_sequence_to(&mut buf);
+            black_box(&buf);
+        });
+    });
+
     g.finish();
 }
 
@@ -100,6 +111,16 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode());
     });
 
+    g.bench_function("struct_to", |b| {
+        let input = encode_struct_input();
+        let mut buf = Vec::with_capacity(input.abi_encoded_size());
+        b.iter(|| {
+            buf.clear();
+            black_box(&input).abi_encode_to(&mut buf);
+            black_box(&buf);
+        });
+    });
+
     g.finish();
 }
 
@@ -116,6 +137,11 @@ fn sol_types_decode(c: &mut Criterion) {
         b.iter(|| sol_data::String::abi_decode(black_box(&input), false).unwrap());
     });
 
+    g.bench_function("dynamic_ref", |b| {
+        let input = decode_dynamic_input();
+        b.iter(|| sol_data::String::abi_decode_ref(black_box(&input), false).unwrap());
+    });
+
     g.finish();
 }
 
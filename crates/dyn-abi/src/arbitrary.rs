@@ -132,6 +132,8 @@ enum Choice {
     Bool,
     Int,
     Uint,
+    Fixed,
+    Ufixed,
     Address,
     Function,
     FixedBytes,
@@ -151,6 +153,8 @@ impl<'a> arbitrary::Arbitrary<'a> for DynSolType {
             Choice::Bool => Ok(Self::Bool),
             Choice::Int => u.arbitrary().map(int_size).map(Self::Int),
             Choice::Uint => u.arbitrary().map(int_size).map(Self::Uint),
+            Choice::Fixed => Ok(Self::Fixed(int_size(u.arbitrary()?), u.int_in_range(0..=80)?)),
+            Choice::Ufixed => Ok(Self::Ufixed(int_size(u.arbitrary()?), u.int_in_range(0..=80)?)),
             Choice::Address => Ok(Self::Address),
             Choice::Function => Ok(Self::Function),
             Choice::FixedBytes => Ok(Self::FixedBytes(u.int_in_range(1..=32)?)),
@@ -316,6 +320,8 @@ impl DynSolType {
             Just(Self::Address),
             any::<usize>().prop_map(|x| Self::Int(int_size(x))),
             any::<usize>().prop_map(|x| Self::Uint(int_size(x))),
+            (any::<usize>(), 0..=80usize).prop_map(|(x, d)| Self::Fixed(int_size(x), d)),
+            (any::<usize>(), 0..=80usize).prop_map(|(x, d)| Self::Ufixed(int_size(x), d)),
             (1..=32usize).prop_map(Self::FixedBytes),
             Just(Self::Bytes),
             Just(Self::String),
@@ -362,6 +368,12 @@ impl DynSolValue {
             DynSolType::Function => u.arbitrary().map(Self::Function),
             &DynSolType::Int(sz) => u.arbitrary().map(|x| Self::Int(adjust_int(x, sz), sz)),
             &DynSolType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(adjust_uint(x, sz), sz)),
+            &DynSolType::Fixed(sz, d) => {
+                u.arbitrary().map(|x| Self::Fixed(adjust_int(x, sz), sz, d))
+            }
+            &DynSolType::Ufixed(sz, d) => {
+                u.arbitrary().map(|x| Self::Ufixed(adjust_uint(x, sz), sz, d))
+            }
             &DynSolType::FixedBytes(sz) => {
                 u.arbitrary().map(|x| Self::FixedBytes(adjust_fb(x, sz), sz))
             }
@@ -416,6 +428,12 @@ impl DynSolValue {
             &DynSolType::Uint(sz) => {
                 any::<U256>().prop_map(move |x| Self::Uint(adjust_uint(x, sz), sz)).sboxed()
             }
+            &DynSolType::Fixed(sz, d) => {
+                any::<I256>().prop_map(move |x| Self::Fixed(adjust_int(x, sz), sz, d)).sboxed()
+            }
+            &DynSolType::Ufixed(sz, d) => {
+                any::<U256>().prop_map(move |x| Self::Ufixed(adjust_uint(x, sz), sz, d)).sboxed()
+            }
             &DynSolType::FixedBytes(sz) => {
                 any::<B256>().prop_map(move |x| Self::FixedBytes(adjust_fb(x, sz), sz)).sboxed()
             }
@@ -705,11 +723,11 @@ mod tests {
         }
 
         match value {
-            DynSolValue::Int(int, size) => {
+            DynSolValue::Int(int, size) | DynSolValue::Fixed(int, size, _) => {
                 let bits = int.into_sign_and_abs().1.bit_len();
                 prop_assert!(bits <= *size, "int: {int}, {size}, {bits}")
             }
-            DynSolValue::Uint(uint, size) => {
+            DynSolValue::Uint(uint, size) | DynSolValue::Ufixed(uint, size, _) => {
                 let bits = uint.bit_len();
                 prop_assert!(bits <= *size, "uint: {uint}, {size}, {bits}")
             }
@@ -29,6 +29,9 @@ impl DynSolType {
     ///     `0b`, `0o`, or `0x` respectively.
     ///   - unit: same as [Solidity ether units](https://docs.soliditylang.org/en/latest/units-and-global-variables.html#ether-units)
     ///   - decimals with more digits than the unit's exponent value are not allowed
+    /// - [`Fixed`](DynSolType::Fixed): `[+-]?{Ufixed}`
+    /// - [`Ufixed`](DynSolType::Ufixed): `[0-9]+(\.[0-9]+)?`
+    ///   - decimals with more non-zero digits than the type's decimals are not allowed
     /// - [`FixedBytes`](DynSolType::FixedBytes): `(0x)?[0-9A-Fa-f]{$0*2}`
     /// - [`Address`](DynSolType::Address): `(0x)?[0-9A-Fa-f]{40}`
     /// - [`Function`](DynSolType::Function): `(0x)?[0-9A-Fa-f]{48}`
@@ -99,6 +102,12 @@ impl<'i> Parser<&'i str, DynSolValue, ContextError> for ValueParser<'_> {
             &DynSolType::Uint(size) => {
                 uint(size).parse_next(input).map(|uint| DynSolValue::Uint(uint, size))
             }
+            &DynSolType::Fixed(size, decimals) => fixed(size, decimals)
+                .parse_next(input)
+                .map(|int| DynSolValue::Fixed(int, size, decimals)),
+            &DynSolType::Ufixed(size, decimals) => ufixed(size, decimals)
+                .parse_next(input)
+                .map(|uint| DynSolValue::Ufixed(uint, size, decimals)),
             &DynSolType::FixedBytes(size) => {
                 fixed_bytes(size).parse_next(input).map(|word| DynSolValue::FixedBytes(word, size))
             }
@@ -392,6 +401,76 @@ fn uint<'i>(len: usize) -> impl Parser<&'i str, U256, ContextError> {
     })
 }
 
+#[inline]
+fn fixed<'i>(size: usize, decimals: usize) -> impl Parser<&'i str, I256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("fixed{size}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "fixed";
+    trace(
+        name,
+        (int_sign, ufixed(size, decimals)).try_map(move |(sign, abs)| {
+            // the magnitude of a negative number may be one greater
+            let max = if sign.is_negative() { abs.saturating_sub(U256::from(1)) } else { abs };
+            if max.bit_len() > size - 1 {
+                return Err(Error::IntOverflow);
+            }
+            I256::checked_from_sign_and_abs(sign, abs).ok_or(Error::IntOverflow)
+        }),
+    )
+}
+
+#[inline]
+fn ufixed<'i>(size: usize, decimals: usize) -> impl Parser<&'i str, U256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("ufixed{size}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "ufixed";
+    trace(name, move |input: &mut &str| {
+        let (intpart, fract) = (
+            digit1,
+            opt(preceded(
+                '.',
+                cut_err(digit1.context(StrContext::Expected(StrContextValue::Description(
+                    "at least one digit",
+                )))),
+            )),
+        )
+            .parse_next(input)?;
+
+        // trailing zeros do not lose any precision
+        let fract = fract.map_or("", |f| f.trim_end_matches('0'));
+        if fract.len() > decimals {
+            return Err(ErrMode::from_external_error(
+                input,
+                ErrorKind::Verify,
+                Error::TooManyDecimals(decimals, fract.len()),
+            ));
+        }
+
+        // intpart * 10^decimals + fract * 10^(decimals-fract.len())
+        let pow10 = |exp: usize| U256::from(10).checked_pow(U256::from(exp));
+        let fract_uint = if fract.is_empty() {
+            Some(U256::ZERO)
+        } else {
+            U256::from_str_radix(fract, 10)
+                .ok()
+                .and_then(|f| f.checked_mul(pow10(decimals - fract.len())?))
+        };
+        let uint = U256::from_str_radix(intpart, 10)
+            .ok()
+            .and_then(|i| if i.is_zero() { Some(i) } else { i.checked_mul(pow10(decimals)?) })
+            .zip(fract_uint)
+            .and_then(|(i, f)| i.checked_add(f))
+            .filter(|u| u.bit_len() <= size)
+            .ok_or_else(|| {
+                ErrMode::from_external_error(input, ErrorKind::Verify, Error::IntOverflow)
+            })?;
+
+        Ok(uint)
+    })
+}
+
 #[inline]
 fn prefixed_int<'i>(input: &mut &'i str) -> PResult<&'i str> {
     trace("prefixed_int", |input: &mut &'i str| {
@@ -524,6 +603,59 @@ mod tests {
         assert!(DynSolType::Bool.coerce_str("tru").is_err());
     }
 
+    #[test]
+    fn coerce_fixed() {
+        let raw = |s: &str| I256::from_dec_str(s).unwrap();
+        assert_eq!(
+            DynSolType::Fixed(128, 18).coerce_str("1.25").unwrap(),
+            DynSolValue::Fixed(raw("1250000000000000000"), 128, 18)
+        );
+        assert_eq!(
+            DynSolType::Fixed(128, 18).coerce_str("-1.25").unwrap(),
+            DynSolValue::Fixed(raw("-1250000000000000000"), 128, 18)
+        );
+        assert_eq!(
+            DynSolType::Fixed(8, 1).coerce_str("+12.7").unwrap(),
+            DynSolValue::Fixed(raw("127"), 8, 1)
+        );
+        assert_eq!(
+            DynSolType::Fixed(8, 1).coerce_str("-12.8").unwrap(),
+            DynSolValue::Fixed(raw("-128"), 8, 1)
+        );
+        assert_eq!(
+            DynSolType::Fixed(16, 2).coerce_str("3.100").unwrap(),
+            DynSolValue::Fixed(raw("310"), 16, 2)
+        );
+        assert!(DynSolType::Fixed(8, 1).coerce_str("12.8").is_err());
+        assert!(DynSolType::Fixed(8, 1).coerce_str("-12.9").is_err());
+
+        let e = DynSolType::Fixed(16, 2).coerce_str("0.125").unwrap_err();
+        assert_error_contains(&e, &Error::TooManyDecimals(2, 3).to_string());
+        assert!(DynSolType::Fixed(16, 2).coerce_str("1.").is_err());
+        assert!(DynSolType::Fixed(16, 2).coerce_str(".1").is_err());
+        assert!(DynSolType::Fixed(16, 2).coerce_str("1 ether").is_err());
+    }
+
+    #[test]
+    fn coerce_ufixed() {
+        assert_eq!(
+            DynSolType::Ufixed(128, 18).coerce_str("1.25").unwrap(),
+            DynSolValue::Ufixed(U256::from(1_250_000_000_000_000_000u64), 128, 18)
+        );
+        assert_eq!(
+            DynSolType::Ufixed(8, 0).coerce_str("255").unwrap(),
+            DynSolValue::Ufixed(U256::from(255), 8, 0)
+        );
+        assert_eq!(
+            DynSolType::Ufixed(256, 80).coerce_str("0.00000000000000000000000000000000000000000000000000000000000000000000000000000001").unwrap(),
+            DynSolValue::Ufixed(U256::from(1), 256, 80)
+        );
+        assert!(DynSolType::Ufixed(8, 0).coerce_str("256").is_err());
+        assert!(DynSolType::Ufixed(8, 0).coerce_str("0.5").is_err());
+        assert!(DynSolType::Ufixed(256, 80).coerce_str("1").is_err());
+        assert!(DynSolType::Ufixed(128, 18).coerce_str("-1.25").is_err());
+    }
+
     #[test]
     fn coerce_int() {
         assert_eq!(
@@ -2,6 +2,7 @@ use crate::{Decoder, DynSolValue, Error, Result, Word};
 use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use alloy_primitives::try_vec;
 use alloy_sol_types::abi::token::{PackedSeqToken, Token, WordToken};
+use alloy_sol_types::PathSegment;
 
 /// A dynamic token.
 ///
@@ -178,6 +179,8 @@ impl<'a> DynToken<'a> {
                     return Err(alloy_sol_types::Error::Overrun.into());
                 }
 
+                child.check_seq_len(size, core::mem::size_of::<DynToken<'_>>())?;
+
                 let mut new_tokens = if size == 1 {
                     // re-use the box allocation
                     unsafe { Vec::from_raw_parts(Box::into_raw(template), 1, 1) }
@@ -185,8 +188,10 @@ impl<'a> DynToken<'a> {
                     try_vec![*template; size]?
                 };
 
-                for t in &mut new_tokens {
-                    t.decode_populate(&mut child)?;
+                for (i, t) in new_tokens.iter_mut().enumerate() {
+                    let offset = child.absolute_offset();
+                    t.decode_populate(&mut child)
+                        .map_err(|e| e.at(PathSegment::Index(i), Some(offset)))?;
                 }
 
                 *contents = new_tokens.into();
@@ -202,7 +207,11 @@ impl<'a> DynToken<'a> {
     pub(crate) fn decode_sequence_populate(&mut self, dec: &mut Decoder<'a>) -> Result<()> {
         match self {
             Self::FixedSeq(buf, size) => {
-                buf.to_mut().iter_mut().take(*size).try_for_each(|item| item.decode_populate(dec))
+                buf.to_mut().iter_mut().take(*size).enumerate().try_for_each(|(i, item)| {
+                    let offset = dec.absolute_offset();
+                    item.decode_populate(dec)
+                        .map_err(|e| e.at(PathSegment::Member(i), Some(offset)))
+                })
             }
             Self::DynSeq { .. } => self.decode_populate(dec),
             _ => Err(Error::custom("Called decode_sequence_populate on non-sequence token")),
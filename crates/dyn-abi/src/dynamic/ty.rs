@@ -3,8 +3,12 @@ use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use alloy_primitives::{
     try_vec,
     utils::{box_try_new, vec_try_with_capacity},
+    Address, Function, I256, U256,
+};
+use alloy_sol_types::{
+    abi::{DecodeLimits, DecodeState, Decoder},
+    sol_data, PathSegment,
 };
-use alloy_sol_types::{abi::Decoder, sol_data};
 use core::{fmt, iter::zip, num::NonZeroUsize, str::FromStr};
 use parser::TypeSpecifier;
 
@@ -84,6 +88,12 @@ pub enum DynSolType {
     Int(usize),
     /// Unsigned Integer.
     Uint(usize),
+    /// Signed fixed-point decimal number, with its size in bits and number of
+    /// decimals.
+    Fixed(usize, usize),
+    /// Unsigned fixed-point decimal number, with its size in bits and number
+    /// of decimals.
+    Ufixed(usize, usize),
     /// Fixed-size bytes, up to 32.
     FixedBytes(usize),
     /// Address.
@@ -161,6 +171,8 @@ impl DynSolType {
             DynSolType::Bool
             | DynSolType::Int(_)
             | DynSolType::Uint(_)
+            | DynSolType::Fixed(..)
+            | DynSolType::Ufixed(..)
             | DynSolType::FixedBytes(_)
             | DynSolType::Address
             | DynSolType::Function
@@ -231,6 +243,12 @@ impl DynSolType {
             Self::Bool => matches!(value, DynSolValue::Bool(_)),
             Self::Int(size) => matches!(value, DynSolValue::Int(_, s) if s == size),
             Self::Uint(size) => matches!(value, DynSolValue::Uint(_, s) if s == size),
+            Self::Fixed(size, decimals) => {
+                matches!(value, DynSolValue::Fixed(_, s, d) if s == size && d == decimals)
+            }
+            Self::Ufixed(size, decimals) => {
+                matches!(value, DynSolValue::Ufixed(_, s, d) if s == size && d == decimals)
+            }
             Self::FixedBytes(size) => matches!(value, DynSolValue::FixedBytes(_, s) if s == size),
             Self::Address => matches!(value, DynSolValue::Address(_)),
             Self::Function => matches!(value, DynSolValue::Function(_)),
@@ -281,6 +299,18 @@ impl DynSolType {
                 Ok(DynSolValue::Uint(sol_data::Uint::<256>::detokenize(word.into()), *size))
             }
 
+            (Self::Fixed(size, decimals), DynToken::Word(word)) => Ok(DynSolValue::Fixed(
+                sol_data::Int::<256>::detokenize(word.into()),
+                *size,
+                *decimals,
+            )),
+
+            (Self::Ufixed(size, decimals), DynToken::Word(word)) => Ok(DynSolValue::Ufixed(
+                sol_data::Uint::<256>::detokenize(word.into()),
+                *size,
+                *decimals,
+            )),
+
             (Self::FixedBytes(size), DynToken::Word(word)) => Ok(DynSolValue::FixedBytes(
                 sol_data::FixedBytes::<32>::detokenize(word.into()),
                 *size,
@@ -390,6 +420,16 @@ impl DynSolType {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(size, decimals) | Self::Ufixed(size, decimals) => {
+                if let Self::Ufixed(..) = self {
+                    out.push('u');
+                }
+                out.push_str("fixed");
+                out.push_str(itoa::Buffer::new().format(*size));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
+
             as_tuple!(Self tuple) => {
                 out.push('(');
                 for (i, val) in tuple.iter().enumerate() {
@@ -433,6 +473,10 @@ impl DynSolType {
             | Self::Uint(_) // 4 + 3
             => 8,
 
+            | Self::Fixed(..) // 5 + 3 + 1 + 2
+            | Self::Ufixed(..) // 6 + 3 + 1 + 2
+            => 12,
+
             | Self::Array(t) // t + 2
             | Self::FixedArray(t, _) // t + 2 + log10(len)
             => t.sol_type_name_capacity() + 8,
@@ -478,7 +522,9 @@ impl DynSolType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => DynToken::Word(Word::ZERO),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => DynToken::Word(Word::ZERO),
 
             Self::Bytes | Self::String => DynToken::PackedSeq(&[]),
 
@@ -507,7 +553,9 @@ impl DynSolType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => self.detokenize(DynToken::Word(topic)).unwrap(),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => self.detokenize(DynToken::Word(topic)).unwrap(),
             _ => DynSolValue::FixedBytes(topic, 32),
         }
     }
@@ -523,6 +571,38 @@ impl DynSolType {
         self.abi_decode_inner(&mut Decoder::new(data, false), DynToken::decode_single_populate)
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice, enforcing the given resource
+    /// limits. Fails if the value does not match this type, or if any of the
+    /// limits is exceeded.
+    ///
+    /// See [`abi_decode`](Self::abi_decode) and [`DecodeLimits`] for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DecodeLimits, DynSolType, DynSolValue};
+    ///
+    /// let ty: DynSolType = "uint8[][]".parse()?;
+    /// let inner = DynSolValue::Array(vec![DynSolValue::Uint(Default::default(), 8); 2]);
+    /// let data = DynSolValue::Array(vec![inner.clone(), inner]).abi_encode();
+    ///
+    /// let limits = DecodeLimits::new().with_max_depth(8).with_max_offset_reuse(0);
+    /// assert!(ty.abi_decode_with_limits(&data, limits).is_ok());
+    /// assert!(ty.abi_decode_with_limits(&data, limits.with_max_array_len(1)).is_err());
+    /// assert!(ty.abi_decode_with_limits(&data, limits.with_max_depth(2)).is_err());
+    /// # Ok::<(), alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_with_limits(&self, data: &[u8], limits: DecodeLimits) -> Result<DynSolValue> {
+        let state = DecodeState::new(limits);
+        self.abi_decode_inner(
+            &mut Decoder::with_state(data, false, &state),
+            DynToken::decode_single_populate,
+        )
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
     ///
@@ -552,6 +632,24 @@ impl DynSolType {
         }
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice as function arguments,
+    /// enforcing the given resource limits.
+    ///
+    /// See [`abi_decode_params`](Self::abi_decode_params) and [`DecodeLimits`]
+    /// for more information.
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_params_with_limits(
+        &self,
+        data: &[u8],
+        limits: DecodeLimits,
+    ) -> Result<DynSolValue> {
+        match self {
+            Self::Tuple(_) => self.abi_decode_sequence_with_limits(data, limits),
+            _ => self.abi_decode_with_limits(data, limits),
+        }
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
     #[inline]
@@ -560,6 +658,171 @@ impl DynSolType {
         self.abi_decode_inner(&mut Decoder::new(data, false), DynToken::decode_sequence_populate)
     }
 
+    /// Decode a [`DynSolValue`] from a byte slice as a sequence, enforcing the
+    /// given resource limits.
+    ///
+    /// See [`abi_decode_sequence`](Self::abi_decode_sequence) and
+    /// [`DecodeLimits`] for more information.
+    #[inline]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn abi_decode_sequence_with_limits(
+        &self,
+        data: &[u8],
+        limits: DecodeLimits,
+    ) -> Result<DynSolValue> {
+        let state = DecodeState::new(limits);
+        self.abi_decode_inner(
+            &mut Decoder::with_state(data, false, &state),
+            DynToken::decode_sequence_populate,
+        )
+    }
+
+    /// Decode a [`DynSolValue`] from a byte slice encoded in non-standard
+    /// packed mode.
+    ///
+    /// This is the inverse of [`DynSolValue::abi_encode_packed`]. Since the
+    /// packed encoding does not contain lengths, a value can only be decoded
+    /// if all of its members have a statically-known
+    /// [packed size](Self::packed_encoded_size), except for the very last one.
+    /// Otherwise, this returns
+    /// [`AmbiguousPackedLayout`](alloy_sol_types::Error::AmbiguousPackedLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    ///
+    /// // A Uniswap V3 path with a single hop.
+    /// let ty: DynSolType = "(address,uint24,address)".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     Address::repeat_byte(0x11).into(),
+    ///     DynSolValue::Uint(U256::from(3000), 24),
+    ///     Address::repeat_byte(0x22).into(),
+    /// ]);
+    /// let packed = value.abi_encode_packed();
+    /// assert_eq!(packed.len(), 43);
+    /// assert_eq!(ty.abi_decode_packed(&packed)?, value);
+    ///
+    /// let ty: DynSolType = "(bytes,address)".parse()?;
+    /// assert!(ty.abi_decode_packed(&packed).is_err());
+    /// # Ok::<(), alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn abi_decode_packed(&self, mut data: &[u8]) -> Result<DynSolValue> {
+        let value = self.abi_decode_packed_from(&mut data)?;
+        if data.is_empty() {
+            Ok(value)
+        } else {
+            Err(alloy_sol_types::Error::BufferNotEmpty.into())
+        }
+    }
+
+    /// Decode a packed value from the front of `data`, advancing it past the
+    /// decoded value.
+    fn abi_decode_packed_from(&self, data: &mut &[u8]) -> Result<DynSolValue> {
+        let value = match self {
+            Self::Bool => DynSolValue::Bool(take_packed(data, 1)?[0] != 0),
+            Self::Int(size) => DynSolValue::Int(packed_int(take_packed(data, size / 8)?), *size),
+            Self::Uint(size) => DynSolValue::Uint(packed_uint(take_packed(data, size / 8)?), *size),
+            Self::Fixed(size, decimals) => {
+                DynSolValue::Fixed(packed_int(take_packed(data, size / 8)?), *size, *decimals)
+            }
+            Self::Ufixed(size, decimals) => {
+                DynSolValue::Ufixed(packed_uint(take_packed(data, size / 8)?), *size, *decimals)
+            }
+            Self::FixedBytes(size) => {
+                let mut word = Word::ZERO;
+                word[..*size].copy_from_slice(take_packed(data, *size)?);
+                DynSolValue::FixedBytes(word, *size)
+            }
+            Self::Address => DynSolValue::Address(Address::from_slice(take_packed(data, 20)?)),
+            Self::Function => DynSolValue::Function(Function::from_slice(take_packed(data, 24)?)),
+            Self::Bytes => DynSolValue::Bytes(core::mem::take(data).to_vec()),
+            Self::String => DynSolValue::String(sol_data::String::abi_decode_packed_from(data)?),
+            Self::Array(inner) => {
+                let size = match inner.packed_encoded_size() {
+                    Some(size) if size > 0 => size,
+                    _ => return Err(self.ambiguous_packed_layout()),
+                };
+                let mut values = vec_try_with_capacity(data.len() / size.max(32))?;
+                while !data.is_empty() {
+                    values.push(inner.abi_decode_packed_element(data, size)?);
+                }
+                DynSolValue::Array(values)
+            }
+            Self::FixedArray(inner, len) => {
+                let Some(size) = inner.packed_encoded_size() else {
+                    return Err(self.ambiguous_packed_layout());
+                };
+                let mut values = vec_try_with_capacity(*len)?;
+                for _ in 0..*len {
+                    values.push(inner.abi_decode_packed_element(data, size)?);
+                }
+                DynSolValue::FixedArray(values)
+            }
+            as_tuple!(Self tuple) => {
+                // only the last member may have a dynamic size
+                if let Some((_, init)) = tuple.split_last() {
+                    if init.iter().any(|ty| ty.packed_encoded_size().is_none()) {
+                        return Err(self.ambiguous_packed_layout());
+                    }
+                }
+                let mut values = vec_try_with_capacity(tuple.len())?;
+                for ty in tuple {
+                    values.push(ty.abi_decode_packed_from(data)?);
+                }
+                match self {
+                    #[cfg(feature = "eip712")]
+                    Self::CustomStruct { name, prop_names, .. } => DynSolValue::CustomStruct {
+                        name: name.clone(),
+                        prop_names: prop_names.clone(),
+                        tuple: values,
+                    },
+                    _ => DynSolValue::Tuple(values),
+                }
+            }
+        };
+        Ok(value)
+    }
+
+    /// Decode a packed array element of the given packed size. Elements are
+    /// padded to 32 bytes: on the right for fixed bytes and functions, and on
+    /// the left otherwise.
+    fn abi_decode_packed_element(&self, data: &mut &[u8], size: usize) -> Result<DynSolValue> {
+        let slot = take_packed(data, size.max(32))?;
+        match self {
+            Self::FixedBytes(_) | Self::Function => self.abi_decode_packed(&slot[..size]),
+            _ => self.abi_decode_packed(&slot[slot.len() - size..]),
+        }
+    }
+
+    /// Returns the size of the packed encoding of this type, or `None` if it
+    /// depends on the value.
+    ///
+    /// See [`DynSolValue::abi_encode_packed`] for more details.
+    pub fn packed_encoded_size(&self) -> Option<usize> {
+        match self {
+            Self::Bool => Some(1),
+            Self::Int(size) | Self::Uint(size) | Self::Fixed(size, _) | Self::Ufixed(size, _) => {
+                Some(size / 8)
+            }
+            Self::FixedBytes(size) => Some(*size),
+            Self::Address => Some(20),
+            Self::Function => Some(24),
+            Self::Bytes | Self::String | Self::Array(_) => None,
+            // array elements are padded to 32 bytes
+            Self::FixedArray(inner, len) => {
+                inner.packed_encoded_size().map(|size| size.max(32) * len)
+            }
+            as_tuple!(Self tuple) => tuple.iter().map(Self::packed_encoded_size).sum(),
+        }
+    }
+
+    #[cold]
+    fn ambiguous_packed_layout(&self) -> Error {
+        alloy_sol_types::Error::AmbiguousPackedLayout(self.sol_type_name()).into()
+    }
+
     /// Calculate the minimum number of ABI words necessary to encode this
     /// type.
     pub fn minimum_words(&self) -> usize {
@@ -568,6 +831,8 @@ impl DynSolType {
             DynSolType::Bool |
             DynSolType::Int(_) |
             DynSolType::Uint(_) |
+            DynSolType::Fixed(..) |
+            DynSolType::Ufixed(..) |
             DynSolType::FixedBytes(_) |
             DynSolType::Address |
             DynSolType::Function |
@@ -583,6 +848,35 @@ impl DynSolType {
         }
     }
 
+    /// Replaces the positional [`PathSegment::Member`]s of fixed-size arrays
+    /// and structs in the path of a decoding error, relative to this type,
+    /// with indices and property names respectively.
+    pub(crate) fn name_decode_path(&self, path: &mut [PathSegment]) {
+        let Some((segment, rest)) = path.split_first_mut() else { return };
+        match (self, &*segment) {
+            (Self::Array(ty), PathSegment::Index(_)) => ty.name_decode_path(rest),
+            (Self::FixedArray(ty, _), &PathSegment::Member(i)) => {
+                *segment = PathSegment::Index(i);
+                ty.name_decode_path(rest);
+            }
+            (Self::Tuple(tuple), &PathSegment::Member(i)) => {
+                if let Some(ty) = tuple.get(i) {
+                    ty.name_decode_path(rest);
+                }
+            }
+            #[cfg(feature = "eip712")]
+            (Self::CustomStruct { prop_names, tuple, .. }, &PathSegment::Member(i)) => {
+                if let Some(ty) = tuple.get(i) {
+                    ty.name_decode_path(rest);
+                }
+                if let Some(name) = prop_names.get(i) {
+                    *segment = PathSegment::Field(name.clone().into());
+                }
+            }
+            _ => {}
+        }
+    }
+
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub(crate) fn abi_decode_inner<'d, F>(
@@ -602,7 +896,7 @@ impl DynSolType {
         }
 
         let mut token = self.empty_dyn_token()?;
-        f(&mut token, decoder)?;
+        f(&mut token, decoder).map_err(|e| e.map_path(|path| self.name_decode_path(path)))?;
         let value = self.detokenize(token).expect("invalid empty_dyn_token");
         debug_assert!(
             self.matches(&value),
@@ -651,10 +945,46 @@ impl DynSolType {
     }
 }
 
+/// Splits `len` bytes off the front of packed data.
+#[inline]
+fn take_packed<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if data.len() < len {
+        return Err(alloy_sol_types::Error::Overrun.into());
+    }
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+    Ok(bytes)
+}
+
+/// Sign-extends a packed big-endian integer.
+#[inline]
+fn packed_int(bytes: &[u8]) -> I256 {
+    let negative = matches!(bytes.first(), Some(byte) if byte & 0x80 != 0);
+    let mut word = [negative as u8 * 0xff; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    I256::from_be_bytes(word)
+}
+
+/// Zero-extends a packed big-endian integer.
+#[inline]
+fn packed_uint(bytes: &[u8]) -> U256 {
+    let mut word = [0; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    U256::from_be_bytes(word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, Address};
+    use alloy_primitives::{hex, Address, FixedBytes};
+
+    /// Strips the location from a decoding error.
+    fn root_cause(e: Error) -> Error {
+        match e {
+            Error::SolTypes(e) => Error::SolTypes(e.root_cause().clone()),
+            e => e,
+        }
+    }
 
     #[test]
     fn dynamically_encodes() {
@@ -713,6 +1043,12 @@ mod tests {
         assert_eq!(value.total_words() * 32, len, "dyn_tuple={}", len != encoded.len());
 
         let re_encoded = value.abi_encode_params();
+        let mut re_encoded_to = Vec::new();
+        value.abi_encode_params_to(&mut re_encoded_to);
+        assert_eq!(re_encoded_to, re_encoded);
+        let mut re_encoded_to = vec![0u8; value.abi_encoded_size()];
+        value.abi_encode_to(&mut &mut re_encoded_to[..]);
+        assert_eq!(re_encoded_to, value.abi_encode());
         assert!(
             re_encoded == encoded,
             "
@@ -1040,7 +1376,7 @@ re-enc: {re_enc}
 
         // Used to eat 60 gb of memory and then crash.
         let my_type: DynSolType = "uint256[][][][][][][][][][]".parse().unwrap();
-        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap());
+        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap()).map_err(root_cause);
         assert_eq!(decoded, Err(alloy_sol_types::Error::RecursionLimitExceeded(16).into()));
 
         // https://github.com/paulmillr/micro-eth-signer/discussions/20
@@ -1049,11 +1385,11 @@ re-enc: {re_enc}
              0000000000000000000000000000000000000000000000000000000000000020"
             .repeat(64);
         let my_type: DynSolType = "uint256[][][][][][][][][][]".parse().unwrap();
-        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap());
+        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap()).map_err(root_cause);
         assert_eq!(decoded, Err(alloy_sol_types::Error::RecursionLimitExceeded(16).into()));
 
         let my_type: DynSolType = "bytes[][][][][][][][][][]".parse().unwrap();
-        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap());
+        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap()).map_err(root_cause);
         assert_eq!(decoded, Err(alloy_sol_types::Error::RecursionLimitExceeded(16).into()));
     }
 
@@ -1064,10 +1400,31 @@ re-enc: {re_enc}
 
         // Used to eat 60 gb of memory.
         let my_type: DynSolType = "uint32[1][]".parse().unwrap();
-        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap());
+        let decoded = my_type.abi_decode(&hex::decode(payload).unwrap()).map_err(root_cause);
         assert_eq!(decoded, Err(alloy_sol_types::Error::Overrun.into()))
     }
 
+    #[test]
+    fn fixed_point() {
+        let ty: DynSolType = "(fixed128x18,ufixed8x1[])".parse().unwrap();
+        let value = ty.coerce_str("(-1.25, [0.5, 25.5])").unwrap();
+        assert_eq!(value.sol_type_name().unwrap(), "(fixed128x18,ufixed8x1[])");
+        assert!(value.matches(&ty));
+
+        let encoded = value.abi_encode_params();
+        assert_eq!(
+            hex::encode(&encoded),
+            concat!(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffeea71b9f6ec30000",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000005",
+                "00000000000000000000000000000000000000000000000000000000000000ff",
+            )
+        );
+        assert_eq!(ty.abi_decode_params(&encoded).unwrap(), value);
+    }
+
     #[test]
     fn fixed_array_dos() {
         let t = "uint32[9999999999]".parse::<DynSolType>().unwrap();
@@ -1115,6 +1472,149 @@ expect: {expected}",
             packed = hex::encode(packed),
             expected = hex::encode(expected),
         );
+
+        match ty.abi_decode_packed(expected) {
+            Ok(decoded) => assert_eq!(decoded, value, "packed decoding failed"),
+            Err(e) => {
+                assert!(ty.packed_encoded_size().is_none(), "packed decoding failed: {e}");
+                assert!(
+                    matches!(e, Error::SolTypes(alloy_sol_types::Error::AmbiguousPackedLayout(_))),
+                    "{e}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decode_limits() {
+        let ty: DynSolType = "uint256[][]".parse().unwrap();
+        // all three elements point to the same inner array
+        let encoded = hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000020
+            0000000000000000000000000000000000000000000000000000000000000003
+            0000000000000000000000000000000000000000000000000000000000000060
+            0000000000000000000000000000000000000000000000000000000000000060
+            0000000000000000000000000000000000000000000000000000000000000060
+            0000000000000000000000000000000000000000000000000000000000000001
+            0000000000000000000000000000000000000000000000000000000000000007
+            "
+        );
+        let inner = DynSolValue::Array(vec![DynSolValue::Uint(U256::from(7), 256)]);
+        let expected = DynSolValue::Array(vec![inner; 3]);
+        let limit_err = |e| Err(Error::SolTypes(e));
+
+        assert_eq!(ty.abi_decode(&encoded).unwrap(), expected);
+        assert_eq!(ty.abi_decode_with_limits(&encoded, DecodeLimits::new()).unwrap(), expected);
+
+        let limits = DecodeLimits::new().with_max_offset_reuse(2);
+        assert_eq!(ty.abi_decode_with_limits(&encoded, limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_offset_reuse(1);
+        assert_eq!(
+            ty.abi_decode_with_limits(&encoded, limits).map_err(root_cause),
+            limit_err(alloy_sol_types::Error::OffsetReuseLimitExceeded(1))
+        );
+
+        let limits = DecodeLimits::new().with_max_array_len(2);
+        assert_eq!(
+            ty.abi_decode_with_limits(&encoded, limits).map_err(root_cause),
+            limit_err(alloy_sol_types::Error::ArrayLengthLimitExceeded(2))
+        );
+
+        let limits = DecodeLimits::new().with_max_depth(3);
+        assert_eq!(
+            ty.abi_decode_params_with_limits(&encoded, limits).map_err(root_cause),
+            limit_err(alloy_sol_types::Error::RecursionLimitExceeded(3))
+        );
+
+        let size = 6 * core::mem::size_of::<DynToken<'_>>();
+        let limits = DecodeLimits::new().with_max_allocation(size);
+        assert_eq!(ty.abi_decode_with_limits(&encoded, limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_allocation(size - 1);
+        assert_eq!(
+            ty.abi_decode_with_limits(&encoded, limits).map_err(root_cause),
+            limit_err(alloy_sol_types::Error::AllocationLimitExceeded(size - 1))
+        );
+
+        let ty = DynSolType::Tuple(vec![ty]);
+        let limits = DecodeLimits::new().with_max_offset_reuse(0);
+        assert!(ty.abi_decode_sequence_with_limits(&encoded, limits).is_err());
+    }
+
+    #[test]
+    fn decode_error_paths() {
+        let ty: DynSolType = "(uint8,bytes[2])[]".parse().unwrap();
+        let value = ty.coerce_str("[(1, [0x12, 0x34]), (2, [0x56, 0x78])]").unwrap();
+        let encoded = value.abi_encode();
+        assert_eq!(ty.abi_decode(&encoded).unwrap(), value);
+
+        let Error::SolTypes(e) = ty.abi_decode(&encoded[..encoded.len() - 32]).unwrap_err() else {
+            panic!("expected a decoding error")
+        };
+        assert_eq!(e.path().unwrap().to_string(), "[1].1[1]");
+        // the head of the last `bytes`, whose data is missing
+        assert_eq!(e.offset(), Some(480));
+        assert_eq!(*e.root_cause(), alloy_sol_types::Error::Overrun);
+    }
+
+    #[test]
+    fn decode_packed() {
+        let ty: DynSolType = "(address,uint24,int8,bytes)".parse().unwrap();
+        let value =
+            ty.coerce_str("(1111111111111111111111111111111111111111, 500, -1, 0x1234)").unwrap();
+        let packed = value.abi_encode_packed();
+        assert_eq!(packed.len(), 20 + 3 + 1 + 2);
+        assert_eq!(ty.abi_decode_packed(&packed).unwrap(), value);
+        assert_eq!(
+            ty.abi_decode_packed(&packed[..23]).unwrap_err(),
+            alloy_sol_types::Error::Overrun.into()
+        );
+
+        let ty: DynSolType = "uint8[]".parse().unwrap();
+        let value = ty.coerce_str("[1, 2]").unwrap();
+        assert_eq!(ty.abi_decode_packed(&value.abi_encode_packed()).unwrap(), value);
+        assert_eq!(
+            ty.abi_decode_packed(&[0; 31]).unwrap_err(),
+            alloy_sol_types::Error::Overrun.into()
+        );
+
+        let ty: DynSolType = "(address,bool)".parse().unwrap();
+        assert_eq!(ty.packed_encoded_size(), Some(21));
+        assert_eq!(
+            ty.abi_decode_packed(&[0; 22]).unwrap_err(),
+            alloy_sol_types::Error::BufferNotEmpty.into()
+        );
+
+        // decodes the same as `SolType`, with array elements in 32-byte slots
+        type Sol = (
+            sol_data::FixedArray<sol_data::Int<8>, 2>,
+            sol_data::FixedArray<sol_data::FixedBytes<2>, 1>,
+            sol_data::Array<sol_data::Address>,
+        );
+        let ty: DynSolType = Sol::SOL_NAME.parse().unwrap();
+        let value = (
+            [-1i8, 1],
+            [FixedBytes([0xab, 0xcd])],
+            vec![Address::repeat_byte(0x11), Address::repeat_byte(0x22)],
+        );
+        let packed = Sol::abi_encode_packed(&value);
+        assert_eq!(packed.len(), 5 * 32);
+        // signed elements are sign-extended, and fixed bytes are right-padded
+        assert_eq!(packed[..32], [0xff; 32]);
+        assert_eq!(packed[64..96], [[0xab, 0xcd].as_slice(), &[0; 30]].concat());
+        assert_eq!(Sol::abi_decode_packed(&packed).unwrap(), value);
+        let decoded = ty.abi_decode_packed(&packed).unwrap();
+        assert_eq!(decoded.abi_encode_packed(), packed);
+        assert_eq!(decoded.abi_encode_params(), Sol::abi_encode_params(&value));
+
+        for s in ["(bytes,address)", "((address,string),bool)", "string[]", "bytes[1]", "uint8[][]"]
+        {
+            let ty: DynSolType = s.parse().unwrap();
+            assert_eq!(
+                ty.abi_decode_packed(&[0; 64]).unwrap_err(),
+                alloy_sol_types::Error::AmbiguousPackedLayout(s.into()).into(),
+            );
+        }
     }
 
     packed_tests! {
@@ -1132,6 +1632,12 @@ expect: {expected}",
         neg_int8_3("int8", "-127", "81"),
         neg_int8_4("int8", "-128", "80"),
 
+        fixed8x1_1("fixed8x1", "1.5", "0f"),
+        fixed8x1_2("fixed8x1", "-1.5", "f1"),
+        fixed16x2("fixed16x2", "-0.01", "ffff"),
+        ufixed8x1("ufixed8x1", "25.5", "ff"),
+        ufixed32x4("ufixed32x4", "1.25", "000030d4"),
+
         int16_1("int16", "0", "0000"),
         int16_2("int16", "1", "0001"),
         int16_3("int16", "16", "0010"),
@@ -1175,6 +1681,10 @@ expect: {expected}",
         bytes_2("bytes", "0001", "0001"),
         bytes_3("bytes", "000102", "000102"),
 
+        bytes1("bytes1", "0x01", "01"),
+        bytes4("bytes4", "0x12345678", "12345678"),
+        bytes32("bytes32", "0x1111111111111111111111111111111111111111111111111111111111111111", "1111111111111111111111111111111111111111111111111111111111111111"),
+
         dynamic_array_of_addresses("address[]", "[\
             1111111111111111111111111111111111111111,\
             2222222222222222222222222222222222222222\
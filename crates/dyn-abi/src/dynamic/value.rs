@@ -1,8 +1,15 @@
 use super::ty::as_tuple;
 use crate::{DynSolType, DynToken, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use alloy_primitives::{Address, Function, I256, U256};
-use alloy_sol_types::{abi::Encoder, utils::words_for_len};
+use alloy_primitives::{
+    aliases::{Fixed256, Ufixed256},
+    bytes::BufMut,
+    Address, Function, I256, U256,
+};
+use alloy_sol_types::{
+    abi::{EncodeBuf, Encoder},
+    utils::words_for_len,
+};
 
 #[cfg(feature = "eip712")]
 macro_rules! as_fixed_seq {
@@ -65,6 +72,14 @@ pub enum DynSolValue {
     Int(I256, usize),
     /// An unsigned integer. The second parameter is the number of bits, not bytes.
     Uint(U256, usize),
+    /// A signed fixed-point number, represented by its raw scaled integer. The
+    /// second parameter is the number of bits, and the third is the number of
+    /// decimals.
+    Fixed(I256, usize, usize),
+    /// An unsigned fixed-point number, represented by its raw scaled integer.
+    /// The second parameter is the number of bits, and the third is the
+    /// number of decimals.
+    Ufixed(U256, usize, usize),
     /// A fixed-length byte array. The second parameter is the number of bytes.
     FixedBytes(Word, usize),
     /// An address.
@@ -189,6 +204,20 @@ impl From<U256> for DynSolValue {
     }
 }
 
+impl<const N: usize> From<Fixed256<N>> for DynSolValue {
+    #[inline]
+    fn from(value: Fixed256<N>) -> Self {
+        Self::Fixed(value.into_raw(), 256, N)
+    }
+}
+
+impl<const N: usize> From<Ufixed256<N>> for DynSolValue {
+    #[inline]
+    fn from(value: Ufixed256<N>) -> Self {
+        Self::Ufixed(value.into_raw(), 256, N)
+    }
+}
+
 impl DynSolValue {
     /// The Solidity type. This returns the Solidity type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
@@ -202,6 +231,8 @@ impl DynSolValue {
             Self::FixedBytes(_, size) => DynSolType::FixedBytes(*size),
             Self::Int(_, size) => DynSolType::Int(*size),
             Self::Uint(_, size) => DynSolType::Uint(*size),
+            Self::Fixed(_, size, decimals) => DynSolType::Fixed(*size, *decimals),
+            Self::Ufixed(_, size, decimals) => DynSolType::Ufixed(*size, *decimals),
             Self::String(_) => DynSolType::String,
             Self::Tuple(inner) => {
                 return inner
@@ -259,6 +290,16 @@ impl DynSolValue {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(_, size, decimals) | Self::Ufixed(_, size, decimals) => {
+                if let Self::Ufixed(..) = self {
+                    out.push('u');
+                }
+                out.push_str("fixed");
+                out.push_str(itoa::Buffer::new().format(*size));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
+
             Self::Array(values) | Self::FixedArray(values) => {
                 // SAFETY: checked in `sol_type_name_capacity`
                 debug_assert!(!values.is_empty());
@@ -306,6 +347,8 @@ impl DynSolValue {
             | Self::Bytes(_)
             | Self::String(_) => Some(8),
 
+            Self::Fixed(..) | Self::Ufixed(..) => Some(12),
+
             Self::Array(t) | Self::FixedArray(t) => {
                 t.first().and_then(Self::sol_type_name_capacity).map(|x| x + 8)
             }
@@ -339,6 +382,8 @@ impl DynSolValue {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(_)
         )
@@ -351,6 +396,8 @@ impl DynSolValue {
             Self::Bool(b) => Some(Word::with_last_byte(b as u8)),
             Self::Int(i, _) => Some(i.into()),
             Self::Uint(u, _) => Some(u.into()),
+            Self::Fixed(i, ..) => Some(i.into()),
+            Self::Ufixed(u, ..) => Some(u.into()),
             Self::FixedBytes(w, _) => Some(w),
             Self::Address(a) => Some(a.into_word()),
             Self::Function(f) => Some(f.into_word()),
@@ -412,6 +459,24 @@ impl DynSolValue {
         }
     }
 
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_fixed(&self) -> Option<(I256, usize, usize)> {
+        match self {
+            Self::Fixed(i, size, decimals) => Some((*i, *size, *decimals)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_ufixed(&self) -> Option<(U256, usize, usize)> {
+        match self {
+            Self::Ufixed(u, size, decimals) => Some((*u, *size, *decimals)),
+            _ => None,
+        }
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub fn as_str(&self) -> Option<&str> {
@@ -524,6 +589,8 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..) => false,
             Self::Bytes(_) | Self::String(_) | Self::Array(_) => true,
             as_fixed_seq!(tuple) => tuple.iter().any(Self::is_dynamic),
@@ -580,7 +647,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => 0,
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => 0,
 
             // `self.as_packed_seq()`
             // 1 for the length, then the body padded to the next word.
@@ -617,14 +686,16 @@ impl DynSolValue {
 
     /// Append this data to the head of an in-progress blob via the encoder.
     #[inline]
-    pub fn head_append(&self, enc: &mut Encoder) {
+    pub fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         match self {
             Self::Address(_)
             | Self::Function(_)
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
 
             Self::String(_) | Self::Bytes(_) | Self::Array(_) => enc.append_indirection(),
 
@@ -642,14 +713,16 @@ impl DynSolValue {
 
     /// Append this data to the tail of an in-progress blob via the encoder.
     #[inline]
-    pub fn tail_append(&self, enc: &mut Encoder) {
+    pub fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         match self {
             Self::Address(_)
             | Self::Function(_)
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => {}
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => {}
 
             Self::String(string) => enc.append_packed_seq(string.as_bytes()),
             Self::Bytes(bytes) => enc.append_packed_seq(bytes),
@@ -677,19 +750,26 @@ impl DynSolValue {
             Self::Bool(b) => buf.push(*b as u8),
             Self::String(s) => buf.extend_from_slice(s.as_bytes()),
             Self::Bytes(bytes) => buf.extend_from_slice(bytes),
-            Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..(*size).max(32)]),
-            Self::Int(num, size) => {
+            Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..(*size).min(32)]),
+            Self::Int(num, size) | Self::Fixed(num, size, _) => {
                 let byte_size = *size / 8;
                 let start = 32usize.saturating_sub(byte_size);
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
             }
-            Self::Uint(num, size) => {
+            Self::Uint(num, size) | Self::Ufixed(num, size, _) => {
                 let byte_size = *size / 8;
                 let start = 32usize.saturating_sub(byte_size);
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
             }
             Self::FixedArray(inner) | Self::Array(inner) => {
                 for val in inner {
+                    // Single-word elements are encoded as in the standard encoding: sign-extended,
+                    // or right-padded for fixed bytes
+                    if let Some(word) = val.as_word() {
+                        buf.extend_from_slice(word.as_slice());
+                        continue;
+                    }
+
                     let mut buf_inner = Vec::new();
                     val.abi_encode_packed_to(&mut buf_inner);
 
@@ -743,8 +823,8 @@ impl DynSolValue {
             Self::Bool(b) => Word::with_last_byte(*b as u8).into(),
             Self::Bytes(buf) => DynToken::PackedSeq(buf),
             Self::FixedBytes(buf, _) => (*buf).into(),
-            Self::Int(int, _) => int.to_be_bytes::<32>().into(),
-            Self::Uint(uint, _) => uint.to_be_bytes::<32>().into(),
+            Self::Int(int, _) | Self::Fixed(int, ..) => int.to_be_bytes::<32>().into(),
+            Self::Uint(uint, _) | Self::Ufixed(uint, ..) => uint.to_be_bytes::<32>().into(),
             Self::String(s) => DynToken::PackedSeq(s.as_bytes()),
             Self::Array(t) => DynToken::from_dyn_seq(t),
             as_fixed_seq!(t) => DynToken::from_fixed_seq(t),
@@ -760,7 +840,7 @@ impl DynSolValue {
     }
 
     /// Encode this data as a sequence into the given encoder.
-    pub(crate) fn encode_seq_to(contents: &[Self], enc: &mut Encoder) {
+    pub(crate) fn encode_seq_to<B: EncodeBuf>(contents: &[Self], enc: &mut Encoder<B>) {
         let head_words = contents.iter().map(Self::head_words).sum::<usize>();
         enc.push_offset(head_words);
 
@@ -783,6 +863,43 @@ impl DynSolValue {
         Self::encode_seq(core::slice::from_ref(self))
     }
 
+    /// Returns the number of bytes that [`abi_encode`](Self::abi_encode)
+    /// would produce for this value.
+    ///
+    /// This can be used to pre-size a buffer for
+    /// [`abi_encode_to`](Self::abi_encode_to).
+    #[inline]
+    pub fn abi_encoded_size(&self) -> usize {
+        self.total_words() * 32
+    }
+
+    /// Encode this value into the given buffer by wrapping it into a
+    /// 1-element sequence, without any intermediate allocations for the
+    /// encoded data.
+    ///
+    /// This writes exactly [`abi_encoded_size`](Self::abi_encoded_size)
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer does not have enough remaining capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    ///
+    /// let value = DynSolValue::String("hello".into());
+    ///
+    /// let mut buf = vec![0u8; value.abi_encoded_size()];
+    /// value.abi_encode_to(&mut &mut buf[..]);
+    /// assert_eq!(buf, value.abi_encode());
+    /// ```
+    #[inline]
+    pub fn abi_encode_to<B: ?Sized + BufMut>(&self, out: &mut B) {
+        Self::encode_seq_to(core::slice::from_ref(self), &mut Encoder::from_buf(out))
+    }
+
     /// Encode this value into a byte array suitable for passing to a function.
     /// If this value is a tuple, it is encoded as is. Otherwise, it is wrapped
     /// into a 1-element sequence.
@@ -807,10 +924,38 @@ impl DynSolValue {
         }
     }
 
+    /// Encode this value into the given buffer as function parameters.
+    ///
+    /// See [`abi_encode_params`](Self::abi_encode_params) and
+    /// [`abi_encode_to`](Self::abi_encode_to) for more details.
+    #[inline]
+    pub fn abi_encode_params_to<B: ?Sized + BufMut>(&self, out: &mut B) {
+        match self {
+            Self::Tuple(seq) => Self::encode_seq_to(seq, &mut Encoder::from_buf(out)),
+            _ => self.abi_encode_to(out),
+        }
+    }
+
     /// If this value is a fixed sequence, encode it into a byte array. If this
     /// value is not a fixed sequence, return `None`.
     #[inline]
     pub fn abi_encode_sequence(&self) -> Option<Vec<u8>> {
         self.as_fixed_seq().map(Self::encode_seq)
     }
+
+    /// If this value is a fixed sequence, encode it into the given buffer and
+    /// return `true`. If this value is not a fixed sequence, return `false`
+    /// without writing anything.
+    ///
+    /// See [`abi_encode_to`](Self::abi_encode_to) for more details.
+    #[inline]
+    pub fn abi_encode_sequence_to<B: ?Sized + BufMut>(&self, out: &mut B) -> bool {
+        match self.as_fixed_seq() {
+            Some(seq) => {
+                Self::encode_seq_to(seq, &mut Encoder::from_buf(out));
+                true
+            }
+            None => false,
+        }
+    }
 }
@@ -13,6 +13,8 @@ impl DynSolType {
             Self::Bool
             | Self::Int(_)
             | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(_)
             | Self::Address
             | Self::Function
@@ -42,6 +44,7 @@ impl DynSolType {
             Self::Bool => bool(value).map(DynSolValue::Bool),
             &Self::Int(n) => int(n, value).map(|x| DynSolValue::Int(x, n)),
             &Self::Uint(n) => uint(n, value).map(|x| DynSolValue::Uint(x, n)),
+            Self::Fixed(..) | Self::Ufixed(..) => fixed(self, value),
             &Self::FixedBytes(n) => fixed_bytes(n, value).map(|x| DynSolValue::FixedBytes(x, n)),
             Self::Address => address(value).map(DynSolValue::Address),
             Self::Function => function(value).map(DynSolValue::Function),
@@ -76,6 +79,14 @@ fn uint(n: usize, value: &serde_json::Value) -> Option<U256> {
     .and_then(|x| (x.bit_len() <= n).then_some(x))
 }
 
+fn fixed(ty: &DynSolType, value: &serde_json::Value) -> Option<DynSolValue> {
+    match value {
+        serde_json::Value::Number(n) => ty.coerce_str(&n.to_string()).ok(),
+        serde_json::Value::String(s) => ty.coerce_str(s).ok(),
+        _ => None,
+    }
+}
+
 fn fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
     if let Some(Ok(buf)) = value.as_str().map(hex::decode) {
         let mut word = Word::ZERO;
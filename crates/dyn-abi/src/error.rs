@@ -1,6 +1,6 @@
 use alloc::{borrow::Cow, string::String};
 use alloy_primitives::{Selector, B256};
-use alloy_sol_types::Error as SolTypesError;
+use alloy_sol_types::{Error as SolTypesError, PathSegment};
 use core::fmt;
 use hex::FromHexError;
 use parser::Error as TypeParserError;
@@ -151,6 +151,30 @@ impl Error {
         Self::SolTypes(SolTypesError::custom(s))
     }
 
+    /// Locates this error in the field or element `segment`, if it is a
+    /// decoding error.
+    ///
+    /// See [`SolTypesError::at`].
+    #[cold]
+    pub(crate) fn at(self, segment: PathSegment, offset: Option<usize>) -> Self {
+        match self {
+            Self::SolTypes(e) => Self::SolTypes(e.at(segment, offset)),
+            e => e,
+        }
+    }
+
+    /// Applies `f` to the path of this error, if it is a located decoding
+    /// error.
+    ///
+    /// See [`SolTypesError::map_path`].
+    #[cold]
+    pub(crate) fn map_path(self, f: impl FnOnce(&mut [PathSegment])) -> Self {
+        match self {
+            Self::SolTypes(e) => Self::SolTypes(e.map_path(f)),
+            e => e,
+        }
+    }
+
     #[cfg(feature = "eip712")]
     pub(crate) fn eip712_coerce(expected: &crate::DynSolType, actual: &serde_json::Value) -> Self {
         #[allow(unused_imports)]
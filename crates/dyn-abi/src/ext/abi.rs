@@ -2,7 +2,10 @@ use crate::{DynSolValue, Error as CrateError, Result, Specifier};
 use alloc::vec::Vec;
 use alloy_json_abi::{Constructor, Error, Function, Param};
 use alloy_primitives::Selector;
-use alloy_sol_types::abi::Decoder;
+use alloy_sol_types::{
+    abi::{DecodeLimits, DecodeState, Decoder},
+    PathSegment,
+};
 
 mod sealed {
     pub trait Sealed {}
@@ -56,6 +59,22 @@ pub trait JsonAbiExt: Sealed {
     /// This function will return an error if the decoded data does not match
     /// the expected input types.
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
+
+    /// ABI-decodes the given data according to this item's input types,
+    /// enforcing the given resource limits.
+    ///
+    /// See [`DecodeLimits`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the decoded data does not match
+    /// the expected input types, or if any of the limits is exceeded.
+    fn abi_decode_input_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>>;
 }
 
 /// Provide ABI encoding and decoding for the [`Function`] type.
@@ -79,6 +98,17 @@ pub trait FunctionExt: JsonAbiExt + Sealed {
     ///
     /// This method does not check for any prefixes or selectors.
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
+
+    /// ABI-decodes the given data according to this functions's output types,
+    /// enforcing the given resource limits.
+    ///
+    /// See [`DecodeLimits`] for more information.
+    fn abi_decode_output_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>>;
 }
 
 impl JsonAbiExt for Constructor {
@@ -94,7 +124,17 @@ impl JsonAbiExt for Constructor {
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, &self.inputs, validate, DecodeLimits::new())
+    }
+
+    #[inline]
+    fn abi_decode_input_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, &self.inputs, validate, limits)
     }
 }
 
@@ -111,7 +151,17 @@ impl JsonAbiExt for Error {
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, &self.inputs, validate, DecodeLimits::new())
+    }
+
+    #[inline]
+    fn abi_decode_input_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, &self.inputs, validate, limits)
     }
 }
 
@@ -128,7 +178,17 @@ impl JsonAbiExt for Function {
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, &self.inputs, validate, DecodeLimits::new())
+    }
+
+    #[inline]
+    fn abi_decode_input_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, &self.inputs, validate, limits)
     }
 }
 
@@ -140,7 +200,17 @@ impl FunctionExt for Function {
 
     #[inline]
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.outputs, validate)
+        abi_decode(data, &self.outputs, validate, DecodeLimits::new())
+    }
+
+    #[inline]
+    fn abi_decode_output_with_limits(
+        &self,
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, &self.outputs, validate, limits)
     }
 }
 
@@ -179,17 +249,59 @@ fn abi_encode(values: &[DynSolValue]) -> Vec<u8> {
     DynSolValue::encode_seq(values)
 }
 
-fn abi_decode(data: &[u8], params: &[Param], validate: bool) -> Result<Vec<DynSolValue>> {
+fn abi_decode(
+    data: &[u8],
+    params: &[Param],
+    validate: bool,
+    limits: DecodeLimits,
+) -> Result<Vec<DynSolValue>> {
     let mut values = Vec::with_capacity(params.len());
-    let mut decoder = Decoder::new(data, validate);
-    for param in params {
+    let state = DecodeState::new(limits);
+    let mut decoder = Decoder::with_state(data, validate, &state);
+    for (i, param) in params.iter().enumerate() {
         let ty = param.resolve()?;
-        let value = ty.abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate)?;
+        let offset = decoder.absolute_offset();
+        let value = ty
+            .abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate)
+            .map_err(|e| {
+                e.map_path(|path| name_components(&param.components, path))
+                    .at(param_segment(i, param), Some(offset))
+            })?;
         values.push(value);
     }
     Ok(values)
 }
 
+/// Returns the path segment of the `i`th parameter.
+fn param_segment(i: usize, param: &Param) -> PathSegment {
+    if param.name.is_empty() {
+        PathSegment::Member(i)
+    } else {
+        PathSegment::Field(param.name.clone().into())
+    }
+}
+
+/// Names the tuple members in the path of a decoding error after the
+/// parameter's components, skipping over array indices.
+fn name_components(components: &[Param], path: &mut [PathSegment]) {
+    let Some(start) = path.iter().position(|s| !matches!(s, PathSegment::Index(_))) else {
+        return;
+    };
+    let (segment, rest) = path[start..].split_first_mut().unwrap();
+    let param = match segment {
+        PathSegment::Member(i) => components.get(*i),
+        // already named from the resolved struct type
+        PathSegment::Field(name) => components.iter().find(|c| c.name == *name),
+        PathSegment::Index(_) => unreachable!(),
+    };
+    if let Some(param) = param {
+        if !param.name.is_empty() {
+            *segment = PathSegment::Field(param.name.clone().into());
+        }
+        name_components(&param.components, rest);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,4 +395,49 @@ mod tests {
         );
         assert_eq!(hex::encode(expected), hex::encode(result));
     }
+
+    #[test]
+    fn decode_error_paths() {
+        let json = r#"{
+            "inputs": [
+                {
+                    "components": [
+                        { "internalType": "address", "name": "maker", "type": "address" },
+                        { "internalType": "bytes", "name": "signature", "type": "bytes" }
+                    ],
+                    "internalType": "struct Exchange.Order[]",
+                    "name": "orders",
+                    "type": "tuple[]"
+                },
+                { "internalType": "uint256", "name": "", "type": "uint256" }
+            ],
+            "name": "fill",
+            "outputs": [],
+            "stateMutability": "nonpayable",
+            "type": "function"
+        }"#;
+        let func: Function = serde_json::from_str(json).unwrap();
+        let order = DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::ZERO),
+            DynSolValue::Bytes(vec![0x12; 4]),
+        ]);
+        let input =
+            [DynSolValue::Array(vec![order.clone(), order]), DynSolValue::Uint(U256::from(1), 256)];
+        let encoded = func.abi_encode_input(&input).unwrap();
+        assert!(func.abi_decode_input(&encoded[4..], true).is_ok());
+
+        let crate::Error::SolTypes(e) =
+            func.abi_decode_input(&encoded[4..encoded.len() - 32], true).unwrap_err()
+        else {
+            panic!("expected a decoding error")
+        };
+        assert_eq!(e.path().unwrap().to_string(), "orders[1].signature");
+        assert_eq!(*e.root_cause(), alloy_sol_types::Error::Overrun);
+
+        // unnamed parameters and components are positional
+        let func = Function::parse("fill((address,bytes)[],uint256)").unwrap();
+        let e = func.abi_decode_input(&encoded[4..encoded.len() - 32], true).unwrap_err();
+        let crate::Error::SolTypes(e) = e else { panic!("expected a decoding error") };
+        assert_eq!(e.path().unwrap().to_string(), "0[1].1");
+    }
 }
@@ -57,6 +57,6 @@ pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, Ty
 
 #[doc(no_inline)]
 pub use alloy_sol_types::{
-    abi::{self, Decoder, Encoder},
+    abi::{self, DecodeLimits, DecodeState, Decoder, Encoder},
     Eip712Domain, SolType, Word,
 };
@@ -61,6 +61,8 @@ impl Specifier<DynSolType> for RootType<'_> {
             "bytes" => Ok(DynSolType::Bytes),
             "uint" => Ok(DynSolType::Uint(256)),
             "int" => Ok(DynSolType::Int(256)),
+            "ufixed" => Ok(DynSolType::Ufixed(128, 18)),
+            "fixed" => Ok(DynSolType::Fixed(128, 18)),
             name => {
                 if let Some(sz) = name.strip_prefix("bytes") {
                     if let Ok(sz) = sz.parse() {
@@ -86,6 +88,19 @@ impl Specifier<DynSolType> for RootType<'_> {
                         }
                     }
                     Err(parser::Error::invalid_size(name).into())
+                } else if let Some(sz) = s.strip_prefix("fixed") {
+                    if let Some((m, n)) = sz.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse(), n.parse()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return if is_uint {
+                                    Ok(DynSolType::Ufixed(m, n))
+                                } else {
+                                    Ok(DynSolType::Fixed(m, n))
+                                };
+                            }
+                        }
+                    }
+                    Err(parser::Error::invalid_size(name).into())
                 } else {
                     Err(parser::Error::invalid_type_string(name).into())
                 }
@@ -297,6 +312,14 @@ mod tests {
         assert_eq!(parse("string"), Ok(DynSolType::String));
         assert_eq!(parse("bytes"), Ok(DynSolType::Bytes));
         assert_eq!(parse("bytes32"), Ok(DynSolType::FixedBytes(32)));
+        assert_eq!(parse("fixed"), Ok(DynSolType::Fixed(128, 18)));
+        assert_eq!(parse("ufixed"), Ok(DynSolType::Ufixed(128, 18)));
+        assert_eq!(parse("fixed64x10"), Ok(DynSolType::Fixed(64, 10)));
+        assert_eq!(parse("ufixed256x80"), Ok(DynSolType::Ufixed(256, 80)));
+        assert_eq!(parse("ufixed8x0"), Ok(DynSolType::Ufixed(8, 0)));
+        parse("fixed64").unwrap_err();
+        parse("fixed7x10").unwrap_err();
+        parse("ufixed256x81").unwrap_err();
     }
 
     #[test]
@@ -1,6 +1,6 @@
 //! Type aliases for common primitive types.
 
-use crate::{FixedBytes, Signed};
+use crate::{Fixed, FixedBytes, Signed, Ufixed};
 
 pub use ruint::aliases::{
     U0, U1, U1024, U128, U16, U160, U192, U2048, U256, U32, U320, U384, U4096, U448, U512, U64, U8,
@@ -32,6 +32,20 @@ int_aliases! {
     I512<512, 8>,
 }
 
+/// Solidity's default `fixed` type: a 128-bit [signed fixed-point number][Fixed]
+/// with 18 decimals.
+pub type Fixed128x18 = Fixed<128, 2, 18>;
+
+/// Solidity's default `ufixed` type: a 128-bit
+/// [unsigned fixed-point number][Ufixed] with 18 decimals.
+pub type Ufixed128x18 = Ufixed<128, 2, 18>;
+
+/// 256-bit [signed fixed-point number][Fixed] with `DECIMALS` decimals.
+pub type Fixed256<const DECIMALS: usize> = Fixed<256, 4, DECIMALS>;
+
+/// 256-bit [unsigned fixed-point number][Ufixed] with `DECIMALS` decimals.
+pub type Ufixed256<const DECIMALS: usize> = Ufixed<256, 4, DECIMALS>;
+
 macro_rules! fixed_bytes_aliases {
     ($($(#[$attr:meta])* $name:ident<$N:literal>),* $(,)?) => {$(
         #[doc = concat!($N, "-byte [fixed byte-array][FixedBytes] type.")]
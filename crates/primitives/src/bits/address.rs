@@ -1,4 +1,4 @@
-use crate::{aliases::U160, utils::keccak256, FixedBytes};
+use crate::{aliases::U160, utils::keccak256, FixedBytes, B256};
 use alloc::{
     borrow::Borrow,
     string::{String, ToString},
@@ -42,6 +42,63 @@ impl fmt::Display for AddressError {
     }
 }
 
+/// `keccak256` of the `CREATE3` proxy init code,
+/// `0x67363d3d37363d34f03d5260086018f3`.
+const CREATE3_PROXY_INIT_CODE_HASH: B256 =
+    b256!("21c35dbe1b344a2488cf3321d6ce542f8e9f305544ff09e4993a62319a497c1f");
+
+/// zkSync Era `CREATE2` prefix: `keccak256("zksyncCreate2")`.
+const ZKSYNC_CREATE2_PREFIX: B256 =
+    b256!("2020dba91b30cc0006188af794c2fb30dd8520db7e2c088b7fc7c103c00ca494");
+
+/// zkSync Era `CREATE` prefix: `keccak256("zksyncCreate")`.
+const ZKSYNC_CREATE_PREFIX: B256 =
+    b256!("63bae3a9951d38e8a3fbb7b70909afc1200610fc5bc55ade242f815974674f23");
+
+/// A contract address derivation scheme.
+///
+/// Used with [`Address::create_with`] to select how a contract's address is
+/// computed from its deployer.
+///
+/// The [`Create`](Self::Create) variant is only available with the `rlp`
+/// feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CreateScheme {
+    /// `CREATE`, see [`Address::create`].
+    #[cfg(feature = "rlp")]
+    Create {
+        /// The deployer's nonce.
+        nonce: u64,
+    },
+    /// `CREATE2`, see [`Address::create2`].
+    Create2 {
+        /// The salt.
+        salt: B256,
+        /// The hash of the init code.
+        init_code_hash: B256,
+    },
+    /// `CREATE3`, see [`Address::create3`].
+    Create3 {
+        /// The salt.
+        salt: B256,
+    },
+    /// zkSync Era `CREATE`, see [`Address::zksync_create`].
+    ZkSyncCreate {
+        /// The deployer's deployment nonce.
+        nonce: u64,
+    },
+    /// zkSync Era `CREATE2`, see [`Address::zksync_create2`].
+    ZkSyncCreate2 {
+        /// The salt.
+        salt: B256,
+        /// The zkSync versioned hash of the bytecode.
+        bytecode_hash: B256,
+        /// The hash of the ABI-encoded constructor arguments.
+        constructor_input_hash: B256,
+    },
+}
+
 wrap_fixed_bytes!(
     // we implement Display with the checksum, so we don't derive it
     extra_derives: [],
@@ -439,6 +496,136 @@ impl Address {
         Self::from_word(hash)
     }
 
+    /// Computes the `CREATE3` address of a smart contract, as implemented by
+    /// the Solady and ZeframLou `CREATE3` libraries.
+    ///
+    /// `self` deploys a minimal proxy with `CREATE2` and `salt`, which then
+    /// deploys the contract with `CREATE` and nonce 1. The resulting address
+    /// thus only depends on the deployer and the salt, not on the init code:
+    ///
+    /// `proxy = keccak256(0xff ++ address ++ salt ++ keccak256(proxy_init_code))[12:]`
+    /// `keccak256(rlp([proxy, 1]))[12:]`
+    ///
+    /// Note that factories usually derive the salt from the caller, for
+    /// example `keccak256(abi.encodePacked(msg.sender, salt))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, b256, hex, Address};
+    /// let factory = address!("9fBB3DF7C40Da2e5A0dE984fFE2CCB7C47cd0ABf");
+    /// let salt = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+    /// let proxy = factory.create2_from_code(salt, hex!("67363d3d37363d34f03d5260086018f3"));
+    /// # #[cfg(feature = "rlp")]
+    /// assert_eq!(factory.create3(salt), proxy.create(1));
+    /// ```
+    #[must_use]
+    pub fn create3<S>(&self, salt: S) -> Self
+    where
+        // not `AsRef` because `[u8; N]` does not implement `AsRef<[u8; N]>`
+        S: Borrow<[u8; 32]>,
+    {
+        let proxy = self._create2(salt.borrow(), &CREATE3_PROXY_INIT_CODE_HASH.0);
+
+        // `rlp([proxy, 1])`
+        let mut bytes = [0; 23];
+        bytes[0] = 0xd6;
+        bytes[1] = 0x94;
+        bytes[2..22].copy_from_slice(proxy.as_slice());
+        bytes[22] = 0x01;
+        let hash = keccak256(bytes);
+        Self::from_word(hash)
+    }
+
+    /// Computes the zkSync Era `CREATE` address of a smart contract:
+    ///
+    /// `keccak256(keccak256("zksyncCreate") ++ pad32(address) ++ pad32(nonce))[12:]`
+    ///
+    /// Note that `nonce` is the deployer's deployment nonce, which is tracked
+    /// separately from its transaction nonce.
+    #[must_use]
+    pub fn zksync_create(&self, nonce: u64) -> Self {
+        let mut bytes = [0; 96];
+        bytes[..32].copy_from_slice(ZKSYNC_CREATE_PREFIX.as_slice());
+        bytes[44..64].copy_from_slice(self.as_slice());
+        bytes[88..].copy_from_slice(&nonce.to_be_bytes());
+        let hash = keccak256(bytes);
+        Self::from_word(hash)
+    }
+
+    /// Computes the zkSync Era `CREATE2` address of a smart contract:
+    ///
+    /// `keccak256(keccak256("zksyncCreate2") ++ pad32(address) ++ salt ++ bytecode_hash ++
+    /// keccak256(constructor_input))[12:]`
+    ///
+    /// Unlike [`create2`](Self::create2), `bytecode_hash` is zkSync's
+    /// versioned bytecode hash, as returned by the compiler, rather than the
+    /// `keccak256` of the init code, and the constructor arguments are hashed
+    /// separately.
+    #[must_use]
+    pub fn zksync_create2<S, H, I>(&self, salt: S, bytecode_hash: H, constructor_input: I) -> Self
+    where
+        // not `AsRef` because `[u8; N]` does not implement `AsRef<[u8; N]>`
+        S: Borrow<[u8; 32]>,
+        H: Borrow<[u8; 32]>,
+        I: AsRef<[u8]>,
+    {
+        self._zksync_create2(
+            salt.borrow(),
+            bytecode_hash.borrow(),
+            &keccak256(constructor_input.as_ref()).0,
+        )
+    }
+
+    // non-generic inner function
+    fn _zksync_create2(
+        &self,
+        salt: &[u8; 32],
+        bytecode_hash: &[u8; 32],
+        constructor_input_hash: &[u8; 32],
+    ) -> Self {
+        let mut bytes = [0; 160];
+        bytes[..32].copy_from_slice(ZKSYNC_CREATE2_PREFIX.as_slice());
+        bytes[44..64].copy_from_slice(self.as_slice());
+        bytes[64..96].copy_from_slice(salt);
+        bytes[96..128].copy_from_slice(bytecode_hash);
+        bytes[128..].copy_from_slice(constructor_input_hash);
+        let hash = keccak256(bytes);
+        Self::from_word(hash)
+    }
+
+    /// Computes the address of a smart contract deployed by `self` using the
+    /// given [`CreateScheme`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, b256, Address, CreateScheme};
+    /// let deployer = address!("5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f");
+    /// let salt = b256!("2b2f5776e38002e0c013d0d89828fdb06fee595ea2d5ed4b194e3883e823e350");
+    /// let init_code_hash = b256!("96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f");
+    /// assert_eq!(
+    ///     deployer.create_with(&CreateScheme::Create2 { salt, init_code_hash }),
+    ///     deployer.create2(salt, init_code_hash),
+    /// );
+    /// assert_eq!(deployer.create_with(&CreateScheme::Create3 { salt }), deployer.create3(salt));
+    /// ```
+    #[must_use]
+    pub fn create_with(&self, scheme: &CreateScheme) -> Self {
+        match scheme {
+            #[cfg(feature = "rlp")]
+            CreateScheme::Create { nonce } => self.create(*nonce),
+            CreateScheme::Create2 { salt, init_code_hash } => {
+                self._create2(&salt.0, &init_code_hash.0)
+            }
+            CreateScheme::Create3 { salt } => self.create3(salt),
+            CreateScheme::ZkSyncCreate { nonce } => self.zksync_create(*nonce),
+            CreateScheme::ZkSyncCreate2 { salt, bytecode_hash, constructor_input_hash } => {
+                self._zksync_create2(&salt.0, &bytecode_hash.0, &constructor_input_hash.0)
+            }
+        }
+    }
+
     /// Instantiate by hashing public key bytes.
     ///
     /// # Panics
@@ -745,6 +932,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_scheme_constants() {
+        assert_eq!(
+            CREATE3_PROXY_INIT_CODE_HASH,
+            keccak256(hex!("67363d3d37363d34f03d5260086018f3"))
+        );
+        assert_eq!(ZKSYNC_CREATE2_PREFIX, keccak256("zksyncCreate2"));
+        assert_eq!(ZKSYNC_CREATE_PREFIX, keccak256("zksyncCreate"));
+    }
+
+    #[test]
+    fn create3() {
+        let deployer = Address::repeat_byte(0x11);
+        let salt = B256::repeat_byte(0x22);
+        let proxy = deployer.create2(salt, CREATE3_PROXY_INIT_CODE_HASH);
+        let expected =
+            Address::from_word(keccak256([&[0xd6, 0x94][..], proxy.as_slice(), &[0x01]].concat()));
+        assert_eq!(deployer.create3(salt), expected);
+        #[cfg(feature = "rlp")]
+        assert_eq!(deployer.create3(salt), proxy.create(1));
+        assert_ne!(deployer.create3(salt), Address::ZERO.create3(salt));
+        assert_eq!(deployer.create_with(&CreateScheme::Create3 { salt }), expected);
+    }
+
+    #[test]
+    fn zksync_create() {
+        let deployer = Address::repeat_byte(0x11);
+        let mut preimage = ZKSYNC_CREATE_PREFIX.to_vec();
+        preimage.extend_from_slice(deployer.into_word().as_slice());
+        preimage.extend_from_slice(&crate::U256::from(7).to_be_bytes::<32>());
+        let expected = Address::from_word(keccak256(preimage));
+        assert_eq!(deployer.zksync_create(7), expected);
+        assert_eq!(deployer.create_with(&CreateScheme::ZkSyncCreate { nonce: 7 }), expected);
+
+        // zksync-ethers `createAddress` test vector
+        let sender = address!("36615Cf349d7F6344891B1e7CA7C72883F5dc049");
+        assert_eq!(sender.zksync_create(1), address!("4B5DF730c2e6b28E17013A1485E5d9BC41Efe021"));
+    }
+
+    #[test]
+    fn zksync_create2() {
+        let deployer = Address::repeat_byte(0x11);
+        let salt = B256::repeat_byte(0x22);
+        let bytecode_hash = B256::repeat_byte(0x33);
+        let input = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        let mut preimage = ZKSYNC_CREATE2_PREFIX.to_vec();
+        preimage.extend_from_slice(deployer.into_word().as_slice());
+        preimage.extend_from_slice(salt.as_slice());
+        preimage.extend_from_slice(bytecode_hash.as_slice());
+        preimage.extend_from_slice(keccak256(input).as_slice());
+        let expected = Address::from_word(keccak256(preimage));
+        assert_eq!(deployer.zksync_create2(salt, bytecode_hash, input), expected);
+        assert_eq!(
+            deployer.create_with(&CreateScheme::ZkSyncCreate2 {
+                salt,
+                bytecode_hash,
+                constructor_input_hash: keccak256(input),
+            }),
+            expected
+        );
+        assert_ne!(deployer.zksync_create2(salt, bytecode_hash, []), expected);
+
+        // inputs of the zksync-ethers `create2Address` test vector, with the
+        // salt `0x01` left-padded to 32 bytes as required by zkSync; the
+        // expected address was computed with zksync-ethers' formula, which
+        // also gives the published `0x29bac3E5E8FFE7415F97C956BFA106D70316ad50`
+        // for the unpadded salt
+        let sender = address!("36615Cf349d7F6344891B1e7CA7C72883F5dc049");
+        let bytecode_hash =
+            b256!("010001cb6a6e8d5f6829522f19fa9568660e0a9cd53b2e8be4deb0a679452e41");
+        assert_eq!(
+            sender.zksync_create2(B256::with_last_byte(1), bytecode_hash, [0x01]),
+            address!("78ee9dea03a39f5cc04c80a575517ff5de02ec4c")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn create_with_create() {
+        let deployer = Address::repeat_byte(0x11);
+        assert_eq!(deployer.create_with(&CreateScheme::Create { nonce: 3 }), deployer.create(3));
+    }
+
     #[test]
     fn test_raw_public_key_to_address() {
         let addr = "0Ac1dF02185025F65202660F8167210A80dD5086".parse::<Address>().unwrap();
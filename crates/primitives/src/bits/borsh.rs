@@ -0,0 +1,58 @@
+use super::FixedBytes;
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, FixedBytes};
+
+    #[test]
+    fn fixed_layout() {
+        let bytes = fixed_bytes!("01234567");
+        assert_eq!(borsh::to_vec(&bytes).unwrap(), [0x01, 0x23, 0x45, 0x67]);
+        assert_eq!(borsh::from_slice::<FixedBytes<4>>(&[0x01, 0x23, 0x45, 0x67]).unwrap(), bytes);
+
+        let address = address!("2222222222222222222222222222222222222222");
+        assert_eq!(borsh::to_vec(&address).unwrap(), address.as_slice());
+
+        assert!(borsh::from_slice::<FixedBytes<4>>(&[0x01, 0x23, 0x45]).is_err());
+        assert!(borsh::from_slice::<FixedBytes<4>>(&[0x01, 0x23, 0x45, 0x67, 0x89]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(bytes: FixedBytes<32>, address: Address, bloom: Bloom)| {
+            proptest::prop_assert_eq!(
+                borsh::from_slice::<FixedBytes<32>>(&borsh::to_vec(&bytes).unwrap()).unwrap(),
+                bytes
+            );
+            proptest::prop_assert_eq!(
+                borsh::from_slice::<Address>(&borsh::to_vec(&address).unwrap()).unwrap(),
+                address
+            );
+            proptest::prop_assert_eq!(
+                borsh::from_slice::<Bloom>(&borsh::to_vec(&bloom).unwrap()).unwrap(),
+                bloom
+            );
+        });
+    }
+}
@@ -177,6 +177,9 @@ macro_rules! wrap_fixed_bytes {
         $crate::impl_rlp!($name, $n);
         $crate::impl_serde!($name);
         $crate::impl_allocative!($name);
+        $crate::impl_borsh!($name, $n);
+        $crate::impl_scale!($name, $n);
+        $crate::impl_sqlx!($name, $n);
         $crate::impl_arbitrary!($name, $n);
         $crate::impl_ssz_fixed_len!($name, $n);
         $crate::impl_rand!($name);
@@ -581,6 +584,145 @@ macro_rules! impl_allocative {
     ($t:ty) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "sqlx")]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {
+        impl<DB> $crate::private::sqlx_core::types::Type<DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::types::Type<DB>,
+        {
+            #[inline]
+            fn type_info() -> DB::TypeInfo {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'a, DB> $crate::private::sqlx_core::encode::Encode<'a, DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::encode::Encode<'a, DB>,
+        {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::private::sqlx_core::database::HasArguments<'a>>::ArgumentBuffer,
+            ) -> $crate::private::sqlx_core::encode::IsNull {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::encode::Encode<'a, DB>>::encode_by_ref(&self.0, buf)
+            }
+        }
+
+        impl<'a, DB> $crate::private::sqlx_core::decode::Decode<'a, DB> for $t
+        where
+            DB: $crate::private::sqlx_core::database::Database,
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::decode::Decode<'a, DB>,
+        {
+            #[inline]
+            fn decode(
+                value: <DB as $crate::private::sqlx_core::database::HasValueRef<'a>>::ValueRef,
+            ) -> Result<Self, $crate::private::sqlx_core::error::BoxDynError> {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::decode::Decode<'a, DB>>::decode(value).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "sqlx"))]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                <$crate::FixedBytes<$n> as $crate::private::borsh::BorshSerialize>::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                <$crate::FixedBytes<$n> as $crate::private::borsh::BorshDeserialize>::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "scale")]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::parity_scale_codec::Encode for $t {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+                <$crate::FixedBytes<$n> as $crate::private::parity_scale_codec::Encode>::using_encoded(&self.0, f)
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::EncodeLike for $t {}
+
+        impl $crate::private::parity_scale_codec::Decode for $t {
+            #[inline]
+            fn decode<I: $crate::private::parity_scale_codec::Input>(
+                input: &mut I,
+            ) -> Result<Self, $crate::private::parity_scale_codec::Error> {
+                <$crate::FixedBytes<$n> as $crate::private::parity_scale_codec::Decode>::decode(input).map(Self)
+            }
+
+            #[inline]
+            fn encoded_fixed_size() -> Option<usize> {
+                Some($n)
+            }
+        }
+
+        impl $crate::private::parity_scale_codec::MaxEncodedLen for $t {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $n
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "scale"))]
+macro_rules! impl_scale {
+    ($t:ty, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "serde")]
@@ -691,6 +833,15 @@ macro_rules! impl_ssz_fixed_len {
             }
         }
 
+        impl $crate::tree_hash::HashTreeRoot for $type {
+            #[inline]
+            fn hash_tree_root(&self) -> $crate::B256 {
+                <$crate::FixedBytes<$fixed_len> as $crate::tree_hash::HashTreeRoot>::hash_tree_root(
+                    &self.0,
+                )
+            }
+        }
+
         impl $crate::private::ssz::Decode for $type {
             #[inline]
             fn is_ssz_fixed_len() -> bool {
@@ -2,7 +2,7 @@
 mod macros;
 
 mod address;
-pub use address::{Address, AddressChecksumBuffer, AddressError};
+pub use address::{Address, AddressChecksumBuffer, AddressError, CreateScheme};
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
@@ -13,11 +13,22 @@ pub use fixed::FixedBytes;
 mod function;
 pub use function::Function;
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "scale")]
+mod scale;
+
 #[cfg(feature = "serde")]
 mod serde;
 
 #[cfg(feature = "ssz")]
 mod ssz;
+
+#[cfg(feature = "std")]
+mod vanity;
+#[cfg(feature = "std")]
+pub use vanity::{AddressPattern, AddressPatternError, VanityMatch, VanityMiner};
@@ -0,0 +1,81 @@
+use super::FixedBytes;
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+impl<const N: usize> Encode for FixedBytes<N> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.0);
+    }
+
+    #[inline]
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(&self.0)
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> EncodeLike for FixedBytes<N> {}
+
+impl<const N: usize> EncodeLike<[u8; N]> for FixedBytes<N> {}
+
+impl<const N: usize> Decode for FixedBytes<N> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = [0u8; N];
+        input.read(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<const N: usize> MaxEncodedLen for FixedBytes<N> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, Bloom};
+
+    #[test]
+    fn fixed_layout() {
+        let bytes = fixed_bytes!("01234567");
+        assert_eq!(bytes.encode(), [0x01, 0x23, 0x45, 0x67]);
+        assert_eq!(bytes.encode(), [0x01u8, 0x23, 0x45, 0x67].encode());
+        assert_eq!(FixedBytes::<4>::decode(&mut &[0x01, 0x23, 0x45, 0x67][..]).unwrap(), bytes);
+        assert!(FixedBytes::<4>::decode(&mut &[0x01, 0x23, 0x45][..]).is_err());
+
+        let address = address!("2222222222222222222222222222222222222222");
+        assert_eq!(address.encode(), address.as_slice());
+
+        assert_eq!(Address::max_encoded_len(), 20);
+        assert_eq!(Bloom::max_encoded_len(), 256);
+        assert_eq!(FixedBytes::<32>::encoded_fixed_size(), Some(32));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(bytes: FixedBytes<32>, address: Address, bloom: Bloom)| {
+            proptest::prop_assert_eq!(FixedBytes::<32>::decode(&mut &bytes.encode()[..]).unwrap(), bytes);
+            proptest::prop_assert_eq!(Address::decode(&mut &address.encode()[..]).unwrap(), address);
+            proptest::prop_assert_eq!(Bloom::decode(&mut &bloom.encode()[..]).unwrap(), bloom);
+        });
+    }
+}
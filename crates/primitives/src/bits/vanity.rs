@@ -0,0 +1,500 @@
+use crate::{Address, B256};
+use core::{borrow::Borrow, fmt, num::NonZeroUsize};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// Number of attempts each thread makes between checking for cancellation
+/// and reporting progress.
+const BATCH_SIZE: u64 = 1 << 12;
+
+/// Error returned when parsing an [`AddressPattern`] from a hex string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressPatternError {
+    /// The pattern contains a non-hex character.
+    InvalidHexCharacter(char),
+    /// The pattern is longer than 40 hex characters.
+    TooLong(usize),
+}
+
+impl std::error::Error for AddressPatternError {}
+
+impl fmt::Display for AddressPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHexCharacter(c) => write!(f, "invalid hex character {c:?} in pattern"),
+            Self::TooLong(len) => {
+                write!(f, "pattern is {len} hex characters long, but addresses have 40")
+            }
+        }
+    }
+}
+
+/// A pattern that an [`Address`] can be matched against, used for mining
+/// vanity addresses.
+///
+/// Every pattern is represented as a bit mask and the expected value of the
+/// masked bits: an address matches if `address & mask == value`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, AddressPattern};
+///
+/// let address = address!("dead00000000000000000000000000000000beef");
+/// assert!(AddressPattern::prefix("0xdead").unwrap().matches(&address));
+/// assert!(AddressPattern::prefix("dEa").unwrap().matches(&address));
+/// assert!(AddressPattern::suffix("beef").unwrap().matches(&address));
+/// assert!(!AddressPattern::leading_zero_bytes(1).matches(&address));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AddressPattern {
+    mask: Address,
+    value: Address,
+}
+
+impl AddressPattern {
+    /// Creates a pattern that matches addresses `a` such that
+    /// `a & mask == value & mask`.
+    #[inline]
+    pub const fn mask(mask: Address, value: Address) -> Self {
+        Self { mask, value: value.bit_and(mask) }
+    }
+
+    /// Creates a pattern that matches addresses starting with the given hex
+    /// characters. The `0x` prefix is optional, and the pattern is not
+    /// case-sensitive.
+    pub fn prefix(hex: &str) -> Result<Self, AddressPatternError> {
+        Self::from_nibbles(hex, false)
+    }
+
+    /// Creates a pattern that matches addresses ending with the given hex
+    /// characters. The `0x` prefix is optional, and the pattern is not
+    /// case-sensitive.
+    pub fn suffix(hex: &str) -> Result<Self, AddressPatternError> {
+        Self::from_nibbles(hex, true)
+    }
+
+    /// Creates a pattern that matches addresses starting with at least `n`
+    /// zero bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 20.
+    pub fn leading_zero_bytes(n: usize) -> Self {
+        assert!(n <= 20, "addresses have at most 20 leading zero bytes");
+        let mut mask = Address::ZERO;
+        mask[..n].fill(0xff);
+        Self { mask, value: Address::ZERO }
+    }
+
+    /// Returns the bit mask of this pattern.
+    #[inline]
+    pub const fn mask_bits(&self) -> &Address {
+        &self.mask
+    }
+
+    /// Returns the expected value of the masked bits.
+    #[inline]
+    pub const fn value_bits(&self) -> &Address {
+        &self.value
+    }
+
+    /// Returns the combination of two patterns, which matches addresses
+    /// matched by both, or `None` if they conflict.
+    pub fn and(&self, other: &Self) -> Option<Self> {
+        let common = self.mask.bit_and(other.mask);
+        if self.value.bit_and(common) != other.value.bit_and(common) {
+            return None;
+        }
+        Some(Self { mask: self.mask.bit_or(other.mask), value: self.value.bit_or(other.value) })
+    }
+
+    /// Returns `true` if the address matches this pattern.
+    #[inline]
+    pub fn matches(&self, address: &Address) -> bool {
+        address.iter().zip(self.mask.iter()).zip(self.value.iter()).all(|((a, m), v)| a & m == *v)
+    }
+
+    /// Returns the expected number of attempts needed to find a match, i.e.
+    /// `2^(number of bits in the mask)`, saturating at `u128::MAX`.
+    pub fn difficulty(&self) -> u128 {
+        let bits: u32 = self.mask.iter().map(|b| b.count_ones()).sum();
+        1u128.checked_shl(bits).unwrap_or(u128::MAX)
+    }
+
+    fn from_nibbles(hex: &str, suffix: bool) -> Result<Self, AddressPatternError> {
+        let hex = hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X")).unwrap_or(hex);
+        if hex.len() > 40 {
+            return Err(AddressPatternError::TooLong(hex.len()));
+        }
+
+        let offset = if suffix { 40 - hex.len() } else { 0 };
+        let mut pattern = Self::default();
+        for (i, c) in hex.chars().enumerate() {
+            let nibble = c.to_digit(16).ok_or(AddressPatternError::InvalidHexCharacter(c))? as u8;
+            let pos = offset + i;
+            let shift = if pos % 2 == 0 { 4 } else { 0 };
+            pattern.mask[pos / 2] |= 0xf << shift;
+            pattern.value[pos / 2] |= nibble << shift;
+        }
+        Ok(pattern)
+    }
+}
+
+/// The result of a successful vanity address search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VanityMatch<T> {
+    /// The salt or nonce that produces the address.
+    pub input: T,
+    /// The resulting address.
+    pub address: Address,
+}
+
+type ProgressFn = dyn Fn(u64) + Send + Sync;
+
+/// Multi-threaded vanity address miner.
+///
+/// Searches `CREATE2` salts ([`Address::mine_create2`]) or `CREATE` nonces
+/// ([`Address::mine_create`]) until the resulting address matches an
+/// [`AddressPattern`].
+///
+/// Inputs are searched in parallel, but the match with the smallest input is
+/// returned, so results do not depend on the number of threads.
+///
+/// A running search can be cancelled by setting the flag returned by
+/// [`cancel_flag`](Self::cancel_flag), and reports the total number of
+/// attempts to the [`on_progress`](Self::on_progress) callback.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, b256, AddressPattern, VanityMiner};
+///
+/// let deployer = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+/// let init_code_hash = b256!("96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f");
+///
+/// let miner = VanityMiner::new(AddressPattern::prefix("0x00").unwrap()).threads(2);
+/// let found = deployer.mine_create2(init_code_hash, &miner).unwrap();
+/// assert!(found.address.starts_with(&[0x00]));
+/// assert_eq!(deployer.create2(found.input, init_code_hash), found.address);
+/// ```
+#[derive(Clone)]
+pub struct VanityMiner {
+    pattern: AddressPattern,
+    threads: Option<NonZeroUsize>,
+    start_salt: B256,
+    max_attempts: Option<u64>,
+    cancel: Arc<AtomicBool>,
+    progress: Option<Arc<ProgressFn>>,
+}
+
+impl fmt::Debug for VanityMiner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VanityMiner")
+            .field("pattern", &self.pattern)
+            .field("threads", &self.threads)
+            .field("start_salt", &self.start_salt)
+            .field("max_attempts", &self.max_attempts)
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+impl VanityMiner {
+    /// Creates a new miner searching for addresses matching `pattern`.
+    pub fn new(pattern: AddressPattern) -> Self {
+        Self {
+            pattern,
+            threads: None,
+            start_salt: B256::ZERO,
+            max_attempts: None,
+            cancel: Arc::new(AtomicBool::new(false)),
+            progress: None,
+        }
+    }
+
+    /// Sets the number of threads to use. Defaults to
+    /// [`std::thread::available_parallelism`].
+    pub const fn threads(mut self, threads: usize) -> Self {
+        self.threads = NonZeroUsize::new(threads);
+        self
+    }
+
+    /// Sets the salt from which the `CREATE2` search starts. Only the last 8
+    /// bytes of the salt are varied.
+    ///
+    /// Use this to search a different part of the salt space, for example by
+    /// setting the first 20 bytes to the deployer address, as required by
+    /// some factories.
+    pub const fn start_salt(mut self, salt: B256) -> Self {
+        self.start_salt = salt;
+        self
+    }
+
+    /// Sets the maximum total number of attempts, across all threads.
+    pub const fn max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Sets the callback that is periodically called with the total number of
+    /// attempts made so far.
+    pub fn on_progress<F: Fn(u64) + Send + Sync + 'static>(mut self, f: F) -> Self {
+        self.progress = Some(Arc::new(f));
+        self
+    }
+
+    /// Returns the flag that cancels the search when set to `true`.
+    ///
+    /// The flag can be shared with other threads; searches check it
+    /// periodically. It is reset to `false` when a new search starts.
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.cancel.clone()
+    }
+
+    /// Cancels any ongoing search with this miner.
+    ///
+    /// This does not affect searches started afterwards.
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the search has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    /// Returns the pattern that this miner searches for.
+    pub const fn pattern(&self) -> &AddressPattern {
+        &self.pattern
+    }
+
+    /// Runs `f` on the inputs `0..=u64::MAX` in parallel, and returns the
+    /// matching address with the smallest input.
+    fn run<T: Send>(
+        &self,
+        f: impl Fn(u64) -> Option<(T, Address)> + Sync,
+    ) -> Option<VanityMatch<T>> {
+        let threads = self
+            .threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get) as u64;
+        let limit = self.max_attempts.unwrap_or(u64::MAX);
+        let attempts = AtomicU64::new(0);
+        // smallest matching input found so far; inputs above it are not searched
+        let best = AtomicU64::new(u64::MAX);
+        let result = Mutex::new(None);
+        self.cancel.store(false, Ordering::Relaxed);
+
+        thread::scope(|s| {
+            for t in 0..threads {
+                let (f, attempts, best, result) = (&f, &attempts, &best, &result);
+                s.spawn(move || {
+                    let mut batch = 0;
+                    let mut i = t;
+                    while i < limit && i <= best.load(Ordering::Relaxed) {
+                        if batch == 0 && self.is_cancelled() {
+                            break;
+                        }
+
+                        if let Some((input, address)) = f(i) {
+                            if self.pattern.matches(&address) {
+                                let mut result = result.lock().unwrap();
+                                if i < best.load(Ordering::Relaxed) {
+                                    best.store(i, Ordering::Relaxed);
+                                    *result = Some(VanityMatch { input, address });
+                                }
+                                break;
+                            }
+                        }
+
+                        batch += 1;
+                        if batch == BATCH_SIZE {
+                            let total = attempts.fetch_add(batch, Ordering::Relaxed) + batch;
+                            batch = 0;
+                            if let Some(progress) = &self.progress {
+                                progress(total);
+                            }
+                        }
+
+                        i = match i.checked_add(threads) {
+                            Some(i) => i,
+                            None => break,
+                        };
+                    }
+                    attempts.fetch_add(batch, Ordering::Relaxed);
+                });
+            }
+        });
+
+        if let Some(progress) = &self.progress {
+            progress(attempts.load(Ordering::Relaxed));
+        }
+        result.into_inner().unwrap()
+    }
+}
+
+impl Address {
+    /// Searches for a `CREATE2` salt such that the resulting address matches
+    /// the miner's pattern.
+    ///
+    /// Salts are derived from the miner's
+    /// [`start_salt`](VanityMiner::start_salt) by writing a counter into its
+    /// last 8 bytes, in big-endian order. The match with the smallest counter
+    /// is returned.
+    ///
+    /// Returns `None` if the search was cancelled or the maximum number of
+    /// attempts was reached.
+    ///
+    /// See [`VanityMiner`] for an example.
+    pub fn mine_create2<H: Borrow<[u8; 32]>>(
+        &self,
+        init_code_hash: H,
+        miner: &VanityMiner,
+    ) -> Option<VanityMatch<B256>> {
+        let init_code_hash = init_code_hash.borrow();
+        miner.run(|i| {
+            let mut salt = miner.start_salt;
+            salt[24..].copy_from_slice(&i.to_be_bytes());
+            Some((salt, self.create2(salt, init_code_hash)))
+        })
+    }
+
+    /// Searches for a `CREATE` nonce such that the resulting address matches
+    /// the miner's pattern.
+    ///
+    /// The smallest matching nonce is returned, as nonces are used in order.
+    ///
+    /// Returns `None` if the search was cancelled or the maximum number of
+    /// attempts was reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{address, AddressPattern, VanityMiner};
+    ///
+    /// let sender = address!("b20a608c624Ca5003905aA834De7156C68b2E1d0");
+    /// let miner = VanityMiner::new(AddressPattern::prefix("0").unwrap()).threads(1);
+    /// let found = sender.mine_create(&miner).unwrap();
+    /// assert_eq!(found.input, 0);
+    /// assert_eq!(sender.create(found.input), found.address);
+    /// ```
+    #[cfg(feature = "rlp")]
+    pub fn mine_create(&self, miner: &VanityMiner) -> Option<VanityMatch<u64>> {
+        miner.run(|nonce| Some((nonce, self.create(nonce))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        let addr = address!("00dead0000000000000000000000000000c0ffee");
+
+        assert!(AddressPattern::prefix("").unwrap().matches(&addr));
+        assert!(AddressPattern::prefix("0x00dEAd").unwrap().matches(&addr));
+        assert!(AddressPattern::prefix("00d").unwrap().matches(&addr));
+        assert!(!AddressPattern::prefix("00e").unwrap().matches(&addr));
+        assert!(AddressPattern::suffix("0ffee").unwrap().matches(&addr));
+        assert!(!AddressPattern::suffix("1ffee").unwrap().matches(&addr));
+        assert!(AddressPattern::leading_zero_bytes(1).matches(&addr));
+        assert!(!AddressPattern::leading_zero_bytes(2).matches(&addr));
+
+        let mask = address!("ff000000000000000000000000000000000000ff");
+        let value = address!("00ffffffffffffffffffffffffffffffffffffee");
+        assert!(AddressPattern::mask(mask, value).matches(&addr));
+
+        let both =
+            AddressPattern::prefix("00").unwrap().and(&AddressPattern::suffix("ee").unwrap());
+        assert!(both.unwrap().matches(&addr));
+        assert_eq!(
+            AddressPattern::prefix("00").unwrap().and(&AddressPattern::prefix("01").unwrap()),
+            None
+        );
+
+        assert_eq!(AddressPattern::prefix("dead").unwrap().difficulty(), 1 << 16);
+        assert_eq!(AddressPattern::leading_zero_bytes(20).difficulty(), u128::MAX);
+
+        assert_eq!(
+            AddressPattern::prefix("0xdeag"),
+            Err(AddressPatternError::InvalidHexCharacter('g'))
+        );
+        assert_eq!(AddressPattern::suffix(&"0".repeat(41)), Err(AddressPatternError::TooLong(41)));
+    }
+
+    #[test]
+    fn mine_create2() {
+        let deployer = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+        let init_code_hash =
+            b256!("96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f");
+        let pattern = AddressPattern::prefix("ab").unwrap();
+
+        let progress = Arc::new(AtomicU64::new(0));
+        let progress2 = progress.clone();
+        let miner = VanityMiner::new(pattern)
+            .threads(4)
+            .start_salt(deployer.into_word())
+            .on_progress(move |n| progress2.store(n, Ordering::Relaxed));
+        let found = deployer.mine_create2(init_code_hash, &miner).unwrap();
+        assert!(pattern.matches(&found.address));
+        assert_eq!(found.input[..12], deployer.into_word()[..12]);
+        assert_eq!(deployer.create2(found.input, init_code_hash), found.address);
+        assert!(progress.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn mine_limits() {
+        let deployer = Address::ZERO;
+        let pattern = AddressPattern::leading_zero_bytes(20);
+
+        let miner = VanityMiner::new(pattern).threads(3).max_attempts(100);
+        assert_eq!(deployer.mine_create2(B256::ZERO, &miner), None);
+
+        // cancel from the progress callback, once the search is running
+        let miner = VanityMiner::new(pattern).threads(2);
+        let cancel = miner.cancel_flag();
+        let miner = miner.on_progress(move |_| cancel.store(true, Ordering::Relaxed));
+        assert_eq!(deployer.mine_create2(B256::ZERO, &miner), None);
+        assert!(miner.is_cancelled());
+
+        // the flag is reset when a new search starts
+        let miner = VanityMiner::new(AddressPattern::prefix("0").unwrap()).threads(2);
+        miner.cancel();
+        assert!(deployer.mine_create2(B256::ZERO, &miner).is_some());
+        assert!(!miner.is_cancelled());
+    }
+
+    #[test]
+    fn mine_smallest_input() {
+        let deployer = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+        let pattern = AddressPattern::prefix("a").unwrap();
+        let expected = (0..)
+            .map(|i| B256::from(crate::U256::from(i)))
+            .find(|salt| pattern.matches(&deployer.create2(salt, B256::ZERO)))
+            .unwrap();
+        for threads in [1, 2, 3, 8] {
+            let miner = VanityMiner::new(pattern).threads(threads);
+            let found = deployer.mine_create2(B256::ZERO, &miner).unwrap();
+            assert_eq!(found.input, expected, "{threads} threads");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn mine_create() {
+        let sender = address!("b20a608c624Ca5003905aA834De7156C68b2E1d0");
+        for threads in [1, 4] {
+            let miner = VanityMiner::new(AddressPattern::prefix("e3").unwrap()).threads(threads);
+            let found = sender.mine_create(&miner).unwrap();
+            assert_eq!(found.input, 1);
+            assert_eq!(found.address, address!("e33c6e89e69d085897f98e92b06ebd541d1daa99"));
+        }
+    }
+}
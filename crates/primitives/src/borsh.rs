@@ -0,0 +1,93 @@
+//! Support for the [`borsh`](https://crates.io/crates/borsh) crate.
+//!
+//! Layouts are as follows:
+//! - [`FixedBytes`](crate::FixedBytes), [`Address`](crate::Address) and
+//!   [`Bloom`](crate::Bloom) are written as their raw bytes, like `[u8; N]`.
+//! - [`Bytes`](crate::Bytes) is written like `Vec<u8>`: a little-endian `u32`
+//!   length followed by the bytes.
+//! - [`Signed`](crate::Signed) is written as the fixed-width little-endian
+//!   bytes of its two's complement representation.
+//!
+//! [`Uint`] is defined in [`ruint`], which does not implement the `borsh`
+//! traits in the versions supported by this crate. Fields of that type can use
+//! [`serialize_uint`] and [`deserialize_uint`] instead, which write the same
+//! fixed-width little-endian layout as [`Signed`](crate::Signed).
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{borsh::{deserialize_uint, serialize_uint}, Address, U256};
+//! use borsh::{BorshDeserialize, BorshSerialize};
+//!
+//! #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+//! struct Transfer {
+//!     to: Address,
+//!     #[borsh(serialize_with = "serialize_uint", deserialize_with = "deserialize_uint")]
+//!     amount: U256,
+//! }
+//!
+//! let transfer = Transfer { to: Address::repeat_byte(0x11), amount: U256::from(1000) };
+//! let encoded = borsh::to_vec(&transfer)?;
+//! assert_eq!(encoded.len(), 20 + 32);
+//! assert_eq!(&encoded[20..22], &[0xe8, 0x03]);
+//! assert_eq!(borsh::from_slice::<Transfer>(&encoded)?, transfer);
+//! # Ok::<(), borsh::io::Error>(())
+//! ```
+
+use crate::Uint;
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+
+/// Writes a [`Uint`] as its fixed-width little-endian bytes.
+///
+/// Meant to be used with `#[borsh(serialize_with = "...")]`.
+#[inline]
+pub fn serialize_uint<W: Write, const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    writer: &mut W,
+) -> Result<()> {
+    writer.write_all(&value.as_le_bytes())
+}
+
+/// Reads a [`Uint`] from its fixed-width little-endian bytes.
+///
+/// Fails if the bytes encode a value that does not fit in `BITS` bits.
+///
+/// Meant to be used with `#[borsh(deserialize_with = "...")]`.
+#[inline]
+pub fn deserialize_uint<R: Read, const BITS: usize, const LIMBS: usize>(
+    reader: &mut R,
+) -> Result<Uint<BITS, LIMBS>> {
+    let mut bytes = vec![0u8; Uint::<BITS, LIMBS>::BYTES];
+    reader.read_exact(&mut bytes)?;
+    Uint::try_from_le_slice(&bytes)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "value is larger than fits the Uint"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U8, U256};
+
+    #[test]
+    fn uint_layout() {
+        let mut encoded = Vec::new();
+        serialize_uint(&U256::from(0x0102), &mut encoded).unwrap();
+        assert_eq!(encoded.len(), 32);
+        assert_eq!(encoded[..3], [0x02, 0x01, 0x00]);
+        assert_eq!(deserialize_uint::<_, 256, 4>(&mut &encoded[..]).unwrap(), U256::from(0x0102));
+
+        assert!(deserialize_uint::<_, 256, 4>(&mut &encoded[..31]).is_err());
+        assert!(deserialize_uint::<_, 7, 1>(&mut &[0x80][..]).is_err());
+        assert_eq!(deserialize_uint::<_, 8, 1>(&mut &[0x80][..]).unwrap(), U8::from(0x80));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn uint_roundtrip() {
+        proptest::proptest!(|(value: U256)| {
+            let mut encoded = Vec::new();
+            serialize_uint(&value, &mut encoded).unwrap();
+            proptest::prop_assert_eq!(deserialize_uint(&mut &encoded[..]).unwrap(), value);
+        });
+    }
+}
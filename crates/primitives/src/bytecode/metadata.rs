@@ -0,0 +1,430 @@
+use crate::{Bytes, B256};
+use alloc::string::String;
+use core::{fmt, str};
+
+/// The maximum nesting depth of CBOR values in skipped metadata fields.
+const MAX_DEPTH: usize = 16;
+
+/// Error when decoding a CBOR metadata section.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetadataError {
+    /// The input ended in the middle of a value.
+    UnexpectedEnd,
+    /// The CBOR item starting with this byte is not supported, or the
+    /// metadata is not a map.
+    Unsupported(u8),
+    /// A map key is not a text string, or a text string is not valid UTF-8.
+    InvalidString,
+    /// A known field has an unexpected type or length.
+    InvalidField(&'static str),
+    /// An unknown field is nested more deeply than supported.
+    TooDeep,
+    /// There are bytes after the metadata map.
+    TrailingBytes,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MetadataError {}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of metadata"),
+            Self::Unsupported(b) => write!(f, "unsupported CBOR item starting with {b:#04x}"),
+            Self::InvalidString => f.write_str("invalid CBOR text string"),
+            Self::InvalidField(name) => write!(f, "invalid metadata field {name:?}"),
+            Self::TooDeep => f.write_str("metadata is nested too deeply"),
+            Self::TrailingBytes => f.write_str("trailing bytes after metadata"),
+        }
+    }
+}
+
+/// The version of the Solidity compiler recorded in the bytecode metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SolcVersion {
+    /// A release build, encoded as its major, minor and patch numbers.
+    Release(u8, u8, u8),
+    /// A prerelease or custom build, encoded as its full version string.
+    Other(String),
+}
+
+impl fmt::Display for SolcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Release(major, minor, patch) => write!(f, "{major}.{minor}.{patch}"),
+            Self::Other(version) => f.write_str(version),
+        }
+    }
+}
+
+/// The metadata that the Solidity compiler appends to contract bytecode.
+///
+/// The metadata is a CBOR-encoded map, followed by its length as a 2-byte
+/// big-endian integer. See the [Solidity documentation] for details.
+///
+/// Unknown fields are ignored.
+///
+/// [Solidity documentation]: https://docs.soliditylang.org/en/latest/metadata.html#encoding-of-the-metadata-hash-in-the-bytecode
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     bytecode::{split_metadata, strip_metadata, SolcVersion},
+///     hex,
+/// };
+///
+/// let code = hex!(
+///     "6080604052600080fd"
+///     "a2646970667358221220"
+///     "b0e5e9cf0c4af5e4c5d1f2b3a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7"
+///     "64736f6c63430008130033"
+/// );
+///
+/// let (runtime, metadata) = split_metadata(&code).unwrap();
+/// assert_eq!(runtime, hex!("6080604052600080fd"));
+/// assert_eq!(metadata.solc, Some(SolcVersion::Release(0, 8, 19)));
+/// assert_eq!(metadata.solc.unwrap().to_string(), "0.8.19");
+/// assert_eq!(metadata.ipfs.unwrap().len(), 34);
+///
+/// assert_eq!(strip_metadata(&code), runtime);
+/// assert_eq!(strip_metadata(runtime), runtime);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BytecodeMetadata {
+    /// The IPFS multihash of the metadata file.
+    pub ipfs: Option<Bytes>,
+    /// The Swarm hash of the metadata file, in the `bzzr0` format.
+    pub bzzr0: Option<B256>,
+    /// The Swarm hash of the metadata file, in the `bzzr1` format.
+    pub bzzr1: Option<B256>,
+    /// Whether experimental compiler features were enabled.
+    pub experimental: bool,
+    /// The compiler version.
+    pub solc: Option<SolcVersion>,
+}
+
+impl BytecodeMetadata {
+    /// Decodes the CBOR-encoded metadata map, without the trailing length.
+    pub fn decode(cbor: &[u8]) -> Result<Self, MetadataError> {
+        Self::decode_inner(cbor).map(|(metadata, _)| metadata)
+    }
+
+    /// Decodes the metadata map, also returning whether it contains any known
+    /// field.
+    fn decode_inner(cbor: &[u8]) -> Result<(Self, bool), MetadataError> {
+        let mut decoder = Decoder { buf: cbor };
+        let (major, len) = decoder.header()?;
+        if major != MAJOR_MAP {
+            return Err(MetadataError::Unsupported(cbor[0]));
+        }
+
+        let mut metadata = Self::default();
+        let mut known = false;
+        for _ in 0..len {
+            let key = decoder.text()?;
+            known |= matches!(key, "ipfs" | "bzzr0" | "bzzr1" | "experimental" | "solc");
+            match key {
+                "ipfs" => {
+                    metadata.ipfs = Some(Bytes::copy_from_slice(decoder.bytes_field("ipfs")?))
+                }
+                "bzzr0" => metadata.bzzr0 = Some(decoder.hash_field("bzzr0")?),
+                "bzzr1" => metadata.bzzr1 = Some(decoder.hash_field("bzzr1")?),
+                "experimental" => metadata.experimental = decoder.bool_field("experimental")?,
+                "solc" => metadata.solc = Some(decoder.solc_field()?),
+                _ => decoder.skip(0)?,
+            }
+        }
+
+        if !decoder.buf.is_empty() {
+            return Err(MetadataError::TrailingBytes);
+        }
+        Ok((metadata, known))
+    }
+}
+
+/// Splits the bytecode into the code and its decoded metadata.
+///
+/// Returns `None` if the bytecode does not end with a valid metadata section.
+/// To avoid mistaking code for metadata, the section must contain at least one
+/// of the fields written by the Solidity compiler: `ipfs`, `bzzr0`, `bzzr1`,
+/// `experimental` or `solc`.
+pub fn split_metadata(code: &[u8]) -> Option<(&[u8], BytecodeMetadata)> {
+    let (code, cbor) = split_trailer(code)?;
+    match BytecodeMetadata::decode_inner(cbor) {
+        Ok((metadata, true)) => Some((code, metadata)),
+        _ => None,
+    }
+}
+
+/// Removes the metadata section from the end of the bytecode, if present.
+pub fn strip_metadata(code: &[u8]) -> &[u8] {
+    split_metadata(code).map_or(code, |(code, _)| code)
+}
+
+/// Compares two bytecodes, ignoring their metadata sections.
+///
+/// This is useful to verify deployed bytecode against compiled bytecode, as
+/// the metadata hash changes with any change in the source files, including
+/// comments and file paths.
+pub fn eq_ignoring_metadata(a: &[u8], b: &[u8]) -> bool {
+    strip_metadata(a) == strip_metadata(b)
+}
+
+/// Splits the bytecode into the code and the raw CBOR trailer, using the
+/// 2-byte length at the end.
+fn split_trailer(code: &[u8]) -> Option<(&[u8], &[u8])> {
+    let len_start = code.len().checked_sub(2)?;
+    let len = u16::from_be_bytes([code[len_start], code[len_start + 1]]) as usize;
+    let cbor_start = len_start.checked_sub(len)?;
+    (len > 0).then(|| (&code[..cbor_start], &code[cbor_start..len_start]))
+}
+
+const MAJOR_UINT: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const FALSE: u64 = 20;
+const TRUE: u64 = 21;
+
+/// A minimal decoder for the subset of CBOR used in bytecode metadata.
+struct Decoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Reads the header of the next item: its major type and argument.
+    ///
+    /// Indefinite lengths are not supported.
+    fn header(&mut self) -> Result<(u8, u64), MetadataError> {
+        let (&initial, rest) = self.buf.split_first().ok_or(MetadataError::UnexpectedEnd)?;
+        self.buf = rest;
+        let major = initial >> 5;
+        let arg = match initial & 0x1f {
+            info @ 0..=23 => info as u64,
+            24 => self.take(1)?[0] as u64,
+            25 => u16::from_be_bytes(self.take(2)?.try_into().unwrap()) as u64,
+            26 => u32::from_be_bytes(self.take(4)?.try_into().unwrap()) as u64,
+            27 => u64::from_be_bytes(self.take(8)?.try_into().unwrap()),
+            _ => return Err(MetadataError::Unsupported(initial)),
+        };
+        Ok((major, arg))
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8], MetadataError> {
+        let len = usize::try_from(len).map_err(|_| MetadataError::UnexpectedEnd)?;
+        if len > self.buf.len() {
+            return Err(MetadataError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(taken)
+    }
+
+    fn text(&mut self) -> Result<&'a str, MetadataError> {
+        match self.header()? {
+            (MAJOR_TEXT, len) => {
+                str::from_utf8(self.take(len)?).map_err(|_| MetadataError::InvalidString)
+            }
+            _ => Err(MetadataError::InvalidString),
+        }
+    }
+
+    fn bytes_field(&mut self, name: &'static str) -> Result<&'a [u8], MetadataError> {
+        match self.header()? {
+            (MAJOR_BYTES, len) => self.take(len),
+            _ => Err(MetadataError::InvalidField(name)),
+        }
+    }
+
+    fn hash_field(&mut self, name: &'static str) -> Result<B256, MetadataError> {
+        B256::try_from(self.bytes_field(name)?).map_err(|_| MetadataError::InvalidField(name))
+    }
+
+    fn bool_field(&mut self, name: &'static str) -> Result<bool, MetadataError> {
+        match self.header()? {
+            (MAJOR_SIMPLE, FALSE) => Ok(false),
+            (MAJOR_SIMPLE, TRUE) => Ok(true),
+            _ => Err(MetadataError::InvalidField(name)),
+        }
+    }
+
+    fn solc_field(&mut self) -> Result<SolcVersion, MetadataError> {
+        let invalid = MetadataError::InvalidField("solc");
+        match self.header()? {
+            (MAJOR_BYTES, 3) => {
+                let version = self.take(3)?;
+                Ok(SolcVersion::Release(version[0], version[1], version[2]))
+            }
+            (MAJOR_TEXT, len) => str::from_utf8(self.take(len)?)
+                .map(|version| SolcVersion::Other(version.into()))
+                .map_err(|_| invalid),
+            _ => Err(invalid),
+        }
+    }
+
+    /// Skips the next item, including nested items.
+    fn skip(&mut self, depth: usize) -> Result<(), MetadataError> {
+        if depth > MAX_DEPTH {
+            return Err(MetadataError::TooDeep);
+        }
+        let (major, arg) = self.header()?;
+        match major {
+            MAJOR_UINT | MAJOR_NEGATIVE | MAJOR_SIMPLE => {}
+            MAJOR_BYTES | MAJOR_TEXT => {
+                self.take(arg)?;
+            }
+            MAJOR_ARRAY => {
+                for _ in 0..arg {
+                    self.skip(depth + 1)?;
+                }
+            }
+            MAJOR_MAP => {
+                for _ in 0..arg {
+                    self.skip(depth + 1)?;
+                    self.skip(depth + 1)?;
+                }
+            }
+            MAJOR_TAG => self.skip(depth + 1)?,
+            _ => unreachable!("major type is 3 bits"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    // solc 0.8.19, IPFS hash
+    const IPFS: &[u8] = &hex!(
+        "6080604052348015600f57600080fd5b00fe"
+        "a2646970667358221220"
+        "d4f5e33b8c4b9a8b3e6a8c16f7c1d2fb7a1f1e0a6a2bb0e0c8e0d4d5f4a3b2c1"
+        "64736f6c63430008130033"
+    );
+
+    // solc 0.5.12, Swarm hash
+    const BZZR1: &[u8] = &hex!(
+        "6080604052600080fd00"
+        "a265627a7a72315820"
+        "0102030405060708091011121314151617181920212223242526272829303132"
+        "64736f6c634300050c0032"
+    );
+
+    #[test]
+    fn solc_metadata() {
+        let (code, metadata) = split_metadata(IPFS).unwrap();
+        assert_eq!(code, hex!("6080604052348015600f57600080fd5b00fe"));
+        assert_eq!(
+            metadata,
+            BytecodeMetadata {
+                ipfs: Some(Bytes::copy_from_slice(&IPFS[code.len() + 8..code.len() + 42])),
+                solc: Some(SolcVersion::Release(0, 8, 19)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(metadata.ipfs.unwrap()[..2], [0x12, 0x20]);
+
+        let (code, metadata) = split_metadata(BZZR1).unwrap();
+        assert_eq!(code, hex!("6080604052600080fd00"));
+        assert_eq!(
+            metadata.bzzr1,
+            Some(b256!("0102030405060708091011121314151617181920212223242526272829303132"))
+        );
+        assert_eq!(metadata.solc.unwrap().to_string(), "0.5.12");
+    }
+
+    #[test]
+    fn other_fields() {
+        // {"experimental": true, "solc": "0.8.20-ci.2023", "extra": [1, {"a": -1}]}
+        let cbor = hex!(
+            "a3"
+            "6c6578706572696d656e74616c" "f5"
+            "64736f6c63" "6e302e382e32302d63692e32303233"
+            "656578747261" "8201a1616120"
+        );
+        let metadata = BytecodeMetadata::decode(&cbor).unwrap();
+        assert!(metadata.experimental);
+        assert_eq!(metadata.solc, Some(SolcVersion::Other("0.8.20-ci.2023".into())));
+        assert_eq!(metadata.ipfs, None);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(BytecodeMetadata::decode(&[]), Err(MetadataError::UnexpectedEnd));
+        assert_eq!(BytecodeMetadata::decode(&[0x80]), Err(MetadataError::Unsupported(0x80)));
+        assert_eq!(BytecodeMetadata::decode(&[0xbf]), Err(MetadataError::Unsupported(0xbf)));
+        assert_eq!(
+            BytecodeMetadata::decode(&[0xa1, 0x01, 0x01]),
+            Err(MetadataError::InvalidString)
+        );
+        assert_eq!(
+            BytecodeMetadata::decode(&hex!("a165627a7a72304100")),
+            Err(MetadataError::InvalidField("bzzr0"))
+        );
+        assert_eq!(BytecodeMetadata::decode(&hex!("a000")), Err(MetadataError::TrailingBytes));
+        assert_eq!(
+            BytecodeMetadata::decode(&hex!("a16464617461")),
+            Err(MetadataError::UnexpectedEnd)
+        );
+
+        // {"a": [[[...]]]}, truncated right after the nesting limit
+        let mut cbor = hex!("a16161").to_vec();
+        cbor.extend([0x81; MAX_DEPTH + 1]);
+        assert_eq!(BytecodeMetadata::decode(&cbor), Err(MetadataError::TooDeep));
+        let len = cbor.len() as u16;
+        let mut code = cbor;
+        code.extend(len.to_be_bytes());
+        assert_eq!(split_metadata(&code), None);
+        assert_eq!(strip_metadata(&code), code);
+
+        for code in [&[][..], &[0x00], &[0x00, 0x00], &[0x60, 0x80, 0x00, 0x05], &hex!("a0ff0001")]
+        {
+            assert_eq!(split_metadata(code), None);
+            assert_eq!(strip_metadata(code), code);
+        }
+    }
+
+    #[test]
+    fn no_known_fields() {
+        // code ending in an empty map and its length
+        let code = hex!("6000" "a0" "0001");
+        assert_eq!(BytecodeMetadata::decode(&[0xa0]), Ok(BytecodeMetadata::default()));
+        assert_eq!(split_metadata(&code), None);
+        assert_eq!(strip_metadata(&code), code);
+
+        // {"a": 1}
+        let code = hex!("6080" "a1616101" "0004");
+        assert!(BytecodeMetadata::decode(&code[2..6]).is_ok());
+        assert_eq!(split_metadata(&code), None);
+        assert_eq!(strip_metadata(&code), code);
+
+        // {"a": 1, "solc": h'000813'}
+        let code = hex!("6080" "a2616101" "64736f6c6343000813" "000d");
+        let (runtime, metadata) = split_metadata(&code).unwrap();
+        assert_eq!(runtime, hex!("6080"));
+        assert_eq!(metadata.solc, Some(SolcVersion::Release(0, 8, 19)));
+    }
+
+    #[test]
+    fn compare() {
+        let mut other = IPFS.to_vec();
+        let len = other.len();
+        other[len - 20] ^= 1;
+        assert_ne!(IPFS, &other[..]);
+        assert!(eq_ignoring_metadata(IPFS, &other));
+
+        other[0] ^= 1;
+        assert!(!eq_ignoring_metadata(IPFS, &other));
+
+        assert!(eq_ignoring_metadata(strip_metadata(BZZR1), BZZR1));
+        assert!(!eq_ignoring_metadata(IPFS, BZZR1));
+    }
+}
@@ -0,0 +1,13 @@
+//! EVM bytecode utilities.
+//!
+//! These functions operate on raw bytecode, such as [`Bytes`](crate::Bytes)
+//! or the bytecode fields of a compiler artifact.
+
+mod metadata;
+pub use metadata::{
+    eq_ignoring_metadata, split_metadata, strip_metadata, BytecodeMetadata, MetadataError,
+    SolcVersion,
+};
+
+mod selectors;
+pub use selectors::{function_selectors, selectors_with_payability};
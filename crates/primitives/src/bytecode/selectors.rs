@@ -0,0 +1,329 @@
+use super::strip_metadata;
+use crate::Selector;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+const STOP: u8 = 0x00;
+const DIV: u8 = 0x04;
+const EQ: u8 = 0x14;
+const AND: u8 = 0x16;
+const XOR: u8 = 0x18;
+const SHR: u8 = 0x1c;
+const CALLVALUE: u8 = 0x34;
+const CALLDATALOAD: u8 = 0x35;
+const JUMP: u8 = 0x56;
+const JUMPI: u8 = 0x57;
+const PUSH0: u8 = 0x5f;
+const PUSH1: u8 = 0x60;
+const PUSH4: u8 = 0x63;
+const PUSH32: u8 = 0x7f;
+const DUP1: u8 = 0x80;
+const SWAP16: u8 = 0x9f;
+const RETURN: u8 = 0xf3;
+const REVERT: u8 = 0xfd;
+const INVALID: u8 = 0xfe;
+const SELFDESTRUCT: u8 = 0xff;
+
+/// The number of instructions at the start of a function body that are
+/// searched for a `CALLVALUE` check.
+const PAYABLE_CHECK_WINDOW: usize = 16;
+
+/// Extracts the function selectors checked by the dispatcher of the given
+/// runtime bytecode.
+///
+/// Selectors are returned in the order in which they first appear, without
+/// duplicates. See [`selectors_with_payability`] for details on the
+/// recognized patterns.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{bytecode::function_selectors, fixed_bytes, hex};
+///
+/// // DUP1 PUSH4 0xa9059cbb EQ PUSH2 0x000f JUMPI ...
+/// let code = hex!("8063a9059cbb1461000f57600080fd5b00");
+/// assert_eq!(function_selectors(&code), [fixed_bytes!("a9059cbb")]);
+/// ```
+pub fn function_selectors(code: &[u8]) -> Vec<Selector> {
+    dispatch_entries(code).into_iter().map(|(selector, _)| selector).collect()
+}
+
+/// Extracts the function selectors checked by the dispatcher of the given
+/// runtime bytecode, along with whether each function appears to be payable.
+///
+/// The dispatcher is recognized by the instruction sequence that `solc` and
+/// `vyper` emit for each function: a `PUSH4` of the selector, optionally
+/// followed by a `DUP` or `SWAP`, then an `EQ` or `XOR` comparison, a `PUSH`
+/// of the jump destination and a `JUMPI`. Trailing metadata is ignored.
+///
+/// Selectors with leading zero bytes are pushed with `PUSH0` to `PUSH3`.
+/// Since such comparisons with small constants are common in function
+/// bodies, they are only recognized in the dispatcher: after the selector is
+/// extracted from the calldata (`CALLDATALOAD PUSH1 0xe0 SHR`, or
+/// `DIV PUSH4 0xffffffff AND` in older `solc` versions), and before the
+/// first halting instruction.
+///
+/// A function is considered non-payable if its body starts with a
+/// `CALLVALUE` check, or if `CALLVALUE` is checked before the dispatcher,
+/// which `solc` does when no function is payable. This is a heuristic and
+/// may be wrong for hand-written or heavily optimized bytecode.
+pub fn selectors_with_payability(code: &[u8]) -> Vec<(Selector, bool)> {
+    dispatch_entries(code)
+}
+
+/// Returns the deduplicated dispatch entries of the bytecode.
+fn dispatch_entries(code: &[u8]) -> Vec<(Selector, bool)> {
+    let instructions = disassemble(strip_metadata(code));
+    let dispatcher = dispatcher_region(&instructions);
+
+    let mut entries = Vec::<(Selector, bool)>::new();
+    let mut global_check = None;
+    for i in 0..instructions.len() {
+        let Some((selector, target)) = match_dispatch(&instructions, i, dispatcher.contains(&i))
+        else {
+            continue;
+        };
+        if entries.iter().any(|(s, _)| *s == selector) {
+            continue;
+        }
+
+        let global_check = *global_check
+            .get_or_insert_with(|| instructions[..i].iter().any(|ins| ins.op == CALLVALUE));
+        let payable = !global_check && !checks_callvalue(&instructions, target);
+        entries.push((selector, payable));
+    }
+    entries
+}
+
+/// A single instruction with its program counter and immediate data.
+struct Instruction<'a> {
+    pc: usize,
+    op: u8,
+    immediate: &'a [u8],
+}
+
+fn disassemble(code: &[u8]) -> Vec<Instruction<'_>> {
+    let mut instructions = Vec::new();
+    let mut pc = 0;
+    while pc < code.len() {
+        let op = code[pc];
+        let len = if (PUSH1..=PUSH32).contains(&op) { (op - PUSH0) as usize } else { 0 };
+        let end = (pc + 1 + len).min(code.len());
+        instructions.push(Instruction { pc, op, immediate: &code[pc + 1..end] });
+        pc = end;
+    }
+    instructions
+}
+
+/// Returns the range of instruction indices of the dispatcher: from the
+/// extraction of the selector from the calldata to the first halting
+/// instruction after it. The range is empty if no selector extraction is
+/// found.
+fn dispatcher_region(instructions: &[Instruction<'_>]) -> Range<usize> {
+    let is_extraction = |w: &[Instruction<'_>]| match (w[0].op, w[1].op, w[2].op) {
+        (CALLDATALOAD, PUSH1, SHR) => w[1].immediate == [0xe0],
+        (DIV, PUSH4, AND) => w[1].immediate == [0xff; 4],
+        _ => false,
+    };
+    let Some(start) = instructions.windows(3).position(is_extraction).map(|i| i + 3) else {
+        return 0..0;
+    };
+    let end = instructions[start..]
+        .iter()
+        .position(|ins| matches!(ins.op, STOP | RETURN | REVERT | INVALID | SELFDESTRUCT))
+        .map_or(instructions.len(), |len| start + len);
+    start..end
+}
+
+/// Matches a dispatcher comparison starting at the `PUSH` at index `i`.
+/// Selectors pushed with fewer than 4 bytes are only matched if
+/// `in_dispatcher` is `true`.
+///
+/// Returns the selector and the program counter of the function body.
+fn match_dispatch(
+    instructions: &[Instruction<'_>],
+    i: usize,
+    in_dispatcher: bool,
+) -> Option<(Selector, usize)> {
+    let push = &instructions[i];
+    let min_push = if in_dispatcher { PUSH0 } else { PUSH4 };
+    if !(min_push..=PUSH4).contains(&push.op) || push.immediate.len() != (push.op - PUSH0) as usize
+    {
+        return None;
+    }
+    // selectors with leading zero bytes are pushed with a shorter `PUSH`
+    let mut selector = Selector::ZERO;
+    selector[4 - push.immediate.len()..].copy_from_slice(push.immediate);
+
+    let mut rest = instructions[i + 1..].iter();
+    let mut cmp = rest.next()?;
+    if (DUP1..=SWAP16).contains(&cmp.op) {
+        cmp = rest.next()?;
+    }
+    let dest = rest.next()?;
+    let jumpi = rest.next()?;
+    if !(PUSH1..=PUSH4).contains(&dest.op) || jumpi.op != JUMPI {
+        return None;
+    }
+
+    let target = match cmp.op {
+        // jumps to the function body if the selector matches
+        EQ => dest.immediate.iter().fold(0, |acc, &b| (acc << 8) | b as usize),
+        // jumps over the function body if the selector does not match
+        XOR => jumpi.pc + 1,
+        _ => return None,
+    };
+    Some((selector, target))
+}
+
+/// Returns `true` if the code at `target` checks `CALLVALUE` before its first
+/// unconditional jump or halt.
+fn checks_callvalue(instructions: &[Instruction<'_>], target: usize) -> bool {
+    let Ok(start) = instructions.binary_search_by_key(&target, |ins| ins.pc) else {
+        return false;
+    };
+    instructions[start..]
+        .iter()
+        .take(PAYABLE_CHECK_WINDOW)
+        .take_while(|ins| !matches!(ins.op, STOP | JUMP | RETURN | REVERT | INVALID | SELFDESTRUCT))
+        .any(|ins| ins.op == CALLVALUE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    const TRANSFER: Selector = Selector::new(hex!("a9059cbb"));
+    const DEPOSIT: Selector = Selector::new(hex!("d0e30db0"));
+
+    #[test]
+    fn solc_dispatcher() {
+        let code = hex!(
+            // PUSH1 0x80 PUSH1 0x40 MSTORE
+            "6080604052"
+            // PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR
+            "60003560e01c"
+            // DUP1 PUSH4 transfer EQ PUSH2 0x0025 JUMPI
+            "8063a9059cbb1461002557"
+            // DUP1 PUSH4 deposit EQ PUSH2 0x0033 JUMPI
+            "8063d0e30db01461003357"
+            // PUSH1 0 DUP1 REVERT
+            "600080fd"
+            // 0x25: JUMPDEST CALLVALUE DUP1 ISZERO PUSH2 0x0031 JUMPI PUSH1 0 DUP1 REVERT
+            "5b34801561003157600080fd"
+            // 0x31: JUMPDEST STOP
+            "5b00"
+            // 0x33: JUMPDEST STOP
+            "5b00"
+            // metadata
+            "a164736f6c6343000813000a"
+        );
+        assert_eq!(function_selectors(&code), [TRANSFER, DEPOSIT]);
+        assert_eq!(selectors_with_payability(&code), [(TRANSFER, false), (DEPOSIT, true)]);
+    }
+
+    #[test]
+    fn global_callvalue_check() {
+        let code = hex!(
+            // CALLVALUE DUP1 ISZERO PUSH2 0x000b JUMPI PUSH1 0 DUP1 REVERT
+            "34801561000b57600080fd"
+            // 0x0b: JUMPDEST POP PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR
+            "5b5060003560e01c"
+            // DUP1 PUSH4 deposit EQ PUSH2 0x001f JUMPI STOP
+            "8063d0e30db01461001f5700"
+            // 0x1f: JUMPDEST STOP
+            "5b00"
+        );
+        assert_eq!(selectors_with_payability(&code), [(DEPOSIT, false)]);
+    }
+
+    #[test]
+    fn vyper_dispatcher() {
+        let code = hex!(
+            // PUSH4 transfer DUP2 XOR PUSH2 0x0011 JUMPI
+            "63a9059cbb811861001157"
+            // CALLVALUE PUSH2 0x001e JUMPI STOP
+            "3461001e5700"
+            // 0x11: JUMPDEST PUSH4 deposit DUP2 XOR PUSH2 0x001e JUMPI STOP
+            "5b63d0e30db0811861001e5700"
+            // 0x1e: JUMPDEST PUSH0 DUP1 REVERT
+            "5b5f80fd"
+        );
+        assert_eq!(selectors_with_payability(&code), [(TRANSFER, false), (DEPOSIT, true)]);
+    }
+
+    #[test]
+    fn leading_zero_selectors() {
+        let code = hex!(
+            // PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR
+            "60003560e01c"
+            // DUP1 PUSH0 EQ PUSH2 0x0021 JUMPI
+            "805f1461002157"
+            // DUP1 PUSH2 0xabcd EQ PUSH2 0x0021 JUMPI
+            "8061abcd1461002157"
+            // DUP1 PUSH3 0x12abcd EQ PUSH2 0x0021 JUMPI STOP
+            "806212abcd146100215700"
+            // 0x21: JUMPDEST STOP
+            "5b00"
+        );
+        assert_eq!(
+            function_selectors(&code),
+            [Selector::ZERO, Selector::new(hex!("0000abcd")), Selector::new(hex!("0012abcd"))]
+        );
+
+        // older `solc` versions extract the selector with `DIV` and `AND`
+        let code = hex!(
+            // PUSH1 0 CALLDATALOAD PUSH29 0x0100..00 SWAP1 DIV PUSH4 0xffffffff AND
+            "600035"
+            "7c0100000000000000000000000000000000000000000000000000000000"
+            "900463ffffffff16"
+            // DUP1 PUSH2 0xabcd EQ PUSH2 0x0033 JUMPI STOP
+            "8061abcd146100335700"
+            // 0x33: JUMPDEST STOP
+            "5b00"
+        );
+        assert_eq!(function_selectors(&code), [Selector::new(hex!("0000abcd"))]);
+
+        // without a selector extraction, only `PUSH4` is recognized
+        assert!(function_selectors(&hex!("8061abcd1461000a57005b00")).is_empty());
+    }
+
+    #[test]
+    fn small_constants_in_function_bodies() {
+        let code = hex!(
+            // PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR
+            "60003560e01c"
+            // DUP1 PUSH4 transfer EQ PUSH2 0x0015 JUMPI
+            "8063a9059cbb1461001557"
+            // PUSH1 0 DUP1 REVERT
+            "600080fd"
+            // 0x15: JUMPDEST PUSH1 4 CALLDATALOAD PUSH1 5 EQ PUSH2 0x0021 JUMPI STOP
+            "5b6004356005146100215700"
+            // 0x21: JUMPDEST STOP
+            "5b00"
+        );
+        assert_eq!(function_selectors(&code), [TRANSFER]);
+    }
+
+    #[test]
+    fn no_dispatcher() {
+        assert!(function_selectors(&[]).is_empty());
+        // PUSH4 in immediate data of a PUSH32 is not an instruction
+        let mut code = vec![PUSH32];
+        code.extend_from_slice(&hex!("63a9059cbb1461002557"));
+        code.resize(33, 0);
+        assert!(function_selectors(&code).is_empty());
+        // PUSH4 ... GT is a binary search pivot, not a selector
+        assert!(function_selectors(&hex!("8063a9059cbb1161002557")).is_empty());
+        // truncated PUSH4
+        assert!(function_selectors(&hex!("63a9059c")).is_empty());
+        assert!(function_selectors(&hex!("8061ab")).is_empty());
+    }
+
+    #[test]
+    fn duplicates() {
+        let code = hex!("8063a9059cbb14610016578063a9059cbb146100165700");
+        assert_eq!(function_selectors(&code), [TRANSFER]);
+    }
+}
@@ -0,0 +1,48 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        BorshSerialize::serialize(&self.0[..], writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_prefixed_layout() {
+        let bytes = bytes!("01234567");
+        let encoded = borsh::to_vec(&bytes).unwrap();
+        assert_eq!(encoded, [0x04, 0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x67]);
+        assert_eq!(encoded, borsh::to_vec(&bytes.to_vec()).unwrap());
+        assert_eq!(borsh::from_slice::<Bytes>(&encoded).unwrap(), bytes);
+
+        assert_eq!(borsh::to_vec(&Bytes::new()).unwrap(), [0x00; 4]);
+        assert!(borsh::from_slice::<Bytes>(&encoded[..7]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(bytes: Bytes)| {
+            proptest::prop_assert_eq!(
+                borsh::from_slice::<Bytes>(&borsh::to_vec(&bytes).unwrap()).unwrap(),
+                bytes
+            );
+        });
+    }
+}
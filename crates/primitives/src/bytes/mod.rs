@@ -6,9 +6,15 @@ use core::{
     ops::{Deref, DerefMut, RangeBounds},
 };
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "scale")]
+mod scale;
+
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -0,0 +1,56 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, Output};
+
+impl Encode for Bytes {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0[..].size_hint()
+    }
+
+    #[inline]
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.0[..].encode_to(dest)
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        self.0[..].encoded_size()
+    }
+}
+
+impl EncodeLike for Bytes {}
+
+impl EncodeLike<Vec<u8>> for Bytes {}
+
+impl Decode for Bytes {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        Vec::<u8>::decode(input).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_prefixed_layout() {
+        let bytes = bytes!("01234567");
+        let encoded = bytes.encode();
+        assert_eq!(encoded, [0x10, 0x01, 0x23, 0x45, 0x67]);
+        assert_eq!(encoded, bytes.to_vec().encode());
+        assert_eq!(Bytes::decode(&mut &encoded[..]).unwrap(), bytes);
+
+        assert_eq!(Bytes::new().encode(), [0x00]);
+        assert!(Bytes::decode(&mut &encoded[..4]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(bytes: Bytes)| {
+            proptest::prop_assert_eq!(Bytes::decode(&mut &bytes.encode()[..]).unwrap(), bytes);
+        });
+    }
+}
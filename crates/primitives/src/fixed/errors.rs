@@ -0,0 +1,33 @@
+use core::fmt;
+
+/// The error type that is returned when parsing a fixed-point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFixedError {
+    /// The input string was empty, or contained no digits.
+    Empty,
+
+    /// An invalid character was encountered while parsing.
+    InvalidDigit(char),
+
+    /// The number has more fractional digits than the type's decimals, and
+    /// the extra digits are not all zero.
+    PrecisionLoss,
+
+    /// The number is too large or too small (negative) and does not fit in the
+    /// target fixed-point type.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedError {}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty fixed-point number"),
+            Self::InvalidDigit(c) => write!(f, "invalid digit: {c:?}"),
+            Self::PrecisionLoss => f.write_str("too many decimal places for the fixed-point type"),
+            Self::Overflow => f.write_str("number does not fit in the fixed-point type"),
+        }
+    }
+}
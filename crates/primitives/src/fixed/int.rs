@@ -0,0 +1,380 @@
+use super::{utils::*, ParseFixedError};
+use crate::{Sign, Signed};
+use core::{fmt, iter, ops, str::FromStr};
+use ruint::Uint;
+
+/// Signed decimal fixed-point number wrapping a [`Signed`].
+///
+/// This is the Rust equivalent of Solidity's `fixedMxN` type: the number is
+/// stored as a two's complement signed integer `raw`, and represents the value
+/// `raw / 10**DECIMALS`. The raw integer is exactly what gets ABI-encoded.
+///
+/// ## Aliases
+///
+/// We provide aliases for Solidity's default `fixed` type, [`Fixed128x18`],
+/// and for 256-bit numbers of any precision, [`Fixed256`].
+///
+/// [`Fixed128x18`]: crate::aliases::Fixed128x18
+/// [`Fixed256`]: crate::aliases::Fixed256
+///
+/// # Usage
+///
+/// ```
+/// # use alloy_primitives::{aliases::Fixed256, I256};
+/// let a: Fixed256<18> = "-1.25".parse().unwrap();
+/// assert_eq!(a.into_raw(), I256::try_from(-1_250_000_000_000_000_000i64).unwrap());
+/// assert_eq!(a.to_string(), "-1.25");
+///
+/// let b = Fixed256::<18>::checked_from_integer(I256::try_from(2).unwrap()).unwrap();
+/// assert_eq!((a * b).to_string(), "-2.5");
+/// assert_eq!((a + b).to_string(), "0.75");
+/// assert_eq!((a / b).to_string(), "-0.625");
+/// assert_eq!((-a).to_string(), "1.25");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+pub struct Fixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(
+    pub(crate) Signed<BITS, LIMBS>,
+);
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Debug
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Display
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, abs) = self.0.into_sign_and_abs();
+        fmt_decimal(f, sign.is_positive(), &abs, DECIMALS)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> FromStr
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    type Err = ParseFixedError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_dec_str(s)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Fixed<BITS, LIMBS, DECIMALS> {
+    /// Number of bits of the underlying integer.
+    pub const BITS: usize = BITS;
+
+    /// Number of decimal places.
+    pub const DECIMALS: usize = DECIMALS;
+
+    /// The minimum value.
+    pub const MIN: Self = Self(Signed::MIN);
+
+    /// The maximum value.
+    pub const MAX: Self = Self(Signed::MAX);
+
+    /// Zero (additive identity) of this type.
+    pub const ZERO: Self = Self(Signed::ZERO);
+
+    /// Creates a fixed-point number from its raw, scaled integer
+    /// representation.
+    #[inline]
+    pub const fn from_raw(raw: Signed<BITS, LIMBS>) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw, scaled integer representation of this number.
+    #[inline]
+    pub const fn into_raw(self) -> Signed<BITS, LIMBS> {
+        self.0
+    }
+
+    /// Returns the scaling factor, `10**DECIMALS`, or `None` if it does not
+    /// fit in the underlying integer type.
+    #[inline]
+    pub fn scaling_factor() -> Option<Signed<BITS, LIMBS>> {
+        scaling_factor(DECIMALS)
+            .and_then(|scale| Signed::checked_from_sign_and_abs(Sign::Positive, scale))
+    }
+
+    /// Returns the number `1`, or `None` if it cannot be represented.
+    #[inline]
+    pub fn one() -> Option<Self> {
+        Self::scaling_factor().map(Self)
+    }
+
+    /// Creates a fixed-point number from an integer. Returns `None` if the
+    /// result does not fit.
+    #[inline]
+    pub fn checked_from_integer(int: Signed<BITS, LIMBS>) -> Option<Self> {
+        int.checked_mul(Self::scaling_factor()?).map(Self)
+    }
+
+    /// Returns the integer part of this number, rounding towards zero.
+    #[inline]
+    pub fn to_integer(self) -> Signed<BITS, LIMBS> {
+        Self::scaling_factor().map_or(Signed::ZERO, |scale| self.0 / scale)
+    }
+
+    /// Returns the integer part of this number as a fixed-point number.
+    #[inline]
+    pub fn trunc(self) -> Self {
+        self - self.fract()
+    }
+
+    /// Returns the fractional part of this number. The result has the same
+    /// sign as `self`.
+    #[inline]
+    pub fn fract(self) -> Self {
+        Self::scaling_factor().map_or(self, |scale| Self(self.0 % scale))
+    }
+
+    /// Returns the sign of `self`.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        self.0.sign()
+    }
+
+    /// Returns `true` if `self` is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if `self` is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is [`Self::MIN`].
+    #[inline]
+    #[track_caller]
+    pub fn abs(self) -> Self {
+        self.checked_abs().expect("attempt to negate with overflow")
+    }
+
+    /// Checked absolute value. Returns `None` if `self` is [`Self::MIN`].
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+
+    /// Checked negation. Returns `None` if `self` is [`Self::MIN`].
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Convert from a decimal string, such as `-1.25`.
+    ///
+    /// Underscores are ignored. Fractional digits beyond [`Self::DECIMALS`]
+    /// are accepted only if they are all zero.
+    pub fn from_dec_str(s: &str) -> Result<Self, ParseFixedError> {
+        let (sign, s) = match s.as_bytes().first() {
+            Some(b'+') => (Sign::Positive, &s[1..]),
+            Some(b'-') => (Sign::Negative, &s[1..]),
+            _ => (Sign::Positive, s),
+        };
+        let abs = parse_decimal(s, DECIMALS)?;
+        Self::from_sign_and_abs(sign, abs).ok_or(ParseFixedError::Overflow)
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Checked multiplication, rounding towards zero. Returns `None` if
+    /// overflow occurred.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (lsign, lhs) = self.0.into_sign_and_abs();
+        let (rsign, rhs) = rhs.0.into_sign_and_abs();
+        let abs = checked_mul_div(lhs, rhs, scaling_factor(DECIMALS)?)?;
+        Self::from_sign_and_abs(lsign * rsign, abs)
+    }
+
+    /// Checked division, rounding towards zero. Returns `None` if `rhs == 0`
+    /// or overflow occurred.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (lsign, lhs) = self.0.into_sign_and_abs();
+        let (rsign, rhs) = rhs.0.into_sign_and_abs();
+        let abs = checked_mul_div(lhs, scaling_factor(DECIMALS)?, rhs)?;
+        Self::from_sign_and_abs(lsign * rsign, abs)
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at the numeric
+    /// bounds instead of overflowing.
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at the
+    /// numeric bounds instead of overflowing.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    #[inline]
+    fn from_sign_and_abs(sign: Sign, abs: Uint<BITS, LIMBS>) -> Option<Self> {
+        Signed::checked_from_sign_and_abs(sign, abs).map(Self)
+    }
+}
+
+impl_arith!(Fixed);
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> ops::Neg
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> iter::Sum
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    #[track_caller]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{Fixed128x18, Fixed256, I256};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_and_display() {
+        let cases = [
+            ("0", "0"),
+            ("-0", "0"),
+            ("+1.5", "1.5"),
+            ("-1.5", "-1.5"),
+            ("-.5", "-0.5"),
+            ("-0.000000000000000001", "-0.000000000000000001"),
+            ("-1_000.25", "-1000.25"),
+        ];
+        for (input, expected) in cases {
+            let parsed = input.parse::<Fixed128x18>().unwrap();
+            assert_eq!(parsed.to_string(), expected, "{input}");
+            assert_eq!(expected.parse::<Fixed128x18>().unwrap(), parsed);
+        }
+
+        assert_eq!(Fixed128x18::MAX.to_string(), "170141183460469231731.687303715884105727");
+        assert_eq!(Fixed128x18::MIN.to_string(), "-170141183460469231731.687303715884105728");
+        assert_eq!(
+            "-170141183460469231731.687303715884105728".parse::<Fixed128x18>(),
+            Ok(Fixed128x18::MIN)
+        );
+        assert_eq!(
+            "170141183460469231731.687303715884105728".parse::<Fixed128x18>(),
+            Err(ParseFixedError::Overflow)
+        );
+        assert_eq!("--1".parse::<Fixed128x18>(), Err(ParseFixedError::InvalidDigit('-')));
+        assert_eq!("-".parse::<Fixed128x18>(), Err(ParseFixedError::Empty));
+
+        let x = "-1.5".parse::<Fixed256<2>>().unwrap();
+        assert_eq!(format!("{x:>6}"), "  -1.5");
+        assert_eq!(format!("{x:06}"), "-001.5");
+        assert_eq!(format!("{:+}", -x), "+1.5");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = "1.5".parse::<Fixed256<6>>().unwrap();
+        let b = "-0.25".parse::<Fixed256<6>>().unwrap();
+        assert_eq!((a + b).to_string(), "1.25");
+        assert_eq!((b - a).to_string(), "-1.75");
+        assert_eq!((a * b).to_string(), "-0.375");
+        assert_eq!((b * b).to_string(), "0.0625");
+        assert_eq!((a / b).to_string(), "-6");
+        assert_eq!([a, b, b].into_iter().sum::<Fixed256<6>>().to_string(), "1");
+
+        // rounds towards zero
+        let third = -Fixed256::<6>::one().unwrap() / "3".parse().unwrap();
+        assert_eq!(third.to_string(), "-0.333333");
+
+        assert_eq!(a.checked_div(Fixed256::ZERO), None);
+        assert_eq!(Fixed256::<6>::MIN.checked_neg(), None);
+        assert_eq!(Fixed256::<6>::MIN.checked_sub(a), None);
+        assert_eq!(Fixed256::<6>::MAX.checked_mul(a), None);
+        assert_eq!(b.abs().to_string(), "0.25");
+    }
+
+    #[test]
+    fn arithmetic_large_operands() {
+        let one = Fixed256::<18>::scaling_factor().unwrap();
+        let (min, max) = (Fixed256::<18>::MIN, Fixed256::<18>::MAX);
+        let almost_one = Fixed256::<18>::from_raw(one - I256::ONE);
+
+        // `(a % d) * b` overflows, but the result fits
+        assert_eq!((max - Fixed256::from_raw(I256::ONE)).checked_div(max), Some(almost_one));
+        assert_eq!((-max + Fixed256::from_raw(I256::ONE)) / max, -almost_one);
+        let expected = I256::MAX - I256::MAX / one - I256::ONE;
+        assert_eq!(almost_one.checked_mul(max), Some(Fixed256::from_raw(expected)));
+        assert_eq!(-almost_one * max, Fixed256::from_raw(-expected));
+        assert_eq!(min.checked_mul(Fixed256::one().unwrap()), Some(min));
+        assert_eq!(min.checked_div(-Fixed256::one().unwrap()), None);
+
+        // the result does not fit
+        assert_eq!(max.checked_div(almost_one), None);
+    }
+
+    #[test]
+    fn integer_parts() {
+        let x = "-12.345".parse::<Fixed256<3>>().unwrap();
+        assert_eq!(x.to_integer(), I256::try_from(-12).unwrap());
+        assert_eq!(x.trunc().to_string(), "-12");
+        assert_eq!(x.fract().to_string(), "-0.345");
+        assert_eq!(
+            Fixed256::<3>::checked_from_integer(I256::try_from(-12).unwrap()).unwrap().into_raw(),
+            I256::try_from(-12_000).unwrap()
+        );
+        assert_eq!(Fixed256::<3>::checked_from_integer(I256::MIN), None);
+        assert_eq!(Fixed256::<77>::scaling_factor(), None);
+    }
+}
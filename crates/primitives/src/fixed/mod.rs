@@ -0,0 +1,57 @@
+//! This module contains decimal fixed-point number implementations, matching
+//! Solidity's `fixedMxN` and `ufixedMxN` types.
+
+/// Implements the arithmetic operators in terms of the `checked_*` methods,
+/// panicking on overflow.
+macro_rules! impl_arith {
+    ($t:ident) => {
+        impl_arith!(@impl $t;
+            Add::add, AddAssign::add_assign => checked_add, "attempt to add with overflow";
+            Sub::sub, SubAssign::sub_assign => checked_sub, "attempt to subtract with overflow";
+            Mul::mul, MulAssign::mul_assign => checked_mul, "attempt to multiply with overflow";
+            Div::div, DivAssign::div_assign => checked_div, "attempt to divide by zero or with overflow";
+        );
+    };
+    (@impl $t:ident; $($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident => $checked:ident, $msg:literal;)+) => {$(
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> core::ops::$trait
+            for $t<BITS, LIMBS, DECIMALS>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> core::ops::$assign_trait
+            for $t<BITS, LIMBS, DECIMALS>
+        {
+            #[inline]
+            #[track_caller]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    )+};
+}
+
+/// Error types for fixed-point numbers.
+mod errors;
+pub use errors::ParseFixedError;
+
+/// Signed fixed-point number wrapping a [`Signed`](crate::Signed).
+mod int;
+pub use int::Fixed;
+
+/// Unsigned fixed-point number wrapping a [`ruint::Uint`].
+mod uint;
+pub use uint::Ufixed;
+
+/// Serde support.
+#[cfg(feature = "serde")]
+mod serde;
+
+/// Utility functions used in the fixed-point implementations.
+mod utils;
@@ -0,0 +1,96 @@
+use super::{Fixed, Ufixed};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Serialize
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Serialize
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Deserialize<'de>
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor(PhantomData))
+    }
+}
+
+impl<'de, const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Deserialize<'de>
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor(PhantomData))
+    }
+}
+
+/// Deserializes a fixed-point number from a decimal string or an integer.
+struct DecimalVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr> Visitor<'_> for DecimalVisitor<T>
+where
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a decimal fixed-point number")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_str(itoa::Buffer::new().format(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        self.visit_str(itoa::Buffer::new().format(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_str(itoa::Buffer::new().format(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.visit_str(itoa::Buffer::new().format(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.visit_str(&v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aliases::{Fixed128x18, Ufixed128x18};
+
+    #[test]
+    fn serde_roundtrip() {
+        let x = "-1.25".parse::<Fixed128x18>().unwrap();
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(s, "\"-1.25\"");
+        assert_eq!(serde_json::from_str::<Fixed128x18>(&s).unwrap(), x);
+        assert_eq!(serde_json::from_str::<Fixed128x18>("-3").unwrap().to_string(), "-3");
+
+        let y = "1.25".parse::<Ufixed128x18>().unwrap();
+        assert_eq!(serde_json::to_string(&y).unwrap(), "\"1.25\"");
+        assert_eq!(serde_json::from_str::<Ufixed128x18>("\"1.25\"").unwrap(), y);
+        assert!(serde_json::from_str::<Ufixed128x18>("\"-1.25\"").is_err());
+    }
+}
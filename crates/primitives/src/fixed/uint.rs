@@ -0,0 +1,331 @@
+use super::{utils::*, ParseFixedError};
+use core::{fmt, iter, str::FromStr};
+use ruint::Uint;
+
+/// Unsigned decimal fixed-point number wrapping a `ruint::Uint`.
+///
+/// This is the Rust equivalent of Solidity's `ufixedMxN` type: the number is
+/// stored as an unsigned integer `raw`, and represents the value
+/// `raw / 10**DECIMALS`. The raw integer is exactly what gets ABI-encoded.
+///
+/// ## Aliases
+///
+/// We provide aliases for Solidity's default `ufixed` type, [`Ufixed128x18`],
+/// and for 256-bit numbers of any precision, [`Ufixed256`].
+///
+/// [`Ufixed128x18`]: crate::aliases::Ufixed128x18
+/// [`Ufixed256`]: crate::aliases::Ufixed256
+///
+/// # Usage
+///
+/// ```
+/// # use alloy_primitives::{aliases::Ufixed256, U256};
+/// let a: Ufixed256<18> = "1.25".parse().unwrap();
+/// assert_eq!(a.into_raw(), U256::from(1_250_000_000_000_000_000u64));
+/// assert_eq!(a.to_string(), "1.25");
+///
+/// let b = Ufixed256::<18>::checked_from_integer(U256::from(2)).unwrap();
+/// assert_eq!((a * b).to_string(), "2.5");
+/// assert_eq!((b - a).to_string(), "0.75");
+/// assert_eq!((a / b).to_string(), "0.625");
+///
+/// // Digits past the type's precision must be zero
+/// assert!("0.1234".parse::<Ufixed256<3>>().is_err());
+/// assert!("0.1230".parse::<Ufixed256<3>>().is_ok());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+pub struct Ufixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(
+    pub(crate) Uint<BITS, LIMBS>,
+);
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Debug
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Display
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, true, &self.0, DECIMALS)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> FromStr
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    type Err = ParseFixedError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_dec_str(s)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Ufixed<BITS, LIMBS, DECIMALS> {
+    /// Number of bits of the underlying integer.
+    pub const BITS: usize = BITS;
+
+    /// Number of decimal places.
+    pub const DECIMALS: usize = DECIMALS;
+
+    /// The minimum value.
+    pub const MIN: Self = Self::ZERO;
+
+    /// The maximum value.
+    pub const MAX: Self = Self(Uint::MAX);
+
+    /// Zero (additive identity) of this type.
+    pub const ZERO: Self = Self(Uint::ZERO);
+
+    /// Creates a fixed-point number from its raw, scaled integer
+    /// representation.
+    #[inline]
+    pub const fn from_raw(raw: Uint<BITS, LIMBS>) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw, scaled integer representation of this number.
+    #[inline]
+    pub const fn into_raw(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+
+    /// Returns the scaling factor, `10**DECIMALS`, or `None` if it does not
+    /// fit in the underlying integer type.
+    #[inline]
+    pub fn scaling_factor() -> Option<Uint<BITS, LIMBS>> {
+        scaling_factor(DECIMALS)
+    }
+
+    /// Returns the number `1`, or `None` if it cannot be represented.
+    #[inline]
+    pub fn one() -> Option<Self> {
+        Self::scaling_factor().map(Self)
+    }
+
+    /// Creates a fixed-point number from an integer. Returns `None` if the
+    /// result does not fit.
+    #[inline]
+    pub fn checked_from_integer(int: Uint<BITS, LIMBS>) -> Option<Self> {
+        int.checked_mul(Self::scaling_factor()?).map(Self)
+    }
+
+    /// Returns the integer part of this number, rounding towards zero.
+    #[inline]
+    pub fn to_integer(self) -> Uint<BITS, LIMBS> {
+        Self::scaling_factor().map_or(Uint::ZERO, |scale| self.0 / scale)
+    }
+
+    /// Returns the integer part of this number as a fixed-point number.
+    #[inline]
+    pub fn trunc(self) -> Self {
+        self - self.fract()
+    }
+
+    /// Returns the fractional part of this number.
+    #[inline]
+    pub fn fract(self) -> Self {
+        Self::scaling_factor().map_or(self, |scale| Self(self.0 % scale))
+    }
+
+    /// Returns `true` if `self` is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Convert from a decimal string, such as `1.25`.
+    ///
+    /// Underscores are ignored. Fractional digits beyond [`Self::DECIMALS`]
+    /// are accepted only if they are all zero.
+    pub fn from_dec_str(s: &str) -> Result<Self, ParseFixedError> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+        parse_decimal(s, DECIMALS).map(Self)
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Checked multiplication, rounding towards zero. Returns `None` if
+    /// overflow occurred.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        checked_mul_div(self.0, rhs.0, Self::scaling_factor()?).map(Self)
+    }
+
+    /// Checked division, rounding towards zero. Returns `None` if `rhs == 0`
+    /// or overflow occurred.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        checked_mul_div(self.0, Self::scaling_factor()?, rhs.0).map(Self)
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at
+    /// [`Self::MAX`].
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at
+    /// [`Self::ZERO`].
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl_arith!(Ufixed);
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> iter::Sum
+    for Ufixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    #[track_caller]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{Ufixed128x18, Ufixed256, U256};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_and_display() {
+        let cases = [
+            ("0", "0"),
+            ("1", "1"),
+            ("+1", "1"),
+            ("1.", "1"),
+            (".5", "0.5"),
+            ("1.25", "1.25"),
+            ("1.250000", "1.25"),
+            ("1_000.000_001", "1000.000001"),
+            ("0.000000000000000001", "0.000000000000000001"),
+            ("0.0000000000000000010", "0.000000000000000001"),
+        ];
+        for (input, expected) in cases {
+            let parsed = input.parse::<Ufixed128x18>().unwrap();
+            assert_eq!(parsed.to_string(), expected, "{input}");
+            assert_eq!(expected.parse::<Ufixed128x18>().unwrap(), parsed);
+        }
+
+        assert_eq!("".parse::<Ufixed128x18>(), Err(ParseFixedError::Empty));
+        assert_eq!(".".parse::<Ufixed128x18>(), Err(ParseFixedError::Empty));
+        assert_eq!("-1".parse::<Ufixed128x18>(), Err(ParseFixedError::InvalidDigit('-')));
+        assert_eq!("1.2.3".parse::<Ufixed128x18>(), Err(ParseFixedError::InvalidDigit('.')));
+        assert_eq!(
+            "0.0000000000000000001".parse::<Ufixed128x18>(),
+            Err(ParseFixedError::PrecisionLoss)
+        );
+        assert_eq!("340282366920938463464".parse::<Ufixed128x18>(), Err(ParseFixedError::Overflow));
+        assert_eq!(Ufixed128x18::MAX.to_string(), "340282366920938463463.374607431768211455");
+    }
+
+    #[test]
+    fn formatting() {
+        let x = "1.5".parse::<Ufixed256<2>>().unwrap();
+        assert_eq!(format!("{x:>6}"), "   1.5");
+        assert_eq!(format!("{x:06}"), "0001.5");
+        assert_eq!(format!("{x:+}"), "+1.5");
+        assert_eq!(format!("{x:?}"), "1.5");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = "1.5".parse::<Ufixed256<6>>().unwrap();
+        let b = "0.25".parse::<Ufixed256<6>>().unwrap();
+        assert_eq!((a + b).to_string(), "1.75");
+        assert_eq!((a - b).to_string(), "1.25");
+        assert_eq!((a * b).to_string(), "0.375");
+        assert_eq!((a / b).to_string(), "6");
+        assert_eq!([a, b, b].into_iter().sum::<Ufixed256<6>>().to_string(), "2");
+
+        // rounds towards zero
+        let third = Ufixed256::<6>::one().unwrap() / "3".parse().unwrap();
+        assert_eq!(third.to_string(), "0.333333");
+
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(a.checked_div(Ufixed256::ZERO), None);
+        assert_eq!(Ufixed256::<6>::MAX.checked_add(b), None);
+        assert_eq!(Ufixed256::<6>::MAX.checked_mul(a), None);
+        assert_eq!(b.saturating_sub(a), Ufixed256::ZERO);
+    }
+
+    #[test]
+    fn arithmetic_large_operands() {
+        let one = Ufixed256::<18>::scaling_factor().unwrap();
+        let max = Ufixed256::<18>::MAX;
+        let almost_one = Ufixed256::<18>::from_raw(one - U256::from(1));
+
+        // `(a % d) * b` overflows, but the result fits
+        assert_eq!(
+            Ufixed256::<18>::from_raw(U256::MAX - U256::from(1)).checked_div(max),
+            Some(almost_one)
+        );
+        assert_eq!(almost_one / max, Ufixed256::ZERO);
+        let expected = U256::MAX - U256::MAX / one - U256::from(1);
+        assert_eq!(almost_one.checked_mul(max), Some(Ufixed256::from_raw(expected)));
+        assert_eq!(max * almost_one, Ufixed256::from_raw(expected));
+        assert_eq!(max.checked_mul(Ufixed256::one().unwrap()), Some(max));
+        assert_eq!(max.checked_div(Ufixed256::one().unwrap()), Some(max));
+
+        // the result does not fit
+        let two = Ufixed256::<18>::from_raw(one * U256::from(2));
+        assert_eq!(max.checked_mul(two), None);
+        assert_eq!(max.checked_div(almost_one), None);
+    }
+
+    #[test]
+    fn integer_parts() {
+        let x = "12.345".parse::<Ufixed256<3>>().unwrap();
+        assert_eq!(x.to_integer(), U256::from(12));
+        assert_eq!(x.trunc().to_string(), "12");
+        assert_eq!(x.fract().to_string(), "0.345");
+        assert_eq!(
+            Ufixed256::<3>::checked_from_integer(U256::from(12)).unwrap().into_raw(),
+            U256::from(12_000)
+        );
+        assert_eq!(Ufixed256::<3>::checked_from_integer(U256::MAX), None);
+
+        // scaling factor does not fit
+        assert_eq!(Ufixed256::<78>::scaling_factor(), None);
+        assert_eq!(Ufixed256::<78>::one(), None);
+        let tiny = Ufixed256::<80>::from_raw(U256::from(1));
+        assert_eq!(tiny.to_string(), format!("0.{}1", "0".repeat(79)));
+        assert_eq!(tiny.to_integer(), U256::ZERO);
+    }
+}
@@ -0,0 +1,97 @@
+use super::ParseFixedError;
+use crate::{Rounding, UintMath};
+use alloc::string::{String, ToString};
+use core::fmt;
+use ruint::Uint;
+
+/// Returns `10**decimals`, or `None` if it does not fit in the integer type.
+#[inline]
+pub(super) fn scaling_factor<const BITS: usize, const LIMBS: usize>(
+    decimals: usize,
+) -> Option<Uint<BITS, LIMBS>> {
+    let ten = Uint::<BITS, LIMBS>::try_from(10u64).ok()?;
+    let exp = Uint::<BITS, LIMBS>::try_from(decimals).ok()?;
+    ten.checked_pow(exp)
+}
+
+/// Computes `a * b / d` with full precision, rounding towards zero.
+///
+/// Returns `None` if `d` is zero or if the result overflows.
+#[inline]
+pub(super) fn checked_mul_div<const BITS: usize, const LIMBS: usize>(
+    a: Uint<BITS, LIMBS>,
+    b: Uint<BITS, LIMBS>,
+    d: Uint<BITS, LIMBS>,
+) -> Option<Uint<BITS, LIMBS>> {
+    a.mul_div(b, d, Rounding::Trunc)
+}
+
+/// Parses an unsigned decimal string, such as `1.25`, into its raw integer
+/// representation scaled by `10**decimals`.
+///
+/// Underscores are ignored. Extra fractional digits are accepted only if they
+/// are all zero.
+pub(super) fn parse_decimal<const BITS: usize, const LIMBS: usize>(
+    s: &str,
+    decimals: usize,
+) -> Result<Uint<BITS, LIMBS>, ParseFixedError> {
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+
+    let mut digits = String::with_capacity(int.len() + decimals);
+    let mut n_frac = 0;
+    for c in int.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            '_' => {}
+            c => return Err(ParseFixedError::InvalidDigit(c)),
+        }
+    }
+    let n_int = digits.len();
+    for c in frac.chars() {
+        match c {
+            '0'..='9' if n_frac < decimals => {
+                digits.push(c);
+                n_frac += 1;
+            }
+            '0' => {}
+            '1'..='9' => return Err(ParseFixedError::PrecisionLoss),
+            '_' => {}
+            c => return Err(ParseFixedError::InvalidDigit(c)),
+        }
+    }
+    if n_int == 0 && n_frac == 0 && !frac.contains('0') {
+        return Err(ParseFixedError::Empty);
+    }
+    digits.extend(core::iter::repeat('0').take(decimals - n_frac));
+
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(Uint::ZERO);
+    }
+    Uint::from_str_radix(digits, 10).map_err(|_| ParseFixedError::Overflow)
+}
+
+/// Formats the raw integer representation of a fixed-point number with
+/// `decimals` fractional digits. Trailing fractional zeros are omitted.
+pub(super) fn fmt_decimal<const BITS: usize, const LIMBS: usize>(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    abs: &Uint<BITS, LIMBS>,
+    decimals: usize,
+) -> fmt::Result {
+    let digits = abs.to_string();
+    let mut s = String::with_capacity(digits.len().max(decimals + 1) + 1);
+    if digits.len() <= decimals {
+        s.push('0');
+        s.push('.');
+        s.extend(core::iter::repeat('0').take(decimals - digits.len()));
+        s.push_str(&digits);
+    } else {
+        let (int, frac) = digits.split_at(digits.len() - decimals);
+        s.push_str(int);
+        s.push('.');
+        s.push_str(frac);
+    }
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    f.pad_integral(is_nonnegative, "", s)
+}
@@ -24,9 +24,18 @@ extern crate alloc;
 
 use tiny_keccak as _;
 
+#[cfg(feature = "borsh")]
+pub mod borsh;
+
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
+#[cfg(feature = "scale")]
+pub mod scale;
+
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
@@ -38,28 +47,47 @@ pub use aliases::{
 #[macro_use]
 mod bits;
 pub use bits::{
-    Address, AddressChecksumBuffer, AddressError, Bloom, BloomInput, FixedBytes, Function,
-    BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
+    Address, AddressChecksumBuffer, AddressError, Bloom, BloomInput, CreateScheme, FixedBytes,
+    Function, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
+#[cfg(feature = "std")]
+pub use bits::{AddressPattern, AddressPatternError, VanityMatch, VanityMiner};
 
 #[path = "bytes/mod.rs"]
 mod bytes_;
 pub use self::bytes_::Bytes;
 
+pub mod bytecode;
+
 mod common;
 pub use common::TxKind;
 
+mod fixed;
+pub use fixed::{Fixed, ParseFixedError, Ufixed};
+
 mod log;
-pub use log::{Log, LogData};
+pub use log::{FilterSet, Log, LogData, LogFilter};
+
+mod math;
+pub use math::{Rounding, UintMath};
+
+pub mod merkle;
 
 mod sealed;
 pub use sealed::{Sealable, Sealed};
+#[cfg(feature = "std")]
+pub use sealed::{LazySealed, LazySealedMut};
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
 
 mod signed;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
 mod signature;
-pub use signature::{to_eip155_v, Parity, SignatureError};
+pub use signature::{to_eip155_v, Erc6492Signature, Parity, SignatureError, ERC6492_MAGIC_BYTES};
+#[cfg(feature = "p256")]
+pub use signature::{P256Signature, P256_VERIFY_ADDRESS};
 
 /// An ECDSA Signature, consisting of V, R, and S.
 #[cfg(feature = "k256")]
@@ -69,6 +97,9 @@ pub type Signature = signature::Signature<k256::ecdsa::Signature>;
 #[cfg(not(feature = "k256"))]
 pub type Signature = signature::Signature<()>;
 
+#[cfg(feature = "ssz")]
+pub mod tree_hash;
+
 pub mod utils;
 pub use utils::{eip191_hash_message, keccak256, Keccak256};
 
@@ -124,9 +155,18 @@ pub mod private {
     #[cfg(feature = "allocative")]
     pub use allocative;
 
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "scale")]
+    pub use parity_scale_codec;
+
     #[cfg(feature = "ssz")]
     pub use ssz;
 
+    #[cfg(feature = "sqlx")]
+    pub use sqlx_core;
+
     #[cfg(feature = "serde")]
     pub use serde;
 
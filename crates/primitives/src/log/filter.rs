@@ -0,0 +1,474 @@
+use crate::{Address, Bloom, BloomInput, Log, B256};
+use alloc::{collections::BTreeSet, vec::Vec};
+
+/// A set of values accepted at a single [`LogFilter`] position.
+///
+/// The values are OR-ed together: a position matches if it equals any value in
+/// the set. An empty set is a wildcard, and matches anything.
+///
+/// Serializes in the `eth_getLogs` shape: `null` for a wildcard, a single value
+/// for a set of one, and an array otherwise.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FilterSet<T>(BTreeSet<T>);
+
+impl<T> Default for FilterSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(BTreeSet::new())
+    }
+}
+
+impl<T: Ord> FilterSet<T> {
+    /// Creates a new wildcard set.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if this set is a wildcard, i.e. it is empty.
+    #[inline]
+    pub fn is_wildcard(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of values in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set is empty. Empty sets are wildcards.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds a value to the set, returning `true` if it was not already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Returns `true` if the set contains the given value. Always `false` for
+    /// wildcards.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.contains(value)
+    }
+
+    /// Returns `true` if the given value is accepted by this set, i.e. the set
+    /// is a wildcard or contains the value.
+    #[inline]
+    pub fn matches(&self, value: &T) -> bool {
+        self.is_wildcard() || self.contains(value)
+    }
+
+    /// Returns an iterator over the values in the set, in ascending order.
+    #[inline]
+    pub fn iter(&self) -> alloc::collections::btree_set::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: AsRef<[u8]> + Ord> FilterSet<T> {
+    /// Returns `true` if the bloom filter may contain any value of the set.
+    /// Always `true` for wildcards.
+    ///
+    /// Note: This method may return false positives. This is inherent to the
+    /// bloom filter data structure.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        self.is_wildcard()
+            || self.iter().any(|value| bloom.contains_input(BloomInput::Raw(value.as_ref())))
+    }
+}
+
+impl<T: Ord> From<T> for FilterSet<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(BTreeSet::from([value]))
+    }
+}
+
+impl<T: Ord> From<Option<T>> for FilterSet<T> {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for FilterSet<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for FilterSet<T> {
+    #[inline]
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for FilterSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for FilterSet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FilterSet<T> {
+    type Item = &'a T;
+    type IntoIter = alloc::collections::btree_set::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> IntoIterator for FilterSet<T> {
+    type Item = T;
+    type IntoIter = alloc::collections::btree_set::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A filter over event logs, by emitting address and topics.
+///
+/// Every position holds a [`FilterSet`] of accepted values. A log matches if
+/// its address is accepted by [`address`](Self::address), and every
+/// non-wildcard topic position `i` accepts the log's `i`th topic. A log with
+/// fewer topics than a non-wildcard position does not match.
+///
+/// With the `serde` feature, the filter (de)serializes as the `address` and
+/// `topics` fields of the `eth_getLogs` parameter object. Block range fields
+/// are left to the RPC layer and ignored when deserializing. Trailing wildcard
+/// topics are not serialized, and `null` entries inside a topic array make the
+/// whole position a wildcard, as in geth.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_primitives::{address, b256, Bloom, Log, LogFilter};
+/// let token = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+/// let transfer = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// let to = b256!("000000000000000000000000d8da6bf26964af9d7eed9e10160973d2a6fa49e0");
+///
+/// // `Transfer(_, to, _)` events from `token`.
+/// let filter = LogFilter::new().address(token).topic0(transfer).topic2(to);
+///
+/// let from = b256!("000000000000000000000000000000000000000000000000000000000000dead");
+/// let log = Log::new(token, vec![transfer, from, to], Default::default()).unwrap();
+/// assert!(filter.matches(&log));
+///
+/// let mut bloom = Bloom::ZERO;
+/// bloom.accrue_log(&log);
+/// assert!(filter.matches_bloom(&bloom));
+/// assert!(!filter.matches_bloom(&Bloom::ZERO));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogFilter {
+    /// The accepted emitting addresses.
+    pub address: FilterSet<Address>,
+    /// The accepted topics, per position.
+    pub topics: [FilterSet<B256>; 4],
+}
+
+impl LogFilter {
+    /// Creates a new filter that matches every log.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the accepted emitting addresses.
+    #[inline]
+    #[must_use]
+    pub fn address(mut self, address: impl Into<FilterSet<Address>>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets the accepted topics at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 4 or greater.
+    #[inline]
+    #[must_use]
+    pub fn topic(mut self, index: usize, topic: impl Into<FilterSet<B256>>) -> Self {
+        self.topics[index] = topic.into();
+        self
+    }
+
+    /// Sets the accepted first topics. For non-anonymous events, this is the
+    /// event selector.
+    #[inline]
+    #[must_use]
+    pub fn topic0(self, topic: impl Into<FilterSet<B256>>) -> Self {
+        self.topic(0, topic)
+    }
+
+    /// Sets the accepted second topics.
+    #[inline]
+    #[must_use]
+    pub fn topic1(self, topic: impl Into<FilterSet<B256>>) -> Self {
+        self.topic(1, topic)
+    }
+
+    /// Sets the accepted third topics.
+    #[inline]
+    #[must_use]
+    pub fn topic2(self, topic: impl Into<FilterSet<B256>>) -> Self {
+        self.topic(2, topic)
+    }
+
+    /// Sets the accepted fourth topics.
+    #[inline]
+    #[must_use]
+    pub fn topic3(self, topic: impl Into<FilterSet<B256>>) -> Self {
+        self.topic(3, topic)
+    }
+
+    /// Returns `true` if the filter matches every log.
+    #[inline]
+    pub fn is_wildcard(&self) -> bool {
+        self.address.is_wildcard() && self.topics.iter().all(FilterSet::is_wildcard)
+    }
+
+    /// Returns `true` if the given log matches the filter.
+    #[inline]
+    pub fn matches(&self, log: &Log) -> bool {
+        self.matches_raw(&log.address, log.topics())
+    }
+
+    /// Returns `true` if a log with the given address and topics matches the
+    /// filter.
+    pub fn matches_raw(&self, address: &Address, topics: &[B256]) -> bool {
+        self.address.matches(address)
+            && self.topics.iter().enumerate().all(|(i, set)| {
+                set.is_wildcard() || topics.get(i).map_or(false, |topic| set.contains(topic))
+            })
+    }
+
+    /// Returns `true` if the bloom filter may contain a log matching the
+    /// filter. Use this to skip blocks or receipts before matching individual
+    /// logs with [`matches`](Self::matches).
+    ///
+    /// Note: This method may return false positives. This is inherent to the
+    /// bloom filter data structure.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        self.address.matches_bloom(bloom) && self.topics.iter().all(|set| set.matches_bloom(bloom))
+    }
+
+    /// Returns the topic positions up to and including the last non-wildcard
+    /// one.
+    #[inline]
+    pub fn topics_trimmed(&self) -> &[FilterSet<B256>] {
+        let len = self.topics.iter().rposition(|set| !set.is_wildcard()).map_or(0, |i| i + 1);
+        &self.topics[..len]
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{FilterSet, LogFilter};
+    use crate::{Address, B256};
+    use alloc::vec::Vec;
+    use serde::{
+        de::Error as _,
+        ser::{SerializeSeq, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    impl<T: Serialize> Serialize for FilterSet<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut iter = self.0.iter();
+            match self.0.len() {
+                0 => serializer.serialize_none(),
+                1 => iter.next().unwrap().serialize(serializer),
+                len => {
+                    let mut seq = serializer.serialize_seq(Some(len))?;
+                    iter.try_for_each(|value| seq.serialize_element(value))?;
+                    seq.end()
+                }
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<Option<T>>),
+    }
+
+    impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for FilterSet<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+                None => Self::default(),
+                Some(OneOrMany::One(value)) => Self::from(value),
+                // A `null` entry accepts anything, so the whole position does.
+                Some(OneOrMany::Many(values)) => {
+                    values.into_iter().collect::<Option<Self>>().unwrap_or_default()
+                }
+            })
+        }
+    }
+
+    impl Serialize for LogFilter {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let topics = self.topics_trimmed();
+            let len = !self.address.is_wildcard() as usize + !topics.is_empty() as usize;
+            let mut s = serializer.serialize_struct("LogFilter", len)?;
+            if self.address.is_wildcard() {
+                s.skip_field("address")?;
+            } else {
+                s.serialize_field("address", &self.address)?;
+            }
+            if topics.is_empty() {
+                s.skip_field("topics")?;
+            } else {
+                s.serialize_field("topics", topics)?;
+            }
+            s.end()
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct LogFilterRepr {
+        #[serde(default)]
+        address: FilterSet<Address>,
+        #[serde(default)]
+        topics: Option<Vec<FilterSet<B256>>>,
+    }
+
+    impl<'de> Deserialize<'de> for LogFilter {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let LogFilterRepr { address, topics } = LogFilterRepr::deserialize(deserializer)?;
+            let topics = topics.unwrap_or_default();
+            if topics.len() > 4 {
+                return Err(D::Error::invalid_length(topics.len(), &"at most 4 topics"));
+            }
+            let mut filter = LogFilter { address, ..Default::default() };
+            for (slot, set) in filter.topics.iter_mut().zip(topics) {
+                *slot = set;
+            }
+            Ok(filter)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: Address = Address::repeat_byte(0xaa);
+    const B: Address = Address::repeat_byte(0xbb);
+    const T0: B256 = B256::repeat_byte(0x01);
+    const T1: B256 = B256::repeat_byte(0x02);
+    const T2: B256 = B256::repeat_byte(0x03);
+
+    fn log(address: Address, topics: &[B256]) -> Log {
+        Log::new(address, topics.to_vec(), Default::default()).unwrap()
+    }
+
+    #[test]
+    fn matches() {
+        let wildcard = LogFilter::new();
+        assert!(wildcard.is_wildcard());
+        assert!(wildcard.matches(&log(A, &[])));
+        assert!(wildcard.matches(&log(B, &[T0, T1])));
+
+        let filter = LogFilter::new().address([A, B]).topic0(T0).topic2([T1, T2]);
+        assert!(!filter.is_wildcard());
+        assert!(filter.matches(&log(A, &[T0, T0, T1])));
+        assert!(filter.matches(&log(B, &[T0, T2, T2, T2])));
+        assert!(!filter.matches(&log(Address::ZERO, &[T0, T0, T1])));
+        assert!(!filter.matches(&log(A, &[T1, T0, T1])));
+        assert!(!filter.matches(&log(A, &[T0, T0, T0])));
+        // too few topics for a non-wildcard position
+        assert!(!filter.matches(&log(A, &[T0, T1])));
+        assert!(!filter.matches(&log(A, &[])));
+
+        let trailing = LogFilter::new().topic1(T1);
+        assert!(trailing.matches(&log(A, &[T0, T1])));
+        assert!(!trailing.matches(&log(A, &[T1])));
+    }
+
+    #[test]
+    fn matches_bloom() {
+        let filter = LogFilter::new().address([A, B]).topic0(T0).topic2([T1, T2]);
+
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_log(&log(B, &[T0, T0, T2]));
+        assert!(filter.matches_bloom(&bloom));
+        assert!(LogFilter::new().matches_bloom(&Bloom::ZERO));
+        assert!(!filter.matches_bloom(&Bloom::ZERO));
+
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_log(&log(B, &[T0]));
+        assert!(!filter.matches_bloom(&bloom));
+
+        // every log accepted by the filter passes the pre-check
+        for l in [log(A, &[T0, T0, T1]), log(B, &[T0, T2, T2, T2])] {
+            let mut bloom = Bloom::ZERO;
+            bloom.accrue_log(&l);
+            assert!(filter.matches(&l));
+            assert!(filter.matches_bloom(&bloom));
+        }
+    }
+
+    #[test]
+    fn topics_trimmed() {
+        assert!(LogFilter::new().topics_trimmed().is_empty());
+        let filter = LogFilter::new().topic1(T1);
+        assert_eq!(filter.topics_trimmed(), &[FilterSet::new(), FilterSet::from(T1)]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let filter = LogFilter::new().address(A).topic0([T0, T1]).topic2(T2);
+        let json = serde_json::to_value(&filter).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "address": A,
+                "topics": [[T0, T1], null, T2],
+            })
+        );
+        assert_eq!(serde_json::from_value::<LogFilter>(json).unwrap(), filter);
+
+        assert_eq!(serde_json::to_string(&LogFilter::new()).unwrap(), "{}");
+        assert_eq!(serde_json::from_str::<LogFilter>("{}").unwrap(), LogFilter::new());
+
+        let json = serde_json::json!({
+            "fromBlock": "0x1",
+            "toBlock": "latest",
+            "address": [A, B],
+            "topics": [null, [T1, null], T2, null],
+        });
+        let filter = serde_json::from_value::<LogFilter>(json).unwrap();
+        assert_eq!(filter, LogFilter::new().address([A, B]).topic2(T2));
+
+        let json = serde_json::json!({ "address": null, "topics": null });
+        assert_eq!(serde_json::from_value::<LogFilter>(json).unwrap(), LogFilter::new());
+
+        let json = serde_json::json!({ "topics": [null, null, null, null, null] });
+        assert!(serde_json::from_value::<LogFilter>(json).is_err());
+        let json = serde_json::json!({ "address": "0x1234" });
+        assert!(serde_json::from_value::<LogFilter>(json).is_err());
+    }
+}
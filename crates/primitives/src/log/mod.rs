@@ -1,6 +1,9 @@
 use crate::{Address, Bytes, B256};
 use alloc::vec::Vec;
 
+mod filter;
+pub use filter::{FilterSet, LogFilter};
+
 /// An Ethereum event log object.
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,595 @@
+//! Full-precision integer math, modeled after the `Math` library of
+//! [OpenZeppelin](https://docs.openzeppelin.com/contracts/5.x/api/utils#Math)
+//! and the common functions of [PRB-Math](https://github.com/PaulRBerg/prb-math).
+
+use crate::{Sign, Signed};
+use ruint::Uint;
+
+/// Rounding mode for [`UintMath`] and the corresponding [`Signed`] methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward negative infinity.
+    #[default]
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    ///
+    /// This is the same as [`Floor`](Self::Floor) for unsigned integers, and
+    /// matches Solidity's signed integer division.
+    Trunc,
+    /// Round to the nearest integer, with ties rounded away from zero.
+    HalfUp,
+}
+
+impl Rounding {
+    /// Returns the rounding mode to apply to the absolute value of a negative
+    /// result.
+    #[inline]
+    pub(crate) const fn negated(self) -> Self {
+        match self {
+            Self::Floor => Self::Ceil,
+            Self::Ceil => Self::Floor,
+            Self::Trunc => Self::Trunc,
+            Self::HalfUp => Self::HalfUp,
+        }
+    }
+}
+
+/// Full-precision math operations on [`Uint`]s.
+///
+/// The [`Floor`](Rounding::Floor), [`Ceil`](Rounding::Ceil) and
+/// [`Trunc`](Rounding::Trunc) modes compute the same results as
+/// OpenZeppelin's `Math.mulDiv`, `Math.sqrt`, `Math.log2` and `Math.log10`
+/// with the corresponding rounding, where OpenZeppelin's `Expand` is the same
+/// as `Ceil` for unsigned integers. `Floor` also corresponds to PRB-Math's
+/// `mulDiv` and `sqrt`. [`HalfUp`](Rounding::HalfUp) has no counterpart in
+/// either library.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Rounding, UintMath, U256};
+///
+/// // `mulDiv` does not overflow on the intermediate product.
+/// let x = U256::MAX;
+/// assert_eq!(x.mul_div(x, x, Rounding::Floor), Some(x));
+/// assert_eq!(U256::from(7).mul_div(U256::from(1), U256::from(2), Rounding::Ceil), Some(U256::from(4)));
+///
+/// assert_eq!(U256::from(8).sqrt_rounded(Rounding::Floor), U256::from(2));
+/// assert_eq!(U256::from(8).sqrt_rounded(Rounding::HalfUp), U256::from(3));
+/// assert_eq!(U256::from(1000).log10_rounded(Rounding::Floor), 3);
+/// assert_eq!(U256::from(1025).log2_rounded(Rounding::Ceil), 11);
+/// ```
+pub trait UintMath: Sized {
+    /// Computes `self * y / denominator` with full precision, rounding the
+    /// result according to `rounding`.
+    ///
+    /// Returns `None` if `denominator` is zero or if the result does not fit
+    /// in `Self`, in which case the Solidity implementations revert.
+    fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Option<Self>;
+
+    /// Computes the square root of `self`, rounded according to `rounding`.
+    fn sqrt_rounded(self, rounding: Rounding) -> Self;
+
+    /// Computes the base 2 logarithm of `self`, rounded according to
+    /// `rounding`.
+    ///
+    /// Returns 0 if `self` is zero.
+    fn log2_rounded(self, rounding: Rounding) -> usize;
+
+    /// Computes the base 10 logarithm of `self`, rounded according to
+    /// `rounding`.
+    ///
+    /// Returns 0 if `self` is zero.
+    fn log10_rounded(self, rounding: Rounding) -> usize;
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintMath for Uint<BITS, LIMBS> {
+    fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Option<Self> {
+        let (result, remainder) = mul_div_rem(self, y, denominator)?;
+        let round_up = match rounding {
+            Rounding::Floor | Rounding::Trunc => false,
+            Rounding::Ceil => remainder != Self::ZERO,
+            Rounding::HalfUp => remainder != Self::ZERO && remainder >= denominator - remainder,
+        };
+        if round_up {
+            result.checked_add(Self::from(1))
+        } else {
+            Some(result)
+        }
+    }
+
+    fn sqrt_rounded(self, rounding: Rounding) -> Self {
+        if self <= Self::from(1) {
+            return self;
+        }
+
+        // Newton's method, starting from a power of two that is at least
+        // `sqrt(self)`. `self / x <= x` holds on every iteration, so the
+        // midpoint is computed without overflowing.
+        let mut x = Self::from(1) << ((self.bit_len() + 1) / 2);
+        loop {
+            let q = self / x;
+            let y = q + ((x - q) >> 1);
+            if y >= x {
+                break;
+            }
+            x = y;
+        }
+
+        let square = x * x;
+        let round_up = match rounding {
+            Rounding::Floor | Rounding::Trunc => false,
+            Rounding::Ceil => square < self,
+            // `(x + 0.5)^2 = x^2 + x + 0.25`
+            Rounding::HalfUp => self - square > x,
+        };
+        x + Self::from(round_up as u8)
+    }
+
+    fn log2_rounded(self, rounding: Rounding) -> usize {
+        if self == Self::ZERO {
+            return 0;
+        }
+
+        let result = self.bit_len() - 1;
+        let round_up = match rounding {
+            Rounding::Floor | Rounding::Trunc => false,
+            Rounding::Ceil => !self.is_power_of_two(),
+            // `log2(self) >= result + 0.5` iff `self^2 >= 2^(2 * result + 1)`
+            Rounding::HalfUp => {
+                let p = Self::from(1) << result;
+                full_mul(self, self) >= scaled_square(p, Self::from(2))
+            }
+        };
+        result + round_up as usize
+    }
+
+    fn log10_rounded(self, rounding: Rounding) -> usize {
+        if self == Self::ZERO {
+            return 0;
+        }
+
+        let ten = Self::from(10);
+        let mut result = 0;
+        let mut p = Self::from(1);
+        while let Some(next) = p.checked_mul(ten) {
+            if next > self {
+                break;
+            }
+            p = next;
+            result += 1;
+        }
+
+        let round_up = match rounding {
+            Rounding::Floor | Rounding::Trunc => false,
+            Rounding::Ceil => p != self,
+            // `log10(self) >= result + 0.5` iff `self^2 >= 10^(2 * result + 1)`
+            Rounding::HalfUp => full_mul(self, self) >= scaled_square(p, ten),
+        };
+        result + round_up as usize
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
+    /// Computes `self * y / denominator` with full precision, rounding the
+    /// result according to `rounding`.
+    ///
+    /// Returns `None` if `denominator` is zero or if the result does not fit
+    /// in `Self`.
+    ///
+    /// PRB-Math's `mulDivSigned` corresponds to [`Rounding::Trunc`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Rounding, I256};
+    ///
+    /// let x = I256::try_from(-7).unwrap();
+    /// let (one, two) = (I256::ONE, I256::try_from(2).unwrap());
+    /// assert_eq!(x.mul_div(one, two, Rounding::Floor), Some(I256::try_from(-4).unwrap()));
+    /// assert_eq!(x.mul_div(one, two, Rounding::Trunc), Some(I256::try_from(-3).unwrap()));
+    /// ```
+    #[inline]
+    pub fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Option<Self> {
+        let (x_sign, x) = self.into_sign_and_abs();
+        let (y_sign, y) = y.into_sign_and_abs();
+        let (d_sign, d) = denominator.into_sign_and_abs();
+
+        let negative = x_sign.is_negative() ^ y_sign.is_negative() ^ d_sign.is_negative();
+        if negative {
+            let abs = x.mul_div(y, d, rounding.negated())?;
+            Self::checked_from_sign_and_abs(Sign::Negative, abs)
+        } else {
+            let abs = x.mul_div(y, d, rounding)?;
+            Self::checked_from_sign_and_abs(Sign::Positive, abs)
+        }
+    }
+
+    /// Computes the square root of `self`, rounded according to `rounding`.
+    ///
+    /// Returns `None` if `self` is negative.
+    #[inline]
+    pub fn sqrt_rounded(self, rounding: Rounding) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        Some(Self::from_raw(self.into_raw().sqrt_rounded(rounding)))
+    }
+
+    /// Computes the base 2 logarithm of `self`, rounded according to
+    /// `rounding`.
+    ///
+    /// Returns `None` if `self` is not positive.
+    #[inline]
+    pub fn log2_rounded(self, rounding: Rounding) -> Option<usize> {
+        if !self.is_positive() {
+            return None;
+        }
+        Some(self.into_raw().log2_rounded(rounding))
+    }
+
+    /// Computes the base 10 logarithm of `self`, rounded according to
+    /// `rounding`.
+    ///
+    /// Returns `None` if `self` is not positive.
+    #[inline]
+    pub fn log10_rounded(self, rounding: Rounding) -> Option<usize> {
+        if !self.is_positive() {
+            return None;
+        }
+        Some(self.into_raw().log10_rounded(rounding))
+    }
+}
+
+/// Computes the full `2 * BITS` product of `x` and `y`, as `(high, low)`.
+///
+/// Uses the Chinese Remainder Theorem to reconstruct the high half from the
+/// product modulo `2^BITS` and `2^BITS - 1`, like OpenZeppelin's `mulDiv`.
+#[inline]
+fn full_mul<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    y: Uint<BITS, LIMBS>,
+) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
+    let low = x.wrapping_mul(y);
+    let mm = x.mul_mod(y, Uint::MAX);
+    let mut high = mm.wrapping_sub(low);
+    if mm < low {
+        high = high.wrapping_sub(Uint::from(1));
+    }
+    (high, low)
+}
+
+/// Computes `scale * p^2` as `(high, low)`, saturating at the maximum
+/// `2 * BITS` value.
+#[inline]
+fn scaled_square<const BITS: usize, const LIMBS: usize>(
+    p: Uint<BITS, LIMBS>,
+    scale: Uint<BITS, LIMBS>,
+) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
+    let (high, low) = full_mul(p, p);
+    let (carry, low) = full_mul(low, scale);
+    match high.checked_mul(scale).and_then(|high| high.checked_add(carry)) {
+        Some(high) => (high, low),
+        None => (Uint::MAX, Uint::MAX),
+    }
+}
+
+/// Computes `floor(x * y / denominator)` and `x * y % denominator` with full
+/// precision.
+///
+/// Returns `None` if `denominator` is zero or the result overflows.
+fn mul_div_rem<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    y: Uint<BITS, LIMBS>,
+    denominator: Uint<BITS, LIMBS>,
+) -> Option<(Uint<BITS, LIMBS>, Uint<BITS, LIMBS>)> {
+    if denominator == Uint::ZERO {
+        return None;
+    }
+
+    let (mut high, mut low) = full_mul(x, y);
+    if high == Uint::ZERO {
+        return Some(low.div_rem(denominator));
+    }
+    // the result must be less than `2^BITS`
+    if denominator <= high {
+        return None;
+    }
+
+    // Make the division exact by subtracting the remainder from `[high low]`.
+    let remainder = x.mul_mod(y, denominator);
+    if remainder > low {
+        high -= Uint::from(1);
+    }
+    low = low.wrapping_sub(remainder);
+
+    // Factor powers of two out of `denominator` and divide `[high low]` by
+    // them. `twos` is the largest power of two dividing `denominator`.
+    let twos = denominator & (!denominator).wrapping_add(Uint::from(1));
+    let denominator = denominator / twos;
+    low /= twos;
+    // Shift bits from `high` into `low`: `2^BITS / twos`, which wraps to zero
+    // if `twos` is one.
+    let flip = (Uint::ZERO.wrapping_sub(twos) / twos).wrapping_add(Uint::from(1));
+    low |= high.wrapping_mul(flip);
+
+    // Invert `denominator` modulo `2^BITS`. It is now odd, so it has an
+    // inverse such that `denominator * inverse = 1 mod 2^BITS`. This seed is
+    // correct for the four least significant bits, and every Newton-Raphson
+    // iteration doubles the number of correct bits.
+    let two = Uint::from(2);
+    let mut inverse = denominator.wrapping_mul(Uint::from(3)) ^ two;
+    let mut correct_bits = 4;
+    while correct_bits < BITS {
+        inverse = inverse.wrapping_mul(two.wrapping_sub(denominator.wrapping_mul(inverse)));
+        correct_bits *= 2;
+    }
+
+    // The division is exact, so multiplying by the modular inverse of the
+    // denominator gives the result modulo `2^BITS`, which is the full result
+    // since it is less than `2^BITS`.
+    Some((low.wrapping_mul(inverse), remainder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U512, I256, U256, U64};
+
+    fn u(s: &str) -> U256 {
+        s.parse().unwrap()
+    }
+
+    fn i(v: i64) -> I256 {
+        I256::try_from(v).unwrap()
+    }
+
+    // Expected values were computed with 512-bit integer arithmetic, rounding
+    // down, up, and half up: `floor + (2 * remainder >= denominator)`.
+    #[test]
+    fn mul_div_known() {
+        #[rustfmt::skip]
+        let cases = [
+            ("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", ["0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"]),
+            ("0x100000000000000000000000000000000", "0x100000000000000000000000000000000", "0x3", ["0x5555555555555555555555555555555555555555555555555555555555555555", "0x5555555555555555555555555555555555555555555555555555555555555556", "0x5555555555555555555555555555555555555555555555555555555555555555"]),
+            ("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0x2", "0x3", ["0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"]),
+            ("0x29a2241af62c0000", "0x9b6e64a8ec60000", "0xde0b6b3a7640000", ["0x1d24b2dfac520000", "0x1d24b2dfac520000", "0x1d24b2dfac520000"]),
+            ("0xd23f0824128b2f330c5c7fd0a6a3a4506513270e269e0d37f2a74de452e6b438", "0x36f675cc81e74ef5e8e25d940ed904759531985d5d9dc9f81818e811892f902b", "0x8d116ece1738f7d93d9c172411e20b8f6b0d549b6f03675a1600a35a099950d8", ["0x51ea726584f3723151826cad4d4e7ef77ea2caf9fd90bf8a20cb64f403e5b5cb", "0x51ea726584f3723151826cad4d4e7ef77ea2caf9fd90bf8a20cb64f403e5b5cc", "0x51ea726584f3723151826cad4d4e7ef77ea2caf9fd90bf8a20cb64f403e5b5cc"]),
+            ("0xa170b33839263059f28c105d1fb17c2390c192cfd3ac94af0f21ddb66cad4a26", "0x6595e60af593bd04cf0fd630f1f29d0da9953f48f1a09f76b5", "0xca232217beaddbc496cb8e81973e0becd7b03898d190f9ebdacc0cb1e29c", ["0x51220a07e8c04b1c4e874e745408513fb72751fac93501ac313222", "0x51220a07e8c04b1c4e874e745408513fb72751fac93501ac313223", "0x51220a07e8c04b1c4e874e745408513fb72751fac93501ac313223"]),
+            ("0x6876d4178f6d05584ef8aa38922766581e27a1c08a6a63ec24ede6a46b4cb242", "0x2faab901301850c5a38fd547923a736994e3bf911a61dbe22e44158bae97ba94", "0x5a5cdaef9e7769b10f4205b4907a70c31012f037b64ce4228c38fb2918f135d2", ["0x371b0172fe726b0b198f2096e77d897272f0877392997d6e7199d3a44b105bdf", "0x371b0172fe726b0b198f2096e77d897272f0877392997d6e7199d3a44b105be0", "0x371b0172fe726b0b198f2096e77d897272f0877392997d6e7199d3a44b105be0"]),
+            ("0x8000000000000000000000000000000000000000000000000000000000000001", "0x100000000000000000000000000000000000000000000000003", "0x180000000000000000000000000000000000000000000000007", ["0x55555555555555555555555555555555555555555555555554c71c71c71c71c7", "0x55555555555555555555555555555555555555555555555554c71c71c71c71c8", "0x55555555555555555555555555555555555555555555555554c71c71c71c71c8"]),
+        ];
+        for (x, y, d, [floor, ceil, half_up]) in cases {
+            let (x, y, d) = (u(x), u(y), u(d));
+            let (q, r) = (U512::from(x) * U512::from(y)).div_rem(U512::from(d));
+            assert_eq!(q, U512::from(u(floor)));
+            assert_eq!(r != U512::ZERO, u(ceil) != u(floor));
+            assert_eq!(
+                r * U512::from(2) >= U512::from(d) && r != U512::ZERO,
+                u(half_up) != u(floor)
+            );
+            assert_eq!(x.mul_div(y, d, Rounding::Floor), Some(u(floor)));
+            assert_eq!(x.mul_div(y, d, Rounding::Trunc), Some(u(floor)));
+            assert_eq!(x.mul_div(y, d, Rounding::Ceil), Some(u(ceil)));
+            assert_eq!(x.mul_div(y, d, Rounding::HalfUp), Some(u(half_up)));
+            // commutative
+            assert_eq!(y.mul_div(x, d, Rounding::Ceil), Some(u(ceil)));
+        }
+    }
+
+    #[test]
+    fn mul_div_edge_cases() {
+        let one = U256::from(1);
+        assert_eq!(U256::MAX.mul_div(U256::MAX, U256::ZERO, Rounding::Floor), None);
+        assert_eq!(one.mul_div(one, U256::ZERO, Rounding::Floor), None);
+        assert_eq!(U256::MAX.mul_div(U256::MAX, U256::MAX - one, Rounding::Floor), None);
+        assert_eq!(
+            U256::MAX.mul_div(U256::from(2), U256::from(2), Rounding::Floor),
+            Some(U256::MAX)
+        );
+        let x = U256::MAX - one;
+        assert_eq!(U256::MAX.mul_div(x, U256::MAX, Rounding::Floor), Some(x));
+        assert_eq!(x.mul_div(U256::MAX, x, Rounding::Ceil), Some(U256::MAX));
+
+        // rounding up overflows
+        let x = u("0x21642c8590b21642c8590b21642c8590b21642c8590b21642c8590b21642c859");
+        let (y, d) = (U256::from(23), U256::from(3));
+        assert_eq!(x.mul_div(y, d, Rounding::Floor), Some(U256::MAX));
+        assert_eq!(x.mul_div(y, d, Rounding::Ceil), None);
+        assert_eq!(x.mul_div(y, d, Rounding::HalfUp), None);
+
+        for rounding in [Rounding::Floor, Rounding::Ceil, Rounding::Trunc, Rounding::HalfUp] {
+            assert_eq!(U256::ZERO.mul_div(U256::MAX, one, rounding), Some(U256::ZERO));
+            assert_eq!(U256::MAX.mul_div(one, one, rounding), Some(U256::MAX));
+        }
+
+        // ties
+        let (seven, two) = (U256::from(7), U256::from(2));
+        assert_eq!(seven.mul_div(one, two, Rounding::HalfUp), Some(U256::from(4)));
+        assert_eq!(U256::from(5).mul_div(one, U256::from(4), Rounding::HalfUp), Some(one));
+    }
+
+    // Pseudo-random differential test against a naive 512-bit implementation.
+    #[test]
+    fn mul_div_differential() {
+        let mut state = 0x243f6a8885a308d3u64;
+        let mut next = || {
+            let mut limbs = [0u64; 4];
+            for limb in &mut limbs {
+                // xorshift64
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                *limb = state;
+            }
+            // vary the magnitudes
+            U256::from_limbs(limbs) >> (state % 256) as usize
+        };
+
+        for _ in 0..1000 {
+            let (x, y, d) = (next(), next(), next());
+            let product = U512::from(x) * U512::from(y);
+            if d == U256::ZERO {
+                assert_eq!(x.mul_div(y, d, Rounding::Floor), None);
+                continue;
+            }
+            let (q, r) = product.div_rem(U512::from(d));
+            let expected = |round_up: bool| {
+                let q = q + U512::from(round_up as u8);
+                U256::checked_from_limbs_slice(q.as_limbs())
+            };
+            assert_eq!(x.mul_div(y, d, Rounding::Floor), expected(false));
+            assert_eq!(x.mul_div(y, d, Rounding::Ceil), expected(r != U512::ZERO));
+            assert_eq!(
+                x.mul_div(y, d, Rounding::HalfUp),
+                expected(r != U512::ZERO && r * U512::from(2) >= U512::from(d))
+            );
+        }
+    }
+
+    #[test]
+    fn mul_div_u64() {
+        let mut x = 0x9e3779b97f4a7c15u64;
+        for _ in 0..1000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let (a, b, d) = (x, x.rotate_left(23) >> (x % 64), (x.rotate_left(41) >> (x % 61)) | 1);
+            let product = a as u128 * b as u128;
+            let floor = u64::try_from(product / d as u128).ok().map(U64::from);
+            assert_eq!(U64::from(a).mul_div(U64::from(b), U64::from(d), Rounding::Floor), floor);
+        }
+    }
+
+    #[test]
+    fn mul_div_signed() {
+        let (one, two) = (I256::ONE, i(2));
+        #[rustfmt::skip]
+        let cases = [
+            // x, y, d, [floor, ceil, trunc, half_up]
+            ( 7, 1,  2, [ 3,  4,  3,  4]),
+            (-7, 1,  2, [-4, -3, -3, -4]),
+            ( 7, 1, -2, [-4, -3, -3, -4]),
+            (-7, -1, -2, [-4, -3, -3, -4]),
+            (-5, 1,  4, [-2, -1, -1, -1]),
+            (-6, 1,  4, [-2, -1, -1, -2]),
+            ( 0, 1, -4, [ 0,  0,  0,  0]),
+        ];
+        for (x, y, d, [floor, ceil, trunc, half_up]) in cases {
+            let (x, y, d) = (i(x), i(y), i(d));
+            assert_eq!(x.mul_div(y, d, Rounding::Floor), Some(i(floor)), "{x} {y} {d}");
+            assert_eq!(x.mul_div(y, d, Rounding::Ceil), Some(i(ceil)), "{x} {y} {d}");
+            assert_eq!(x.mul_div(y, d, Rounding::Trunc), Some(i(trunc)), "{x} {y} {d}");
+            assert_eq!(x.mul_div(y, d, Rounding::HalfUp), Some(i(half_up)), "{x} {y} {d}");
+        }
+
+        assert_eq!(I256::MIN.mul_div(one, one, Rounding::Floor), Some(I256::MIN));
+        assert_eq!(I256::MIN.mul_div(one, I256::MINUS_ONE, Rounding::Floor), None);
+        assert_eq!(I256::MIN.mul_div(I256::MINUS_ONE, one, Rounding::Floor), None);
+        assert_eq!(I256::MIN.mul_div(I256::MIN, I256::MIN, Rounding::Floor), Some(I256::MIN));
+        assert_eq!(I256::MAX.mul_div(I256::MAX, I256::MIN, Rounding::Floor), Some(I256::MIN + one));
+        assert_eq!(I256::MAX.mul_div(two, two, Rounding::Ceil), Some(I256::MAX));
+        assert_eq!(one.mul_div(one, I256::ZERO, Rounding::Floor), None);
+    }
+
+    #[test]
+    fn sqrt() {
+        #[rustfmt::skip]
+        let cases = [
+            // value, [floor, ceil, half_up]
+            (U256::ZERO, [U256::ZERO, U256::ZERO, U256::ZERO]),
+            (U256::from(1), [U256::from(1), U256::from(1), U256::from(1)]),
+            (U256::from(2), [U256::from(1), U256::from(2), U256::from(1)]),
+            (U256::from(3), [U256::from(1), U256::from(2), U256::from(2)]),
+            (U256::from(4), [U256::from(2), U256::from(2), U256::from(2)]),
+            (U256::from(6), [U256::from(2), U256::from(3), U256::from(2)]),
+            (U256::from(7), [U256::from(2), U256::from(3), U256::from(3)]),
+            (U256::from(999_999), [U256::from(999), U256::from(1000), U256::from(1000)]),
+            (u("1000000000000000000000000000000000000"), [u("1000000000000000000"), u("1000000000000000000"), u("1000000000000000000")]),
+            (U256::MAX, [U256::MAX >> 128, U256::from(1) << 128, U256::from(1) << 128]),
+            (U256::MAX << 1 >> 1, [u("0xb504f333f9de6484597d89b3754abe9f"), u("0xb504f333f9de6484597d89b3754abea0"), u("0xb504f333f9de6484597d89b3754abe9f")]),
+        ];
+        for (value, [floor, ceil, half_up]) in cases {
+            assert_eq!(value.sqrt_rounded(Rounding::Floor), floor, "{value}");
+            assert_eq!(value.sqrt_rounded(Rounding::Trunc), floor, "{value}");
+            assert_eq!(value.sqrt_rounded(Rounding::Ceil), ceil, "{value}");
+            assert_eq!(value.sqrt_rounded(Rounding::HalfUp), half_up, "{value}");
+        }
+
+        for x in 0..10_000u64 {
+            let floor = U64::from(x).sqrt_rounded(Rounding::Floor).to::<u64>();
+            assert!(floor * floor <= x && (floor + 1) * (floor + 1) > x);
+        }
+
+        assert_eq!(i(-1).sqrt_rounded(Rounding::Floor), None);
+        assert_eq!(i(8).sqrt_rounded(Rounding::HalfUp), Some(i(3)));
+        assert_eq!(
+            I256::MAX.sqrt_rounded(Rounding::Ceil),
+            Some(I256::from_raw(u("0xb504f333f9de6484597d89b3754abea0")))
+        );
+    }
+
+    #[test]
+    fn log2() {
+        #[rustfmt::skip]
+        let cases = [
+            // value, [floor, ceil, half_up]
+            (U256::ZERO, [0, 0, 0]),
+            (U256::from(1), [0, 0, 0]),
+            (U256::from(2), [1, 1, 1]),
+            (U256::from(3), [1, 2, 2]),
+            (U256::from(5), [2, 3, 2]),
+            (U256::from(6), [2, 3, 3]),
+            (U256::from(1024), [10, 10, 10]),
+            (U256::from(1025), [10, 11, 10]),
+            (U256::from(1448), [10, 11, 10]),
+            (U256::from(1449), [10, 11, 11]),
+            (U256::from(1) << 128, [128, 128, 128]),
+            (U256::MAX, [255, 256, 256]),
+        ];
+        for (value, [floor, ceil, half_up]) in cases {
+            assert_eq!(value.log2_rounded(Rounding::Floor), floor, "{value}");
+            assert_eq!(value.log2_rounded(Rounding::Trunc), floor, "{value}");
+            assert_eq!(value.log2_rounded(Rounding::Ceil), ceil, "{value}");
+            assert_eq!(value.log2_rounded(Rounding::HalfUp), half_up, "{value}");
+        }
+
+        assert_eq!(I256::ZERO.log2_rounded(Rounding::Floor), None);
+        assert_eq!(i(-8).log2_rounded(Rounding::Floor), None);
+        assert_eq!(i(1449).log2_rounded(Rounding::HalfUp), Some(11));
+        assert_eq!(I256::MAX.log2_rounded(Rounding::Ceil), Some(255));
+    }
+
+    #[test]
+    fn log10() {
+        #[rustfmt::skip]
+        let cases = [
+            // value, [floor, ceil, half_up]
+            (U256::ZERO, [0, 0, 0]),
+            (U256::from(1), [0, 0, 0]),
+            (U256::from(3), [0, 1, 0]),
+            (U256::from(4), [0, 1, 1]),
+            (U256::from(9), [0, 1, 1]),
+            (U256::from(10), [1, 1, 1]),
+            (U256::from(31), [1, 2, 1]),
+            (U256::from(32), [1, 2, 2]),
+            (U256::from(99), [1, 2, 2]),
+            (U256::from(316), [2, 3, 2]),
+            (U256::from(317), [2, 3, 3]),
+            (u("1000000000000000000"), [18, 18, 18]),
+            (U256::MAX, [77, 78, 77]),
+        ];
+        for (value, [floor, ceil, half_up]) in cases {
+            assert_eq!(value.log10_rounded(Rounding::Floor), floor, "{value}");
+            assert_eq!(value.log10_rounded(Rounding::Trunc), floor, "{value}");
+            assert_eq!(value.log10_rounded(Rounding::Ceil), ceil, "{value}");
+            assert_eq!(value.log10_rounded(Rounding::HalfUp), half_up, "{value}");
+        }
+
+        assert_eq!(I256::ZERO.log10_rounded(Rounding::Floor), None);
+        assert_eq!(i(-100).log10_rounded(Rounding::Floor), None);
+        assert_eq!(i(317).log10_rounded(Rounding::HalfUp), Some(3));
+    }
+}
@@ -0,0 +1,432 @@
+//! Sorted-pair Keccak-256 Merkle trees, compatible with OpenZeppelin's
+//! [`MerkleProof`] library and [`StandardMerkleTree`].
+//!
+//! Internal nodes are the [`keccak256`] hash of their two children, sorted
+//! before concatenation, so proofs do not need to encode the position of each
+//! sibling. Leaves produced by [`standard_leaf_hash`] are double-hashed to
+//! prevent second preimage attacks.
+//!
+//! [`MerkleProof`]: https://docs.openzeppelin.com/contracts/5.x/api/utils#MerkleProof
+//! [`StandardMerkleTree`]: https://github.com/OpenZeppelin/merkle-tree
+
+use crate::{keccak256, B256};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Merkle tree error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleError {
+    /// Cannot build a tree without leaves.
+    Empty,
+    /// The leaf index is out of bounds.
+    IndexOutOfBounds(usize),
+    /// The same leaf index was requested more than once in a multiproof.
+    DuplicateIndex(usize),
+    /// The multiproof is malformed.
+    InvalidMultiProof,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MerkleError {}
+
+impl fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("expected a non-zero number of leaves"),
+            Self::IndexOutOfBounds(i) => write!(f, "leaf index {i} is out of bounds"),
+            Self::DuplicateIndex(i) => write!(f, "cannot prove duplicated leaf index {i}"),
+            Self::InvalidMultiProof => f.write_str("invalid multiproof"),
+        }
+    }
+}
+
+/// Hashes an ABI-encoded value into a leaf, as OpenZeppelin's
+/// `StandardMerkleTree` does: `keccak256(keccak256(abi.encode(...values)))`.
+///
+/// The encoding must be that of `abi.encode` with the values as separate
+/// arguments, i.e. the *parameter* encoding of the value tuple.
+#[inline]
+pub fn standard_leaf_hash<T: AsRef<[u8]>>(encoded: T) -> B256 {
+    keccak256(keccak256(encoded))
+}
+
+/// Hashes a pair of nodes, sorting them first.
+#[inline]
+pub fn hash_pair(a: &B256, b: &B256) -> B256 {
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(a.as_slice());
+    buf[32..].copy_from_slice(b.as_slice());
+    keccak256(buf)
+}
+
+/// Computes the root reconstructed from a leaf and its proof.
+pub fn process_proof(leaf: B256, proof: &[B256]) -> B256 {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling))
+}
+
+/// Returns `true` if `proof` proves that `leaf` is part of the tree with the
+/// given `root`.
+#[inline]
+pub fn verify_proof(root: &B256, leaf: B256, proof: &[B256]) -> bool {
+    process_proof(leaf, proof) == *root
+}
+
+/// A proof that multiple leaves are part of a tree, as accepted by
+/// OpenZeppelin's `MerkleProof.multiProofVerify`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MultiProof {
+    /// The leaves being proven, in the order they are consumed.
+    pub leaves: Vec<B256>,
+    /// The sibling nodes that are not computed from the leaves.
+    pub proof: Vec<B256>,
+    /// For every hashing step, whether the second node is taken from the
+    /// computed nodes (`true`) or from [`proof`](Self::proof) (`false`).
+    pub proof_flags: Vec<bool>,
+}
+
+impl MultiProof {
+    /// Computes the root reconstructed from the multiproof.
+    pub fn process(&self) -> Result<B256, MerkleError> {
+        let Self { leaves, proof, proof_flags } = self;
+        let total = proof_flags.len();
+        if leaves.len() + proof.len() != total + 1 {
+            return Err(MerkleError::InvalidMultiProof);
+        }
+
+        let mut hashes = Vec::with_capacity(total);
+        let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0, 0, 0);
+        let mut next = |hashes: &Vec<B256>| {
+            if leaf_pos < leaves.len() {
+                leaf_pos += 1;
+                Ok(leaves[leaf_pos - 1])
+            } else {
+                hash_pos += 1;
+                hashes.get(hash_pos - 1).copied().ok_or(MerkleError::InvalidMultiProof)
+            }
+        };
+        for &flag in proof_flags {
+            let a = next(&hashes)?;
+            let b = if flag {
+                next(&hashes)?
+            } else {
+                proof_pos += 1;
+                *proof.get(proof_pos - 1).ok_or(MerkleError::InvalidMultiProof)?
+            };
+            hashes.push(hash_pair(&a, &b));
+        }
+
+        match hashes.last() {
+            Some(_) if proof_pos != proof.len() => Err(MerkleError::InvalidMultiProof),
+            Some(root) => Ok(*root),
+            // Exactly one of them holds a single node.
+            None => Ok(leaves.first().or(proof.first()).copied().unwrap()),
+        }
+    }
+
+    /// Returns `true` if the multiproof proves that all of its leaves are part
+    /// of the tree with the given `root`.
+    #[inline]
+    pub fn verify(&self, root: &B256) -> bool {
+        self.process() == Ok(*root)
+    }
+}
+
+/// A sorted-pair Keccak-256 Merkle tree, laid out like OpenZeppelin's
+/// `StandardMerkleTree`.
+///
+/// The tree is stored as a complete binary tree in a flat array, with the root
+/// at index `0` and the leaves at the end, in reverse order. Leaves are
+/// referred to by their index in the input, regardless of their position in
+/// the tree.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{hex, merkle::{self, MerkleTree}};
+///
+/// // `abi.encode(address, uint256)` of each allowlist entry.
+/// let values = [
+///     hex!("00000000000000000000000011111111111111111111111111111111111111110000000000000000000000000000000000000000000000004563918244f40000"),
+///     hex!("000000000000000000000000222222222222222222222222222222222222222200000000000000000000000000000000000000000000000022b1c8c1227a0000"),
+/// ];
+/// let tree = MerkleTree::from_encoded(&values).unwrap();
+/// assert_eq!(
+///     tree.root(),
+///     hex!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77"),
+/// );
+///
+/// let proof = tree.proof(0).unwrap();
+/// assert!(merkle::verify_proof(&tree.root(), tree.leaf(0).unwrap(), &proof));
+///
+/// let multi_proof = tree.multi_proof(&[0, 1]).unwrap();
+/// assert!(multi_proof.verify(&tree.root()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleTree {
+    /// The tree nodes.
+    tree: Vec<B256>,
+    /// The tree index of every leaf, by input index.
+    positions: Vec<usize>,
+}
+
+impl MerkleTree {
+    /// Builds a tree from already hashed leaves, sorting them first, as
+    /// `StandardMerkleTree` does by default.
+    pub fn new(leaves: Vec<B256>) -> Result<Self, MerkleError> {
+        let mut order = (0..leaves.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| leaves[i]);
+        Self::build(leaves, &order)
+    }
+
+    /// Builds a tree from already hashed leaves, keeping them in input order.
+    /// This corresponds to `StandardMerkleTree`'s `sortLeaves: false` option.
+    pub fn new_unsorted(leaves: Vec<B256>) -> Result<Self, MerkleError> {
+        let order = (0..leaves.len()).collect::<Vec<_>>();
+        Self::build(leaves, &order)
+    }
+
+    /// Builds a sorted tree from ABI-encoded values, hashing each of them with
+    /// [`standard_leaf_hash`].
+    ///
+    /// This matches `StandardMerkleTree.of(values, types)`.
+    pub fn from_encoded<I>(values: I) -> Result<Self, MerkleError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        Self::new(values.into_iter().map(standard_leaf_hash).collect())
+    }
+
+    fn build(leaves: Vec<B256>, order: &[usize]) -> Result<Self, MerkleError> {
+        if leaves.is_empty() {
+            return Err(MerkleError::Empty);
+        }
+
+        let len = 2 * leaves.len() - 1;
+        let mut tree = vec![B256::ZERO; len];
+        let mut positions = vec![0; leaves.len()];
+        for (i, &leaf) in order.iter().enumerate() {
+            tree[len - 1 - i] = leaves[leaf];
+            positions[leaf] = len - 1 - i;
+        }
+        for i in (0..len - leaves.len()).rev() {
+            tree[i] = hash_pair(&tree[2 * i + 1], &tree[2 * i + 2]);
+        }
+        Ok(Self { tree, positions })
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> B256 {
+        self.tree[0]
+    }
+
+    /// Returns the number of leaves.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns `true` if the tree has no leaves. This is never the case.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns the hashed leaf at the given input index.
+    #[inline]
+    pub fn leaf(&self, index: usize) -> Option<B256> {
+        self.positions.get(index).map(|&i| self.tree[i])
+    }
+
+    /// Returns the input index of the given hashed leaf, if it is in the tree.
+    pub fn leaf_index(&self, leaf: &B256) -> Option<usize> {
+        self.positions.iter().position(|&i| self.tree[i] == *leaf)
+    }
+
+    /// Returns the tree nodes, with the root first. This is the `tree` field
+    /// of a dumped `StandardMerkleTree`.
+    #[inline]
+    pub fn nodes(&self) -> &[B256] {
+        &self.tree
+    }
+
+    /// Returns the proof for the leaf at the given input index.
+    pub fn proof(&self, index: usize) -> Result<Vec<B256>, MerkleError> {
+        let mut i = *self.positions.get(index).ok_or(MerkleError::IndexOutOfBounds(index))?;
+        let mut proof = Vec::new();
+        while i > 0 {
+            proof.push(self.tree[sibling(i)]);
+            i = (i - 1) / 2;
+        }
+        Ok(proof)
+    }
+
+    /// Returns a multiproof for the leaves at the given input indices.
+    ///
+    /// The leaves of the returned proof are ordered by their position in the
+    /// tree, not by `indices`.
+    pub fn multi_proof(&self, indices: &[usize]) -> Result<MultiProof, MerkleError> {
+        let mut positions = indices
+            .iter()
+            .map(|&index| {
+                self.positions.get(index).copied().ok_or(MerkleError::IndexOutOfBounds(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+        if let Some(w) = positions.windows(2).find(|w| w[0] == w[1]) {
+            let index = self.positions.iter().position(|&i| i == w[0]).unwrap();
+            return Err(MerkleError::DuplicateIndex(index));
+        }
+
+        // The stack is a queue of tree indices in descending order.
+        let mut stack = alloc::collections::VecDeque::from(positions.clone());
+        let mut proof = Vec::new();
+        let mut proof_flags = Vec::new();
+        while let Some(j) = stack.front().copied().filter(|&j| j > 0) {
+            stack.pop_front();
+            let s = sibling(j);
+            if stack.front() == Some(&s) {
+                proof_flags.push(true);
+                stack.pop_front();
+            } else {
+                proof_flags.push(false);
+                proof.push(self.tree[s]);
+            }
+            stack.push_back((j - 1) / 2);
+        }
+        if positions.is_empty() {
+            proof.push(self.tree[0]);
+        }
+
+        let leaves = positions.iter().map(|&i| self.tree[i]).collect();
+        Ok(MultiProof { leaves, proof, proof_flags })
+    }
+}
+
+#[inline]
+const fn sibling(i: usize) -> usize {
+    if i % 2 == 1 {
+        i + 1
+    } else {
+        i - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Address, U256};
+
+    fn encode(address: Address, amount: u64) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(address.into_word().as_slice());
+        out[32..].copy_from_slice(&U256::from(amount).to_be_bytes::<32>());
+        out
+    }
+
+    fn leaves(n: usize) -> Vec<B256> {
+        (0..n).map(|i| standard_leaf_hash((i as u64).to_be_bytes())).collect()
+    }
+
+    #[test]
+    fn standard_tree() {
+        let values = [
+            encode(Address::repeat_byte(0x11), 5_000_000_000_000_000_000),
+            encode(Address::repeat_byte(0x22), 2_500_000_000_000_000_000),
+        ];
+        let tree = MerkleTree::from_encoded(values).unwrap();
+        assert_eq!(
+            tree.root(),
+            hex!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77")
+        );
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.nodes().len(), 3);
+        assert_eq!(tree.leaf(0), Some(standard_leaf_hash(values[0])));
+        assert_eq!(tree.leaf_index(&standard_leaf_hash(values[1])), Some(1));
+        assert_eq!(tree.leaf(2), None);
+        assert_eq!(tree.proof(0).unwrap(), [tree.leaf(1).unwrap()]);
+        assert_eq!(tree.proof(2), Err(MerkleError::IndexOutOfBounds(2)));
+    }
+
+    #[test]
+    fn layout() {
+        let leaves = leaves(3);
+        let tree = MerkleTree::new_unsorted(leaves.clone()).unwrap();
+        let nodes = tree.nodes();
+        assert_eq!(nodes[4], leaves[0]);
+        assert_eq!(nodes[3], leaves[1]);
+        assert_eq!(nodes[2], leaves[2]);
+        assert_eq!(nodes[1], hash_pair(&leaves[0], &leaves[1]));
+        assert_eq!(nodes[0], hash_pair(&nodes[1], &leaves[2]));
+
+        let mut sorted = leaves.clone();
+        sorted.sort();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        assert_eq!(tree.root(), MerkleTree::new_unsorted(sorted).unwrap().root());
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(tree.leaf(i), Some(*leaf));
+        }
+
+        let single = MerkleTree::new(vec![leaves[0]]).unwrap();
+        assert_eq!(single.root(), leaves[0]);
+        assert!(single.proof(0).unwrap().is_empty());
+
+        assert_eq!(MerkleTree::new(vec![]), Err(MerkleError::Empty));
+    }
+
+    #[test]
+    fn proofs() {
+        for n in 1..=33 {
+            let leaves = leaves(n);
+            let tree = MerkleTree::new(leaves.clone()).unwrap();
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+                assert!(verify_proof(&tree.root(), *leaf, &proof), "{n} {i}");
+                assert!(!verify_proof(&tree.root(), B256::ZERO, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn multi_proofs() {
+        for n in 1..=12 {
+            let leaves = leaves(n);
+            let tree = MerkleTree::new(leaves.clone()).unwrap();
+            // every subset of leaves
+            for mask in 0u32..(1 << n) {
+                let indices = (0..n).filter(|i| mask & (1 << i) != 0).collect::<Vec<_>>();
+                let proof = tree.multi_proof(&indices).unwrap();
+                assert_eq!(proof.process(), Ok(tree.root()), "{n} {indices:?}");
+                assert_eq!(proof.leaves.len(), indices.len());
+                assert!(indices.iter().all(|&i| proof.leaves.contains(&leaves[i])));
+
+                if !proof.leaves.is_empty() && n > 1 {
+                    let mut bad = proof.clone();
+                    bad.leaves[0] = B256::ZERO;
+                    assert!(!bad.verify(&tree.root()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_multi_proofs() {
+        let tree = MerkleTree::new(leaves(5)).unwrap();
+        assert_eq!(tree.multi_proof(&[1, 5]), Err(MerkleError::IndexOutOfBounds(5)));
+        assert_eq!(tree.multi_proof(&[3, 1, 3]), Err(MerkleError::DuplicateIndex(3)));
+
+        let proof = tree.multi_proof(&[0, 2]).unwrap();
+
+        let mut bad = proof.clone();
+        bad.proof.push(B256::ZERO);
+        assert_eq!(bad.process(), Err(MerkleError::InvalidMultiProof));
+
+        let mut bad = proof.clone();
+        bad.proof_flags.iter_mut().for_each(|f| *f = !*f);
+        assert!(!bad.verify(&tree.root()));
+
+        assert_eq!(MultiProof::default().process(), Err(MerkleError::InvalidMultiProof));
+    }
+}
@@ -0,0 +1,109 @@
+//! Support for the [`parity-scale-codec`](https://crates.io/crates/parity-scale-codec)
+//! crate.
+//!
+//! Layouts are as follows:
+//! - [`FixedBytes`](crate::FixedBytes), [`Address`](crate::Address) and
+//!   [`Bloom`](crate::Bloom) are written as their raw bytes, like `[u8; N]`.
+//! - [`Bytes`](crate::Bytes) is written like `Vec<u8>`: a compact length
+//!   followed by the bytes.
+//! - [`Signed`](crate::Signed) is written as the fixed-width little-endian
+//!   bytes of its two's complement representation, like Substrate's `I256`.
+//!
+//! [`Uint`] is defined in [`ruint`], whose own SCALE implementation writes a
+//! compact-length-prefixed byte vector. Fields of that type can use
+//! [`encode_uint`] and [`decode_uint`] instead, which write the same
+//! fixed-width little-endian layout as [`Signed`](crate::Signed) and
+//! Substrate's `U256`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{
+//!     scale::{decode_uint, encode_uint},
+//!     Address, U256,
+//! };
+//! use parity_scale_codec::{Decode, Encode, Error, Input, Output};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Transfer {
+//!     to: Address,
+//!     amount: U256,
+//! }
+//!
+//! impl Encode for Transfer {
+//!     fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+//!         self.to.encode_to(dest);
+//!         encode_uint(&self.amount, dest);
+//!     }
+//! }
+//!
+//! impl Decode for Transfer {
+//!     fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+//!         Ok(Self { to: Address::decode(input)?, amount: decode_uint(input)? })
+//!     }
+//! }
+//!
+//! let transfer = Transfer { to: Address::repeat_byte(0x11), amount: U256::from(1000) };
+//! let encoded = transfer.encode();
+//! assert_eq!(encoded.len(), 20 + 32);
+//! assert_eq!(&encoded[20..22], &[0xe8, 0x03]);
+//! assert_eq!(Transfer::decode(&mut &encoded[..])?, transfer);
+//! # Ok::<(), Error>(())
+//! ```
+
+use crate::Uint;
+use parity_scale_codec::{Error, Input, Output};
+
+/// Writes a [`Uint`] as its fixed-width little-endian bytes.
+#[inline]
+pub fn encode_uint<T: Output + ?Sized, const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    dest: &mut T,
+) {
+    dest.write(&value.as_le_bytes());
+}
+
+/// Reads a [`Uint`] from its fixed-width little-endian bytes.
+///
+/// Fails if the bytes encode a value that does not fit in `BITS` bits.
+#[inline]
+pub fn decode_uint<I: Input, const BITS: usize, const LIMBS: usize>(
+    input: &mut I,
+) -> Result<Uint<BITS, LIMBS>, Error> {
+    let mut bytes = vec![0; Uint::<BITS, LIMBS>::BYTES];
+    input.read(&mut bytes)?;
+    Uint::try_from_le_slice(&bytes).ok_or_else(|| "value out of range".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U8, hex, U256};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn uint_layout() {
+        // `primitive_types::U256::from(0x0102030405060708u64).encode()`
+        let encoded = hex!("0807060504030201000000000000000000000000000000000000000000000000");
+        let value = U256::from(0x0102030405060708u64);
+        let mut dest = Vec::new();
+        encode_uint(&value, &mut dest);
+        assert_eq!(dest, encoded);
+        assert_eq!(decode_uint::<_, 256, 4>(&mut &encoded[..]).unwrap(), value);
+
+        assert!(decode_uint::<_, 256, 4>(&mut &encoded[..31]).is_err());
+        assert!(decode_uint::<_, 7, 1>(&mut &[0x80][..]).is_err());
+        assert_eq!(decode_uint::<_, 8, 1>(&mut &[0x80][..]).unwrap(), U8::from(0x80));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn uint_roundtrip() {
+        proptest::proptest!(|(value: U256)| {
+            let mut encoded = Vec::new();
+            encode_uint(&value, &mut encoded);
+            proptest::prop_assert_eq!(encoded.len(), 32);
+            proptest::prop_assert_eq!(decode_uint(&mut &encoded[..]).unwrap(), value);
+        });
+    }
+}
@@ -0,0 +1,329 @@
+use super::{Sealable, Sealed};
+use crate::B256;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+// Same as `std::sync::OnceLock`, which was stabilized after the MSRV.
+use once_cell::sync::OnceCell;
+
+/// A consensus hashable item, whose hash is computed on first access.
+///
+/// Unlike [`Sealed`], constructing a `LazySealed` is free: the hash is only
+/// computed with [`Sealable::hash_slow`] the first time [`seal`](Self::seal)
+/// is called, and then cached. The cache is thread-safe, so a `LazySealed<T>`
+/// is [`Sync`] whenever `T` is.
+///
+/// Mutable access to the inner item goes through [`inner_mut`](Self::inner_mut),
+/// which invalidates the cached hash as soon as the item is mutated.
+///
+/// Two values are equal if their hashes are equal.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{keccak256, B256, LazySealed, Sealable};
+///
+/// #[derive(Debug)]
+/// struct Block(Vec<u8>);
+///
+/// impl Sealable for Block {
+///     fn hash_slow(&self) -> B256 {
+///         keccak256(&self.0)
+///     }
+/// }
+///
+/// let mut block = LazySealed::new(Block(vec![1, 2, 3]));
+/// assert_eq!(block.cached_seal(), None);
+/// assert_eq!(block.seal(), keccak256([1, 2, 3]));
+/// assert!(block.cached_seal().is_some());
+///
+/// block.inner_mut().0.push(4);
+/// assert_eq!(block.cached_seal(), None);
+/// assert_eq!(block.seal(), keccak256([1, 2, 3, 4]));
+/// ```
+pub struct LazySealed<T> {
+    /// The inner item.
+    inner: T,
+    /// Its hash, if already computed.
+    seal: OnceCell<B256>,
+}
+
+impl<T: Clone> Clone for LazySealed<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), seal: self.seal.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LazySealed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazySealed")
+            .field("inner", &self.inner)
+            .field("seal", &self.seal.get())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for LazySealed<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Deref for LazySealed<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner()
+    }
+}
+
+impl<T: Sealable> PartialEq for LazySealed<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.seal() == other.seal()
+    }
+}
+
+impl<T: Sealable> Eq for LazySealed<T> {}
+
+impl<T: Sealable> Hash for LazySealed<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seal().hash(state);
+    }
+}
+
+impl<T> From<Sealed<T>> for LazySealed<T> {
+    #[inline]
+    fn from(sealed: Sealed<T>) -> Self {
+        let (inner, seal) = sealed.into_parts();
+        Self { inner, seal: OnceCell::with_value(seal) }
+    }
+}
+
+impl<T: Sealable> From<LazySealed<T>> for Sealed<T> {
+    #[inline]
+    fn from(sealed: LazySealed<T>) -> Self {
+        sealed.into_sealed()
+    }
+}
+
+impl<T> LazySealed<T> {
+    /// Wraps the item without computing its hash.
+    #[inline]
+    pub const fn new(inner: T) -> Self {
+        Self { inner, seal: OnceCell::new() }
+    }
+
+    /// Get the inner item.
+    #[inline(always)]
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Get mutable access to the inner item.
+    ///
+    /// The cached hash is cleared the first time the returned guard is
+    /// dereferenced mutably.
+    #[inline]
+    pub fn inner_mut(&mut self) -> LazySealedMut<'_, T> {
+        LazySealedMut { sealed: self }
+    }
+
+    /// Get the hash if it has already been computed.
+    #[inline]
+    pub fn cached_seal(&self) -> Option<B256> {
+        self.seal.get().copied()
+    }
+
+    /// Unseal the inner item, discarding the hash.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // false positive
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Sealable> LazySealed<T> {
+    /// Get the hash, computing it if necessary. The first call may be slow.
+    #[inline]
+    pub fn seal(&self) -> B256 {
+        *self.seal.get_or_init(|| self.inner.hash_slow())
+    }
+
+    /// Convert into a [`Sealed`], computing the hash if necessary.
+    #[inline]
+    pub fn into_sealed(self) -> Sealed<T> {
+        let seal = self.seal();
+        Sealed::new_unchecked(self.inner, seal)
+    }
+}
+
+/// Mutable access to the item of a [`LazySealed`].
+///
+/// Created by [`LazySealed::inner_mut`]. Mutably dereferencing the guard
+/// clears the cached hash, so that it is recomputed on the next call to
+/// [`LazySealed::seal`].
+pub struct LazySealedMut<'a, T> {
+    sealed: &'a mut LazySealed<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for LazySealedMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazySealedMut").field(&self.sealed.inner).finish()
+    }
+}
+
+impl<T> Deref for LazySealedMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.sealed.inner
+    }
+}
+
+impl<T> DerefMut for LazySealedMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.sealed.seal.take();
+        &mut self.sealed.inner
+    }
+}
+
+#[cfg(feature = "rlp")]
+impl<T: alloy_rlp::Encodable> alloy_rlp::Encodable for LazySealed<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.inner.length()
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn bytes::BufMut) {
+        self.inner.encode(out)
+    }
+}
+
+#[cfg(feature = "rlp")]
+impl<T: alloy_rlp::Decodable> alloy_rlp::Decodable for LazySealed<T> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        T::decode(buf).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LazySealed<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for LazySealed<T> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keccak256;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone, Debug, Default)]
+    struct Counted {
+        data: Vec<u8>,
+        hashes: std::sync::Arc<AtomicUsize>,
+    }
+
+    impl Sealable for Counted {
+        fn hash_slow(&self) -> B256 {
+            self.hashes.fetch_add(1, Ordering::Relaxed);
+            keccak256(&self.data)
+        }
+    }
+
+    #[test]
+    fn computes_once() {
+        let sealed = LazySealed::new(Counted { data: vec![1, 2, 3], ..Default::default() });
+        assert_eq!(sealed.hashes.load(Ordering::Relaxed), 0);
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| assert_eq!(sealed.seal(), keccak256([1, 2, 3])));
+            }
+        });
+        assert_eq!(sealed.hashes.load(Ordering::Relaxed), 1);
+        assert_eq!(sealed.clone().into_sealed().seal(), keccak256([1, 2, 3]));
+        assert_eq!(sealed.hashes.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn mutation_invalidates() {
+        let mut sealed = LazySealed::new(Counted { data: vec![1], ..Default::default() });
+        let seal = sealed.seal();
+
+        // shared access through the guard keeps the cache
+        assert_eq!(sealed.inner_mut().data.len(), 1);
+        assert_eq!(sealed.cached_seal(), Some(seal));
+
+        sealed.inner_mut().data.push(2);
+        assert_eq!(sealed.cached_seal(), None);
+        assert_eq!(sealed.seal(), keccak256([1, 2]));
+        assert_eq!(sealed.hashes.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn equality_by_hash() {
+        let a = LazySealed::new(Counted { data: vec![1], ..Default::default() });
+        let b = LazySealed::new(Counted { data: vec![1], ..Default::default() });
+        let c = LazySealed::new(Counted { data: vec![2], ..Default::default() });
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let sealed = Counted { data: vec![2], ..Default::default() }.seal_slow();
+        let lazy = LazySealed::from(sealed);
+        assert_eq!(lazy.cached_seal(), Some(keccak256([2])));
+        assert_eq!(lazy, c);
+        assert_eq!(lazy.hashes.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn is_sync() {
+        fn assert_sync<T: Sync + Send>() {}
+        assert_sync::<LazySealed<Vec<u8>>>();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_passthrough() {
+        let sealed = LazySealed::new(crate::Bytes::from_static(&[1, 2]));
+        let json = serde_json::to_string(&sealed).unwrap();
+        assert_eq!(json, r#""0x0102""#);
+        let decoded: LazySealed<crate::Bytes> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*decoded, *sealed);
+    }
+
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn rlp_passthrough() {
+        use alloy_rlp::{Decodable, Encodable};
+
+        let sealed = LazySealed::new(crate::Bytes::from_static(&[1, 2]));
+        let mut encoded = Vec::new();
+        sealed.encode(&mut encoded);
+        assert_eq!(encoded, alloy_rlp::encode(&*sealed));
+        assert_eq!(sealed.length(), encoded.len());
+        let decoded = LazySealed::<crate::Bytes>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(*decoded, *sealed);
+    }
+}
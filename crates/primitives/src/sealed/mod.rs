@@ -1,5 +1,10 @@
 use crate::B256;
 
+#[cfg(feature = "std")]
+mod lazy;
+#[cfg(feature = "std")]
+pub use lazy::{LazySealed, LazySealedMut};
+
 /// A consensus hashable item, with its memoized hash.
 ///
 /// We do not implement any specific hashing algorithm here. Instead types
@@ -0,0 +1,113 @@
+//! Byte containers as standard, padded base64 strings.
+//!
+//! Works with any [`ByteContainer`].
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, Bytes};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Blob(#[serde(with = "serde_helpers::base64")] Bytes);
+//!
+//! let blob = Blob(Bytes::from_static(b"hello"));
+//! let json = serde_json::to_string(&blob)?;
+//! assert_eq!(json, r#""aGVsbG8=""#);
+//! assert_eq!(serde_json::from_str::<Blob>(&json)?, blob);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::{option_and_vec, ByteContainer, Repr};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use core::{fmt, marker::PhantomData};
+use serde::{de, Deserializer, Serializer};
+
+struct Base64;
+
+impl<T: ByteContainer> Repr<T> for Base64 {
+    #[inline]
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(value, serializer)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Serializes a byte container as a base64 string.
+#[inline]
+pub fn serialize<T: ByteContainer, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(value.as_byte_slice()))
+}
+
+/// Deserializes a byte container from a base64 string.
+pub fn deserialize<'de, T: ByteContainer, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct Base64Visitor<T>(PhantomData<T>);
+
+    impl<T: ByteContainer> de::Visitor<'_> for Base64Visitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a base64 string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            T::from_byte_vec(STANDARD.decode(v).map_err(E::custom)?)
+        }
+    }
+
+    deserializer.deserialize_str(Base64Visitor(PhantomData))
+}
+
+option_and_vec!(Base64, [T: ByteContainer], T);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bytes, FixedBytes};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        bytes: Bytes,
+        #[serde(with = "super")]
+        fixed: FixedBytes<4>,
+        #[serde(with = "super::option")]
+        address: Option<Address>,
+        #[serde(with = "super::vec")]
+        list: Vec<Bytes>,
+    }
+
+    #[test]
+    fn json() {
+        let value = Value {
+            bytes: Bytes::from_static(b"hello"),
+            fixed: FixedBytes(*b"\xde\xad\xbe\xef"),
+            address: Some(Address::ZERO),
+            list: vec![Bytes::new(), Bytes::from_static(b"a")],
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"bytes":"aGVsbG8=","fixed":"3q2+7w==","address":"AAAAAAAAAAAAAAAAAAAAAAAAAAA=","list":["","YQ=="]}"#
+        );
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+
+        for invalid in [
+            r#"{"bytes":"aGVsbG8","fixed":"3q2+7w==","address":null,"list":[]}"#,
+            r#"{"bytes":"","fixed":"3q2+","address":null,"list":[]}"#,
+            r#"{"bytes":"","fixed":"3q2+7w==","address":null,"list":[[1]]}"#,
+        ] {
+            assert!(serde_json::from_str::<Value>(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Integers as decimal strings.
+//!
+//! Works with any type that formats as a decimal number with
+//! [`Display`](fmt::Display) and parses with [`FromStr`], such as
+//! [`Uint`](crate::Uint), [`Signed`](crate::Signed) and the primitive integer
+//! types. Values are always serialized as strings. In human-readable formats,
+//! JSON-style integer literals are accepted as well.
+//!
+//! Only decimal digits with an optional leading `-` are accepted when
+//! deserializing, even if the type's [`FromStr`] implementation also accepts
+//! other formats, such as `0x` hexadecimal strings.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Amount(#[serde(with = "serde_helpers::decimal")] U256);
+//!
+//! let amount = Amount(U256::from(1) << 128);
+//! let json = serde_json::to_string(&amount)?;
+//! assert_eq!(json, r#""340282366920938463463374607431768211456""#);
+//! assert_eq!(serde_json::from_str::<Amount>(&json)?, amount);
+//! assert_eq!(serde_json::from_str::<Amount>("1000")?, Amount(U256::from(1000)));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::{option_and_vec, Repr};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{de, Deserializer, Serializer};
+
+struct Decimal;
+
+impl<T> Repr<T> for Decimal
+where
+    T: fmt::Display + FromStr,
+    T::Err: fmt::Display,
+{
+    #[inline]
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(value, serializer)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Serializes an integer as a decimal string.
+#[inline]
+pub fn serialize<T: fmt::Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserializes an integer from a decimal string.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    struct DecimalVisitor<T>(PhantomData<T>);
+
+    impl<T> de::Visitor<'_> for DecimalVisitor<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a decimal integer")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let digits = v.strip_prefix('-').unwrap_or(v);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            self.visit_str(itoa::Buffer::new().format(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            self.visit_str(itoa::Buffer::new().format(v))
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            self.visit_str(itoa::Buffer::new().format(v))
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            self.visit_str(itoa::Buffer::new().format(v))
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(DecimalVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_str(DecimalVisitor(PhantomData))
+    }
+}
+
+option_and_vec!(Decimal, [T: fmt::Display + FromStr], T, where T::Err: fmt::Display);
+
+#[cfg(test)]
+mod tests {
+    use crate::{I256, U256};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        unsigned: U256,
+        #[serde(with = "super")]
+        signed: I256,
+        #[serde(with = "super::option")]
+        optional: Option<u128>,
+        #[serde(with = "super::vec")]
+        list: Vec<U256>,
+    }
+
+    #[test]
+    fn json() {
+        let value = Value {
+            unsigned: U256::MAX,
+            signed: I256::MINUS_ONE,
+            optional: Some(u128::MAX),
+            list: vec![U256::ZERO, U256::from(10)],
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"unsigned":"{}","signed":"-1","optional":"{}","list":["0","10"]}}"#,
+                U256::MAX,
+                u128::MAX
+            )
+        );
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+
+        let value: Value =
+            serde_json::from_str(r#"{"unsigned":1,"signed":-2,"optional":null,"list":[3,"4"]}"#)
+                .unwrap();
+        assert_eq!(value.unsigned, U256::from(1));
+        assert_eq!(value.signed, I256::try_from(-2).unwrap());
+        assert_eq!(value.optional, None);
+        assert_eq!(value.list, [U256::from(3), U256::from(4)]);
+
+        assert!(serde_json::from_str::<Value>(
+            r#"{"unsigned":"-1","signed":"0","optional":null,"list":[]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn decimal_only() {
+        #[derive(Debug, Deserialize)]
+        struct Amount(#[serde(with = "super")] U256);
+
+        #[derive(Debug, Deserialize)]
+        struct Delta(#[serde(with = "super")] I256);
+
+        assert_eq!(serde_json::from_str::<Amount>(r#""10""#).unwrap().0, U256::from(10));
+        assert_eq!(
+            serde_json::from_str::<Delta>(r#""-10""#).unwrap().0,
+            I256::try_from(-10).unwrap()
+        );
+        for s in
+            [r#""0x10""#, r#""0o10""#, r#""0b10""#, r#""1_0""#, r#""+10""#, r#""""#, r#"" 10""#]
+        {
+            assert!(serde_json::from_str::<Amount>(s).is_err(), "{s}");
+        }
+        for s in [r#""0x10""#, r#""-0x10""#, r#""-""#, r#""--1""#] {
+            assert!(serde_json::from_str::<Delta>(s).is_err(), "{s}");
+        }
+
+        let err = serde_json::from_str::<Amount>(r#""0x10""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value: string \"0x10\", expected a decimal integer at line 1 column 6"
+        );
+    }
+
+    #[test]
+    fn bincode() {
+        let value = Value {
+            unsigned: U256::from(1000),
+            signed: I256::MINUS_ONE,
+            optional: None,
+            list: vec![U256::from(1)],
+        };
+        let encoded = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Value>(&encoded).unwrap(), value);
+    }
+}
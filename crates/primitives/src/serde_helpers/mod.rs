@@ -0,0 +1,246 @@
+//! Serde adapters for alternate wire formats.
+//!
+//! By default, [`Bytes`], [`FixedBytes`] and [`Uint`](crate::Uint) serialize
+//! as `0x`-prefixed hex strings in human-readable formats. The modules in
+//! this one can be used with `#[serde(with = "...")]` to pick a different
+//! representation for a single field:
+//!
+//! - [`base64`]: byte containers as standard, padded base64 strings. Requires
+//!   the `base64` feature.
+//! - [`decimal`]: integers as decimal strings.
+//! - [`quantity`]: [`Uint`](crate::Uint)s as `0x`-prefixed hex strings
+//!   without leading zeros, as used by the Ethereum JSON-RPC API.
+//! - [`raw_bytes`]: byte containers as raw bytes, even in human-readable
+//!   formats.
+//!
+//! Each module has `option` and `vec` submodules for `Option<T>` and `Vec<T>`
+//! fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, Bytes, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Balance {
+//!     #[serde(with = "serde_helpers::decimal")]
+//!     amount: U256,
+//!     #[serde(with = "serde_helpers::quantity::option")]
+//!     nonce: Option<U256>,
+//!     #[serde(with = "serde_helpers::raw_bytes::vec")]
+//!     proofs: Vec<Bytes>,
+//! }
+//!
+//! let balance = Balance {
+//!     amount: U256::from(1000),
+//!     nonce: Some(U256::from(16)),
+//!     proofs: vec![Bytes::from_static(&[1, 2])],
+//! };
+//! let json = serde_json::to_string(&balance)?;
+//! assert_eq!(json, r#"{"amount":"1000","nonce":"0x10","proofs":[[1,2]]}"#);
+//! assert_eq!(serde_json::from_str::<Balance>(&json)?, balance);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{Address, Bloom, Bytes, FixedBytes};
+use alloc::{format, vec::Vec};
+use core::marker::PhantomData;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "base64")]
+pub mod base64;
+
+pub mod decimal;
+
+pub mod quantity;
+
+pub mod raw_bytes;
+
+/// A byte container that can be used with the [`base64`] and [`raw_bytes`]
+/// adapters.
+pub trait ByteContainer: Sized {
+    /// Returns the contents as a byte slice.
+    fn as_byte_slice(&self) -> &[u8];
+
+    /// Creates the container from decoded bytes, failing if their length is
+    /// not valid for the container.
+    fn from_byte_vec<E: de::Error>(bytes: Vec<u8>) -> Result<Self, E>;
+}
+
+impl ByteContainer for Vec<u8> {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn from_byte_vec<E: de::Error>(bytes: Vec<u8>) -> Result<Self, E> {
+        Ok(bytes)
+    }
+}
+
+impl ByteContainer for Bytes {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn from_byte_vec<E: de::Error>(bytes: Vec<u8>) -> Result<Self, E> {
+        Ok(bytes.into())
+    }
+}
+
+impl<const N: usize> ByteContainer for FixedBytes<N> {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    #[inline]
+    fn from_byte_vec<E: de::Error>(bytes: Vec<u8>) -> Result<Self, E> {
+        Self::try_from(bytes.as_slice())
+            .map_err(|_| E::invalid_length(bytes.len(), &format!("exactly {N} bytes").as_str()))
+    }
+}
+
+macro_rules! impl_byte_container_for_wrappers {
+    ($($t:ty),+) => {$(
+        impl ByteContainer for $t {
+            #[inline]
+            fn as_byte_slice(&self) -> &[u8] {
+                self.as_slice()
+            }
+
+            #[inline]
+            fn from_byte_vec<E: de::Error>(bytes: Vec<u8>) -> Result<Self, E> {
+                ByteContainer::from_byte_vec(bytes).map(Self)
+            }
+        }
+    )+};
+}
+
+impl_byte_container_for_wrappers!(Address, Bloom);
+
+/// A representation of `T`, implemented by the marker types of the adapter
+/// modules so that their `option` and `vec` variants can share one
+/// implementation.
+trait Repr<T> {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+}
+
+struct SerializeAs<'a, R, T>(&'a T, PhantomData<R>);
+
+impl<R: Repr<T>, T> Serialize for SerializeAs<'_, R, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        R::serialize(self.0, serializer)
+    }
+}
+
+struct DeserializeAs<R, T>(T, PhantomData<R>);
+
+impl<'de, R: Repr<T>, T> Deserialize<'de> for DeserializeAs<R, T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        R::deserialize(deserializer).map(|value| Self(value, PhantomData))
+    }
+}
+
+#[inline]
+fn serialize_option<R: Repr<T>, T, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(&SerializeAs::<R, T>(value, PhantomData)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[inline]
+fn deserialize_option<'de, R: Repr<T>, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Option::<DeserializeAs<R, T>>::deserialize(deserializer).map(|value| value.map(|value| value.0))
+}
+
+#[inline]
+fn serialize_vec<R: Repr<T>, T, S: Serializer>(
+    values: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|value| SerializeAs::<R, T>(value, PhantomData)))
+}
+
+#[inline]
+fn deserialize_vec<'de, R: Repr<T>, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    Vec::<DeserializeAs<R, T>>::deserialize(deserializer)
+        .map(|values| values.into_iter().map(|value| value.0).collect())
+}
+
+/// Implements the `option` and `vec` submodules of an adapter module, given
+/// its [`Repr`] marker type and the bounds on the represented type.
+macro_rules! option_and_vec {
+    ($repr:ty, [$($generics:tt)*], $t:ty $(, where $($bounds:tt)*)?) => {
+        /// Adapter for `Option` fields.
+        pub mod option {
+            use super::*;
+
+            /// Serializes an optional value.
+            #[inline]
+            pub fn serialize<$($generics)*, S: Serializer>(
+                value: &Option<$t>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            $(where $($bounds)*)?
+            {
+                $crate::serde_helpers::serialize_option::<$repr, _, _>(value, serializer)
+            }
+
+            /// Deserializes an optional value.
+            #[inline]
+            pub fn deserialize<'de, $($generics)*, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$t>, D::Error>
+            $(where $($bounds)*)?
+            {
+                $crate::serde_helpers::deserialize_option::<$repr, _, _>(deserializer)
+            }
+        }
+
+        /// Adapter for `Vec` fields.
+        pub mod vec {
+            use super::*;
+            use alloc::vec::Vec;
+
+            /// Serializes a sequence of values.
+            #[inline]
+            pub fn serialize<$($generics)*, S: Serializer>(
+                values: &[$t],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            $(where $($bounds)*)?
+            {
+                $crate::serde_helpers::serialize_vec::<$repr, _, _>(values, serializer)
+            }
+
+            /// Deserializes a sequence of values.
+            #[inline]
+            pub fn deserialize<'de, $($generics)*, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<$t>, D::Error>
+            $(where $($bounds)*)?
+            {
+                $crate::serde_helpers::deserialize_vec::<$repr, _, _>(deserializer)
+            }
+        }
+    };
+}
+
+use option_and_vec;
@@ -0,0 +1,147 @@
+//! [`Uint`]s as Ethereum JSON-RPC quantities.
+//!
+//! A quantity is a `0x`-prefixed, lowercase hex string without leading zeros,
+//! with zero written as `0x0`. Unlike the default [`Uint`] implementation,
+//! this representation is used in binary formats as well. Deserialization
+//! rejects values without the prefix, with no digits, or with leading zeros.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, U64};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "serde_helpers::quantity")]
+//!     number: U64,
+//! }
+//!
+//! let block = Block { number: U64::from(1024) };
+//! let json = serde_json::to_string(&block)?;
+//! assert_eq!(json, r#"{"number":"0x400"}"#);
+//! assert_eq!(serde_json::from_str::<Block>(&json)?, block);
+//! assert!(serde_json::from_str::<Block>(r#"{"number":"0x0400"}"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::{option_and_vec, Repr};
+use crate::Uint;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+struct Quantity;
+
+impl<const BITS: usize, const LIMBS: usize> Repr<Uint<BITS, LIMBS>> for Quantity {
+    #[inline]
+    fn serialize<S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize(value, serializer)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Serializes a [`Uint`] as a quantity.
+#[inline]
+pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+    value: &Uint<BITS, LIMBS>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{value:#x}"))
+}
+
+/// Deserializes a [`Uint`] from a quantity.
+pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Uint<BITS, LIMBS>, D::Error> {
+    struct QuantityVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<const BITS: usize, const LIMBS: usize> de::Visitor<'_> for QuantityVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a 0x-prefixed hex quantity without leading zeros")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let digits = v
+                .strip_prefix("0x")
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+            if digits.is_empty()
+                || (digits.len() > 1 && digits.starts_with('0'))
+                || !digits.bytes().all(|b| b.is_ascii_hexdigit())
+            {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            Uint::from_str_radix(digits, 16).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(QuantityVisitor)
+}
+
+option_and_vec!(Quantity, [const BITS: usize, const LIMBS: usize], Uint<BITS, LIMBS>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{U256, U64};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        number: U64,
+        #[serde(with = "super::option")]
+        optional: Option<U256>,
+        #[serde(with = "super::vec")]
+        list: Vec<U256>,
+    }
+
+    #[test]
+    fn json() {
+        let value = Value {
+            number: U64::ZERO,
+            optional: Some(U256::MAX),
+            list: vec![U256::from(1), U256::from(0x1234)],
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"number":"0x0","optional":"0x{}","list":["0x1","0x1234"]}}"#,
+                "f".repeat(64)
+            )
+        );
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+
+        let value: Value =
+            serde_json::from_str(r#"{"number":"0xABC","optional":null,"list":[]}"#).unwrap();
+        assert_eq!(value.number, U64::from(0xabc));
+        assert_eq!(value.optional, None);
+    }
+
+    #[test]
+    fn invalid() {
+        for number in ["", "0x", "0x00", "0x01", "10", "0xg", "0x1_0", "0x10000000000000000"] {
+            let json = format!(r#"{{"number":"{number}","optional":null,"list":[]}}"#);
+            assert!(serde_json::from_str::<Value>(&json).is_err(), "{number}");
+        }
+        assert!(serde_json::from_str::<Value>(r#"{"number":1,"optional":null,"list":[]}"#).is_err());
+    }
+
+    #[test]
+    fn bincode() {
+        let value = Value { number: U64::from(1), optional: None, list: vec![U256::from(2)] };
+        let encoded = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Value>(&encoded).unwrap(), value);
+    }
+}
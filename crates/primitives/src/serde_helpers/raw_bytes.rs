@@ -0,0 +1,124 @@
+//! Byte containers as raw bytes.
+//!
+//! The default implementations of [`Bytes`](crate::Bytes) and
+//! [`FixedBytes`](crate::FixedBytes) already use raw bytes in binary formats,
+//! but switch to hex strings in human-readable ones. This representation
+//! always uses [`Serializer::serialize_bytes`], which is what self-describing
+//! binary formats such as CBOR expect even if they report being
+//! human-readable. Formats without a native byte string type, like JSON,
+//! represent the bytes as an array of integers.
+//!
+//! Works with any [`ByteContainer`].
+
+use super::{option_and_vec, ByteContainer, Repr};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{de, Deserializer, Serializer};
+
+struct RawBytes;
+
+impl<T: ByteContainer> Repr<T> for RawBytes {
+    #[inline]
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(value, serializer)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Serializes a byte container as raw bytes.
+#[inline]
+pub fn serialize<T: ByteContainer, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(value.as_byte_slice())
+}
+
+/// Deserializes a byte container from raw bytes, or a sequence of `u8`.
+pub fn deserialize<'de, T: ByteContainer, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct RawBytesVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: ByteContainer> de::Visitor<'de> for RawBytesVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("raw bytes or an array of u8")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            T::from_byte_vec(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            T::from_byte_vec(v)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            // Don't trust the size hint too much, like `serde`'s `size_hint::cautious`.
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            T::from_byte_vec(bytes)
+        }
+    }
+
+    deserializer.deserialize_byte_buf(RawBytesVisitor(PhantomData))
+}
+
+option_and_vec!(RawBytes, [T: ByteContainer], T);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bytes, FixedBytes};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        bytes: Bytes,
+        #[serde(with = "super")]
+        fixed: FixedBytes<2>,
+        #[serde(with = "super::option")]
+        address: Option<Address>,
+        #[serde(with = "super::vec")]
+        list: Vec<Vec<u8>>,
+    }
+
+    #[test]
+    fn json() {
+        let value = Value {
+            bytes: Bytes::from_static(&[1, 2, 3]),
+            fixed: FixedBytes([4, 5]),
+            address: None,
+            list: vec![vec![], vec![6]],
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"bytes":[1,2,3],"fixed":[4,5],"address":null,"list":[[],[6]]}"#);
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+
+        assert!(serde_json::from_str::<Value>(
+            r#"{"bytes":[],"fixed":[4,5,6],"address":null,"list":[]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bincode() {
+        let value = Value {
+            bytes: Bytes::from_static(&[1, 2, 3]),
+            fixed: FixedBytes([4, 5]),
+            address: Some(Address::repeat_byte(0x11)),
+            list: vec![vec![6, 7]],
+        };
+        let encoded = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Value>(&encoded).unwrap(), value);
+    }
+}
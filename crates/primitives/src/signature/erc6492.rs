@@ -0,0 +1,238 @@
+use crate::{signature::SignatureError, Address, Bytes, B256};
+use alloc::vec::Vec;
+
+/// The magic suffix that marks an [ERC-6492] wrapped signature.
+///
+/// [ERC-6492]: https://eips.ethereum.org/EIPS/eip-6492
+pub const ERC6492_MAGIC_BYTES: B256 =
+    b256!("6492649264926492649264926492649264926492649264926492649264926492");
+
+/// An [ERC-6492] signature envelope, used for validating signatures of
+/// smart contract wallets that have not been deployed yet.
+///
+/// The envelope is encoded as
+/// `abi.encode(factory, factoryCalldata, signature) ++ magicBytes`, where
+/// `signature` is the inner [ERC-1271] signature that is validated once the
+/// wallet has been deployed by calling `factory` with `factoryCalldata`.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_primitives::{address, bytes, Erc6492Signature};
+/// let sig = Erc6492Signature::new(
+///     address!("0000000000000000000000000000000000000001"),
+///     bytes!("deadbeef"),
+///     bytes!("01020304"),
+/// );
+/// let encoded = sig.encode();
+/// assert!(Erc6492Signature::is_erc6492(&encoded));
+/// assert_eq!(Erc6492Signature::decode(&encoded).unwrap(), sig);
+///
+/// // Plain signatures are not wrapped.
+/// assert!(!Erc6492Signature::is_erc6492(&[0u8; 65]));
+/// ```
+///
+/// [ERC-6492]: https://eips.ethereum.org/EIPS/eip-6492
+/// [ERC-1271]: https://eips.ethereum.org/EIPS/eip-1271
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Erc6492Signature {
+    /// The factory that deploys the smart contract wallet.
+    pub factory: Address,
+    /// The calldata used to call the factory.
+    pub factory_calldata: Bytes,
+    /// The inner signature, to be validated with ERC-1271 after deployment.
+    pub signature: Bytes,
+}
+
+impl Erc6492Signature {
+    /// Creates a new ERC-6492 signature envelope.
+    #[inline]
+    pub const fn new(factory: Address, factory_calldata: Bytes, signature: Bytes) -> Self {
+        Self { factory, factory_calldata, signature }
+    }
+
+    /// Returns `true` if the given signature is wrapped in an ERC-6492
+    /// envelope, i.e. if it ends with [`ERC6492_MAGIC_BYTES`].
+    #[inline]
+    pub fn is_erc6492(signature: &[u8]) -> bool {
+        signature.ends_with(ERC6492_MAGIC_BYTES.as_slice())
+    }
+
+    /// Decodes an ERC-6492 signature envelope.
+    ///
+    /// Returns an error if the signature does not end with
+    /// [`ERC6492_MAGIC_BYTES`] or if the ABI-encoded body is malformed.
+    pub fn decode(signature: &[u8]) -> Result<Self, SignatureError> {
+        if !Self::is_erc6492(signature) {
+            return Err(SignatureError::FromBytes("missing ERC-6492 magic bytes"));
+        }
+        let body = &signature[..signature.len() - 32];
+
+        let factory = read_word(body, 0)?;
+        if factory[..12] != [0u8; 12] {
+            return Err(SignatureError::FromBytes("invalid ERC-6492 factory address"));
+        }
+        let factory = Address::from_slice(&factory[12..]);
+        let factory_calldata = read_bytes(body, read_usize(body, 32)?)?;
+        let signature = read_bytes(body, read_usize(body, 64)?)?;
+        Ok(Self { factory, factory_calldata, signature })
+    }
+
+    /// Decodes the given signature if it is wrapped in an ERC-6492 envelope.
+    ///
+    /// Returns `Ok(None)` if the signature is not wrapped, in which case it
+    /// should be validated as-is.
+    #[inline]
+    pub fn try_decode(signature: &[u8]) -> Result<Option<Self>, SignatureError> {
+        if Self::is_erc6492(signature) {
+            Self::decode(signature).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the length of the encoded envelope, including the magic
+    /// suffix.
+    #[inline]
+    pub const fn encoded_len(&self) -> usize {
+        // head + 2 * (length word + padded data) + magic bytes
+        3 * 32
+            + 32
+            + padded_len(self.factory_calldata.0.len())
+            + 32
+            + padded_len(self.signature.0.len())
+            + 32
+    }
+
+    /// Encodes the envelope, appending [`ERC6492_MAGIC_BYTES`].
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.encoded_len());
+        out.extend_from_slice(self.factory.into_word().as_slice());
+        let calldata_offset = 3 * 32;
+        let signature_offset = calldata_offset + 32 + padded_len(self.factory_calldata.len());
+        write_usize(&mut out, calldata_offset);
+        write_usize(&mut out, signature_offset);
+        write_bytes(&mut out, &self.factory_calldata);
+        write_bytes(&mut out, &self.signature);
+        out.extend_from_slice(ERC6492_MAGIC_BYTES.as_slice());
+        debug_assert_eq!(out.len(), self.encoded_len());
+        out
+    }
+}
+
+#[inline]
+const fn padded_len(len: usize) -> usize {
+    (len + 31) / 32 * 32
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<&[u8], SignatureError> {
+    offset
+        .checked_add(32)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(SignatureError::FromBytes("ERC-6492 signature is too short"))
+}
+
+fn read_usize(data: &[u8], offset: usize) -> Result<usize, SignatureError> {
+    let word = read_word(data, offset)?;
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|&b| b != 0) {
+        return Err(SignatureError::FromBytes("invalid ERC-6492 offset or length"));
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(low);
+    usize::try_from(u64::from_be_bytes(buf))
+        .map_err(|_| SignatureError::FromBytes("invalid ERC-6492 offset or length"))
+}
+
+fn read_bytes(data: &[u8], offset: usize) -> Result<Bytes, SignatureError> {
+    let len = read_usize(data, offset)?;
+    let start = offset + 32;
+    start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .map(Bytes::copy_from_slice)
+        .ok_or(SignatureError::FromBytes("ERC-6492 signature is too short"))
+}
+
+fn write_usize(out: &mut Vec<u8>, value: usize) {
+    out.extend_from_slice(&[0u8; 24]);
+    out.extend_from_slice(&(value as u64).to_be_bytes());
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_usize(out, bytes.len());
+    out.extend_from_slice(bytes);
+    out.resize(out.len() + padded_len(bytes.len()) - bytes.len(), 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn roundtrip() {
+        let sig = Erc6492Signature::new(
+            address!("ca11bde05977b3631167028862be2a173976ca11"),
+            Bytes::from_static(&[0xaa; 33]),
+            Bytes::from_static(&[0xbb; 65]),
+        );
+        let encoded = sig.encode();
+        assert_eq!(encoded.len(), sig.encoded_len());
+        assert_eq!(encoded.len(), 3 * 32 + 32 + 64 + 32 + 96 + 32);
+        assert_eq!(Erc6492Signature::decode(&encoded).unwrap(), sig);
+        assert_eq!(Erc6492Signature::try_decode(&encoded).unwrap(), Some(sig));
+
+        let empty = Erc6492Signature::default();
+        assert_eq!(Erc6492Signature::decode(&empty.encode()).unwrap(), empty);
+    }
+
+    #[test]
+    fn encoding() {
+        let sig = Erc6492Signature::new(
+            address!("0000000000000000000000000000000000000001"),
+            Bytes::from_static(&hex!("deadbeef")),
+            Bytes::from_static(&hex!("01")),
+        );
+        let expected = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000060"
+            "00000000000000000000000000000000000000000000000000000000000000a0"
+            "0000000000000000000000000000000000000000000000000000000000000004"
+            "deadbeef00000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0100000000000000000000000000000000000000000000000000000000000000"
+            "6492649264926492649264926492649264926492649264926492649264926492"
+        );
+        assert_eq!(sig.encode(), expected);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Erc6492Signature::try_decode(&[0u8; 65]).unwrap(), None);
+        assert!(Erc6492Signature::decode(&[0u8; 65]).is_err());
+        assert!(Erc6492Signature::decode(ERC6492_MAGIC_BYTES.as_slice()).is_err());
+
+        let sig = Erc6492Signature::new(
+            Address::repeat_byte(1),
+            Bytes::from_static(&[1, 2, 3]),
+            Bytes::from_static(&[4, 5, 6]),
+        );
+        let encoded = sig.encode();
+
+        // truncated body
+        let mut truncated = encoded[..encoded.len() - 64].to_vec();
+        truncated.extend_from_slice(ERC6492_MAGIC_BYTES.as_slice());
+        assert!(Erc6492Signature::decode(&truncated).is_err());
+
+        // dirty address word
+        let mut dirty = encoded.clone();
+        dirty[0] = 1;
+        assert!(Erc6492Signature::decode(&dirty).is_err());
+
+        // out of bounds offset
+        let mut oob = encoded;
+        oob[63] = 0xff;
+        assert!(Erc6492Signature::decode(&oob).is_err());
+    }
+}
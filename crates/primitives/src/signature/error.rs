@@ -2,7 +2,7 @@ use core::convert::Infallible;
 
 /// Errors in signature parsing or verification.
 #[derive(Debug)]
-#[cfg_attr(not(feature = "k256"), derive(Copy, Clone))]
+#[cfg_attr(not(any(feature = "k256", feature = "p256")), derive(Copy, Clone))]
 pub enum SignatureError {
     /// Error converting from bytes.
     FromBytes(&'static str),
@@ -16,6 +16,10 @@ pub enum SignatureError {
     /// k256 error
     #[cfg(feature = "k256")]
     K256(k256::ecdsa::Error),
+
+    /// p256 error
+    #[cfg(feature = "p256")]
+    P256(p256::ecdsa::Error),
 }
 
 #[cfg(feature = "k256")]
@@ -37,6 +41,8 @@ impl std::error::Error for SignatureError {
         match self {
             #[cfg(feature = "k256")]
             Self::K256(e) => Some(e),
+            #[cfg(feature = "p256")]
+            Self::P256(e) => Some(e),
             Self::FromHex(e) => Some(e),
             _ => None,
         }
@@ -48,6 +54,8 @@ impl core::fmt::Display for SignatureError {
         match self {
             #[cfg(feature = "k256")]
             Self::K256(e) => e.fmt(f),
+            #[cfg(feature = "p256")]
+            Self::P256(e) => e.fmt(f),
             Self::FromBytes(e) => f.write_str(e),
             Self::FromHex(e) => e.fmt(f),
             Self::InvalidParity(v) => write!(f, "invalid parity: {v}"),
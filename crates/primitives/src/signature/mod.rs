@@ -1,9 +1,17 @@
+mod erc6492;
+pub use erc6492::{Erc6492Signature, ERC6492_MAGIC_BYTES};
+
 mod error;
 pub use error::SignatureError;
 
 mod parity;
 pub use parity::Parity;
 
+#[cfg(feature = "p256")]
+mod secp256r1;
+#[cfg(feature = "p256")]
+pub use secp256r1::{P256Signature, P256_VERIFY_ADDRESS};
+
 mod sig;
 pub(crate) use sig::Signature;
 
@@ -0,0 +1,315 @@
+use crate::{hex, signature::SignatureError, Address, B256, U256};
+use core::str::FromStr;
+use p256::ecdsa::{signature::hazmat::PrehashVerifier, VerifyingKey};
+
+/// The address of the [RIP-7212] `P256VERIFY` precompile.
+///
+/// [RIP-7212]: https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md
+pub const P256_VERIFY_ADDRESS: Address = address!("0000000000000000000000000000000000000100");
+
+/// A secp256r1 (P-256) ECDSA signature, as produced by passkeys and verified by
+/// the [RIP-7212] `P256VERIFY` precompile.
+///
+/// Unlike secp256k1 [`Signature`](crate::Signature)s, P-256 signatures do not
+/// carry a recovery ID: they are verified against a known public key instead.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_primitives::{keccak256, P256Signature};
+/// use p256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+///
+/// let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+/// let hash = keccak256("hello");
+/// let sig: p256::ecdsa::Signature = key.sign_prehash(hash.as_slice()).unwrap();
+///
+/// let sig = P256Signature::from(sig);
+/// assert!(sig.verify_prehash(&hash, key.verifying_key()).is_ok());
+/// assert_eq!(P256Signature::try_from(&sig.as_bytes()[..]).unwrap(), sig);
+/// ```
+///
+/// [RIP-7212]: https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct P256Signature {
+    inner: p256::ecdsa::Signature,
+}
+
+impl From<p256::ecdsa::Signature> for P256Signature {
+    #[inline]
+    fn from(inner: p256::ecdsa::Signature) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<P256Signature> for p256::ecdsa::Signature {
+    #[inline]
+    fn from(value: P256Signature) -> Self {
+        value.inner
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for P256Signature {
+    type Error = SignatureError;
+
+    /// Parses a raw signature which is expected to be 64 bytes long where
+    /// the first 32 bytes is the `r` value and the second 32 bytes the `s`
+    /// value.
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 64 {
+            return Err(SignatureError::FromBytes("expected exactly 64 bytes"));
+        }
+        p256::ecdsa::Signature::from_slice(bytes).map(Self::from).map_err(SignatureError::P256)
+    }
+}
+
+impl FromStr for P256Signature {
+    type Err = SignatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s)?;
+        Self::try_from(&bytes[..])
+    }
+}
+
+impl From<&P256Signature> for [u8; 64] {
+    #[inline]
+    fn from(value: &P256Signature) -> [u8; 64] {
+        value.as_bytes()
+    }
+}
+
+impl From<P256Signature> for [u8; 64] {
+    #[inline]
+    fn from(value: P256Signature) -> [u8; 64] {
+        value.as_bytes()
+    }
+}
+
+impl P256Signature {
+    /// Instantiate from the `r` and `s` values.
+    ///
+    /// Returns an error if either value is zero or not lower than the curve
+    /// order.
+    #[inline]
+    pub fn from_rs(r: U256, s: U256) -> Result<Self, SignatureError> {
+        Self::from_scalars(r.into(), s.into())
+    }
+
+    /// Creates a [`P256Signature`] from the serialized `r` and `s` scalar
+    /// values.
+    ///
+    /// See [`p256::ecdsa::Signature::from_scalars`] for more details.
+    #[inline]
+    pub fn from_scalars(r: B256, s: B256) -> Result<Self, SignatureError> {
+        p256::ecdsa::Signature::from_scalars(r.0, s.0).map(Self::from).map_err(SignatureError::P256)
+    }
+
+    /// Returns the inner ECDSA signature.
+    #[inline]
+    pub const fn inner(&self) -> &p256::ecdsa::Signature {
+        &self.inner
+    }
+
+    /// Returns the `r` component of this signature.
+    #[inline]
+    pub fn r(&self) -> U256 {
+        U256::from_be_slice(self.inner.r().to_bytes().as_ref())
+    }
+
+    /// Returns the `s` component of this signature.
+    #[inline]
+    pub fn s(&self) -> U256 {
+        U256::from_be_slice(self.inner.s().to_bytes().as_ref())
+    }
+
+    /// Returns the byte-array representation of this signature.
+    ///
+    /// The first 32 bytes are the `r` value, and the second 32 bytes the `s`
+    /// value.
+    #[inline]
+    pub fn as_bytes(&self) -> [u8; 64] {
+        self.inner.to_bytes().into()
+    }
+
+    /// Normalizes the signature into "low S" form.
+    ///
+    /// Returns `None` if the signature is already normalized. The RIP-7212
+    /// precompile accepts both forms, but many on-chain verifiers, such as
+    /// WebAuthn libraries, reject high S values to prevent malleability.
+    #[inline]
+    pub fn normalize_s(&self) -> Option<Self> {
+        self.inner.normalize_s().map(Self::from)
+    }
+
+    /// Returns `true` if the signature is in "low S" form.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.normalize_s().is_none()
+    }
+
+    /// Verifies this signature against the given prehashed message and public
+    /// key.
+    ///
+    /// Both "low S" and "high S" signatures are accepted, matching the
+    /// behavior of the RIP-7212 precompile.
+    #[inline]
+    pub fn verify_prehash(
+        &self,
+        prehash: &B256,
+        public_key: &VerifyingKey,
+    ) -> Result<(), SignatureError> {
+        public_key.verify_prehash(prehash.as_slice(), &self.inner).map_err(SignatureError::P256)
+    }
+
+    /// Encodes the input for the [RIP-7212] `P256VERIFY` precompile at
+    /// [`P256_VERIFY_ADDRESS`].
+    ///
+    /// The input is `hash ++ r ++ s ++ x ++ y`, where `(x, y)` are the
+    /// coordinates of the uncompressed public key. The precompile returns
+    /// `uint256(1)` if the signature is valid, and no data otherwise.
+    ///
+    /// [RIP-7212]: https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md
+    pub fn precompile_input(&self, prehash: &B256, public_key: &VerifyingKey) -> [u8; 160] {
+        let point = public_key.to_encoded_point(false);
+        let mut input = [0u8; 160];
+        input[..32].copy_from_slice(prehash.as_slice());
+        input[32..96].copy_from_slice(&self.as_bytes());
+        // uncompressed points are always `0x04 ++ x ++ y`
+        input[96..].copy_from_slice(&point.as_bytes()[1..]);
+        input
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for P256Signature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        // if the serializer is human readable, serialize as a map, otherwise as a tuple
+        if serializer.is_human_readable() {
+            let mut map = serializer.serialize_map(Some(2))?;
+            map.serialize_entry("r", &self.r())?;
+            map.serialize_entry("s", &self.s())?;
+            map.end()
+        } else {
+            serde::Serialize::serialize(&(self.r(), self.s()), serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for P256Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Rs {
+            r: U256,
+            s: U256,
+        }
+
+        let (r, s) = if deserializer.is_human_readable() {
+            let Rs { r, s } = Rs::deserialize(deserializer)?;
+            (r, s)
+        } else {
+            <(U256, U256)>::deserialize(deserializer)?
+        };
+        Self::from_rs(r, s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+
+    fn sign(key: &SigningKey, hash: &B256) -> P256Signature {
+        let sig: p256::ecdsa::Signature = key.sign_prehash(hash.as_slice()).unwrap();
+        sig.into()
+    }
+
+    #[test]
+    fn parse_roundtrip() {
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let sig = sign(&key, &crate::keccak256("hello"));
+
+        let bytes = sig.as_bytes();
+        assert_eq!(P256Signature::try_from(&bytes[..]).unwrap(), sig);
+        assert_eq!(P256Signature::from_str(&hex::encode_prefixed(bytes)).unwrap(), sig);
+        assert_eq!(P256Signature::from_rs(sig.r(), sig.s()).unwrap(), sig);
+        assert_eq!(U256::from_be_slice(&bytes[..32]), sig.r());
+        assert_eq!(U256::from_be_slice(&bytes[32..]), sig.s());
+
+        assert!(P256Signature::try_from(&bytes[..63]).is_err());
+        assert!(P256Signature::try_from(&[0u8; 64][..]).is_err());
+        assert!(P256Signature::from_rs(U256::ZERO, sig.s()).is_err());
+        assert!(P256Signature::from_rs(sig.r(), U256::MAX).is_err());
+    }
+
+    #[test]
+    fn verify() {
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let hash = crate::keccak256("hello");
+        let sig = sign(&key, &hash);
+        assert!(sig.verify_prehash(&hash, key.verifying_key()).is_ok());
+        assert!(sig.verify_prehash(&crate::keccak256("world"), key.verifying_key()).is_err());
+
+        let other = SigningKey::from_slice(&[0x43; 32]).unwrap();
+        assert!(sig.verify_prehash(&hash, other.verifying_key()).is_err());
+    }
+
+    #[test]
+    fn normalize_s() {
+        // order of the P-256 curve
+        let n =
+            U256::from_str("0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+                .unwrap();
+
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        for i in 0..16u8 {
+            let hash = crate::keccak256([i]);
+            let sig = sign(&key, &hash);
+            let flipped = P256Signature::from_rs(sig.r(), n - sig.s()).unwrap();
+            assert_ne!(sig.is_normalized(), flipped.is_normalized());
+
+            let (low, high) = if sig.is_normalized() { (sig, flipped) } else { (flipped, sig) };
+            assert_eq!(low.normalize_s(), None);
+            assert_eq!(high.normalize_s(), Some(low));
+
+            // both forms verify
+            assert!(low.verify_prehash(&hash, key.verifying_key()).is_ok());
+            assert!(high.verify_prehash(&hash, key.verifying_key()).is_ok());
+        }
+    }
+
+    #[test]
+    fn precompile_input() {
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let hash = crate::keccak256("hello");
+        let sig = sign(&key, &hash);
+        let input = sig.precompile_input(&hash, key.verifying_key());
+
+        assert_eq!(input[..32], hash[..]);
+        assert_eq!(input[32..96], sig.as_bytes()[..]);
+        let point = key.verifying_key().to_encoded_point(false);
+        assert_eq!(&input[96..128], &point.x().unwrap()[..]);
+        assert_eq!(&input[128..], &point.y().unwrap()[..]);
+
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..].copy_from_slice(&input[96..]);
+        assert_eq!(&VerifyingKey::from_sec1_bytes(&uncompressed).unwrap(), key.verifying_key());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let sig = sign(&key, &crate::keccak256("hello"));
+
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!(r#"{{"r":"{:#x}","s":"{:#x}"}}"#, sig.r(), sig.s()));
+        assert_eq!(serde_json::from_str::<P256Signature>(&json).unwrap(), sig);
+        assert!(serde_json::from_str::<P256Signature>(r#"{"r":"0x0","s":"0x1"}"#).is_err());
+
+        let bin = bincode::serialize(&sig).unwrap();
+        assert_eq!(bincode::deserialize::<P256Signature>(&bin).unwrap(), sig);
+    }
+}
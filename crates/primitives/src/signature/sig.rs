@@ -6,6 +6,22 @@ use crate::{
 use alloc::vec::Vec;
 use core::str::FromStr;
 
+/// The order of the secp256k1 curve.
+const SECP256K1N_ORDER: U256 = U256::from_limbs([
+    0xbfd25e8cd0364141,
+    0xbaaedce6af48a03b,
+    0xfffffffffffffffe,
+    0xffffffffffffffff,
+]);
+
+/// Half the order of the secp256k1 curve. Signatures with `s` above this value are "high S".
+const SECP256K1N_HALF_ORDER: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
 /// An Ethereum ECDSA signature.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Signature<T> {
@@ -166,6 +182,17 @@ impl Signature<k256::ecdsa::Signature> {
         Self::from_signature_and_parity(inner, parity)
     }
 
+    /// Decodes a signature from its 64-byte [ERC-2098] compact representation.
+    ///
+    /// See [`as_erc2098`](Self::as_erc2098) for the format.
+    ///
+    /// [ERC-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[inline]
+    pub fn from_erc2098(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let (r, s, y_parity) = split_erc2098(bytes)?;
+        Self::from_rs_and_parity(r, s, y_parity)
+    }
+
     /// Normalizes the signature into "low S" form as described in
     /// [BIP 0062: Dealing with Malleability][1].
     ///
@@ -244,6 +271,67 @@ impl Signature<k256::ecdsa::Signature> {
         )
         .map_err(Into::into)
     }
+
+    /// Signs the given prehashed message with the given signing key.
+    ///
+    /// The signature is computed using deterministic [RFC 6979] nonces and is
+    /// always returned in normalized "low S" form, with the recovery ID as a
+    /// y-parity bool. Use [`with_chain_id`](Self::with_chain_id) to apply
+    /// [EIP-155].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{keccak256, Address, Signature};
+    /// let key = k256::ecdsa::SigningKey::from_slice(&[0x42; 32]).unwrap();
+    /// let hash = keccak256("hello");
+    /// let sig = Signature::sign_prehash(&key, &hash).unwrap();
+    /// assert_eq!(sig.recover_address_from_prehash(&hash).unwrap(), Address::from_private_key(&key));
+    /// ```
+    ///
+    /// [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    #[inline]
+    pub fn sign_prehash(
+        signing_key: &k256::ecdsa::SigningKey,
+        prehash: &crate::B256,
+    ) -> Result<Self, SignatureError> {
+        let (sig, recid) = signing_key.sign_prehash_recoverable(prehash.as_slice())?;
+        Self::from_signature_and_parity(sig, recid)
+    }
+
+    /// Signs the given message with the given signing key, by first prefixing
+    /// and hashing the message according to [EIP-191](crate::eip191_hash_message).
+    ///
+    /// See [`sign_prehash`](Self::sign_prehash) for more details.
+    #[inline]
+    pub fn sign_message<T: AsRef<[u8]>>(
+        signing_key: &k256::ecdsa::SigningKey,
+        msg: T,
+    ) -> Result<Self, SignatureError> {
+        Self::sign_prehash(signing_key, &crate::eip191_hash_message(msg))
+    }
+
+    /// Signs [EIP-712] typed data with the given signing key.
+    ///
+    /// The signed hash is `keccak256("\x19\x01" ++ domain_separator ++ struct_hash)`.
+    ///
+    /// See [`sign_prehash`](Self::sign_prehash) for more details.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    #[inline]
+    pub fn sign_typed_data_hash(
+        signing_key: &k256::ecdsa::SigningKey,
+        domain_separator: &crate::B256,
+        struct_hash: &crate::B256,
+    ) -> Result<Self, SignatureError> {
+        let mut buf = [0u8; 66];
+        buf[0] = 0x19;
+        buf[1] = 0x01;
+        buf[2..34].copy_from_slice(domain_separator.as_slice());
+        buf[34..].copy_from_slice(struct_hash.as_slice());
+        Self::sign_prehash(signing_key, &crate::keccak256(buf))
+    }
 }
 
 impl Signature<()> {
@@ -270,6 +358,17 @@ impl Signature<()> {
     ) -> Result<Self, SignatureError> {
         Ok(Self { inner: (), v: parity.try_into().map_err(Into::into)?, r, s })
     }
+
+    /// Decodes a signature from its 64-byte [ERC-2098] compact representation.
+    ///
+    /// See [`as_erc2098`](Self::as_erc2098) for the format.
+    ///
+    /// [ERC-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[inline]
+    pub fn from_erc2098(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let (r, s, y_parity) = split_erc2098(bytes)?;
+        Self::from_rs_and_parity(r, s, y_parity)
+    }
 }
 
 impl<S: Copy> Signature<S> {
@@ -332,6 +431,35 @@ impl<S> Signature<S> {
         sig
     }
 
+    /// Returns the 64-byte [ERC-2098] compact representation of this signature.
+    ///
+    /// The first 32 bytes are the `r` value, and the second 32 bytes are the
+    /// `s` value with the y-parity stored in its most significant bit
+    /// (`yParityAndS`).
+    ///
+    /// ERC-2098 requires a "low S" value, so signatures with a high `s` are
+    /// normalized first by replacing `s` with `n - s` and flipping the
+    /// y-parity. The resulting compact signature recovers the same signer.
+    ///
+    /// An `s` that is not less than the curve order `n` is not a valid scalar
+    /// and is written unchanged.
+    ///
+    /// [ERC-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[inline]
+    pub fn as_erc2098(&self) -> [u8; 64] {
+        let (s, y_parity) = if self.s > SECP256K1N_HALF_ORDER && self.s < SECP256K1N_ORDER {
+            (SECP256K1N_ORDER - self.s, !self.v.y_parity())
+        } else {
+            (self.s, self.v.y_parity())
+        };
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
+        sig[32..].copy_from_slice(&s.to_be_bytes::<32>());
+        sig[32] |= (y_parity as u8) << 7;
+        sig
+    }
+
     /// Sets the recovery ID by normalizing a `v` value.
     #[inline]
     pub fn with_parity<T: Into<Parity>>(self, parity: T) -> Self {
@@ -371,6 +499,22 @@ impl<S> Signature<S> {
     }
 }
 
+/// Splits an [ERC-2098] compact signature into its `r`, `s` and y-parity
+/// components.
+///
+/// [ERC-2098]: https://eips.ethereum.org/EIPS/eip-2098
+fn split_erc2098(bytes: &[u8]) -> Result<(U256, U256, bool), SignatureError> {
+    if bytes.len() != 64 {
+        return Err(SignatureError::FromBytes("expected exactly 64 bytes"));
+    }
+    let r = U256::from_be_slice(&bytes[..32]);
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&bytes[32..]);
+    let y_parity = s[0] & 0x80 != 0;
+    s[0] &= 0x7f;
+    Ok((r, U256::from_be_bytes(s), y_parity))
+}
+
 #[cfg(feature = "rlp")]
 impl alloy_rlp::Encodable for crate::Signature {
     fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
@@ -614,6 +758,144 @@ mod tests {
         assert_eq!(s1, s2);
     }
 
+    #[test]
+    #[cfg(feature = "k256")]
+    fn sign_roundtrip() {
+        let key = k256::ecdsa::SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let address = crate::Address::from_private_key(&key);
+        let half_n =
+            U256::from_str("0x7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0")
+                .unwrap();
+
+        for i in 0..32u8 {
+            let msg = [i; 7];
+            let sig = Signature::sign_message(&key, msg).unwrap();
+            assert!(sig.s() <= half_n);
+            assert_eq!(sig.normalize_s(), None);
+            assert_eq!(sig.recover_address_from_msg(msg).unwrap(), address);
+            // deterministic nonces
+            assert_eq!(Signature::sign_message(&key, msg).unwrap(), sig);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn sign_known_vector() {
+        // https://eips.ethereum.org/EIPS/eip-2098#test-cases
+        let key = k256::ecdsa::SigningKey::from_slice(&hex!(
+            "1234567890123456789012345678901234567890123456789012345678901234"
+        ))
+        .unwrap();
+        let sig = Signature::sign_message(&key, "Hello World").unwrap();
+        assert_eq!(sig.as_erc2098(), hex!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b907e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"));
+        let sig = Signature::sign_message(&key, "It's a small(er) world").unwrap();
+        assert_eq!(sig.as_erc2098(), hex!("9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"));
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn sign_typed_data_hash() {
+        let key = k256::ecdsa::SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let domain_separator = crate::B256::repeat_byte(0x11);
+        let struct_hash = crate::B256::repeat_byte(0x22);
+        let sig = Signature::sign_typed_data_hash(&key, &domain_separator, &struct_hash).unwrap();
+        let hash = crate::keccak256(
+            [&[0x19, 0x01][..], domain_separator.as_slice(), struct_hash.as_slice()].concat(),
+        );
+        assert_eq!(sig, Signature::sign_prehash(&key, &hash).unwrap());
+        assert_eq!(
+            sig.recover_address_from_prehash(&hash).unwrap(),
+            crate::Address::from_private_key(&key)
+        );
+    }
+
+    #[test]
+    fn erc2098() {
+        // test vectors taken from:
+        // https://eips.ethereum.org/EIPS/eip-2098#test-cases
+        let sig = crate::Signature::from_rs_and_parity(
+            U256::from_str("0x68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90")
+                .unwrap(),
+            U256::from_str("0x7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064")
+                .unwrap(),
+            27,
+        )
+        .unwrap();
+        let compact = sig.as_erc2098();
+        assert_eq!(compact, hex!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b907e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"));
+        assert_eq!(crate::Signature::from_erc2098(&compact).unwrap(), sig.with_parity_bool());
+
+        let sig = crate::Signature::from_rs_and_parity(
+            U256::from_str("0x9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76")
+                .unwrap(),
+            U256::from_str("0x139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793")
+                .unwrap(),
+            28,
+        )
+        .unwrap();
+        let compact = sig.as_erc2098();
+        assert_eq!(compact, hex!("9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"));
+        assert_eq!(crate::Signature::from_erc2098(&compact).unwrap(), sig.with_parity_bool());
+
+        assert!(crate::Signature::from_erc2098(&compact[..63]).is_err());
+        assert!(crate::Signature::from_erc2098(&[0; 65]).is_err());
+    }
+
+    #[test]
+    fn erc2098_high_s() {
+        assert_eq!(SECP256K1N_HALF_ORDER, SECP256K1N_ORDER >> 1);
+
+        // the first EIP-2098 test vector with `s` replaced by `n - s` and the parity flipped
+        let s =
+            U256::from_str("0x7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064")
+                .unwrap();
+        let high_s = SECP256K1N_ORDER - s;
+        assert!(high_s > SECP256K1N_HALF_ORDER);
+        let sig = crate::Signature::from_rs_and_parity(
+            U256::from_str("0x68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90")
+                .unwrap(),
+            high_s,
+            28,
+        )
+        .unwrap();
+        let compact = sig.as_erc2098();
+        assert_eq!(compact, hex!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b907e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"));
+
+        let decoded = crate::Signature::from_erc2098(&compact).unwrap();
+        assert_eq!(decoded.s(), s);
+        assert!(!decoded.v().y_parity());
+    }
+
+    #[test]
+    fn erc2098_s_out_of_range() {
+        let r = U256::from(1);
+        for s in [SECP256K1N_ORDER, SECP256K1N_ORDER + U256::from(1), U256::MAX] {
+            for parity in [false, true] {
+                let sig = Signature::<()>::from_rs_and_parity(r, s, parity).unwrap();
+                let compact = sig.as_erc2098();
+                assert_eq!(compact[..32], r.to_be_bytes::<32>());
+                let mut expected = s.to_be_bytes::<32>();
+                expected[0] |= (parity as u8) << 7;
+                assert_eq!(compact[32..], expected);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn erc2098_recover() {
+        // https://eips.ethereum.org/EIPS/eip-2098#test-cases
+        let key = k256::ecdsa::SigningKey::from_slice(&hex!(
+            "1234567890123456789012345678901234567890123456789012345678901234"
+        ))
+        .unwrap();
+        let expected = crate::Address::from_private_key(&key);
+        let sig = crate::Signature::from_erc2098(&hex!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b907e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064")).unwrap();
+        assert_eq!(sig.recover_address_from_msg("Hello World").unwrap(), expected);
+        let sig = crate::Signature::from_erc2098(&hex!("9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793")).unwrap();
+        assert_eq!(sig.recover_address_from_msg("It's a small(er) world").unwrap(), expected);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn deserialize_without_parity() {
@@ -0,0 +1,48 @@
+use super::Signed;
+use crate::borsh::{deserialize_uint, serialize_uint};
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_uint(&self.0, writer)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        deserialize_uint(reader).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I256, I8};
+
+    #[test]
+    fn twos_complement_layout() {
+        assert_eq!(borsh::to_vec(&I256::MINUS_ONE).unwrap(), [0xff; 32]);
+        assert_eq!(borsh::to_vec(&I8::MIN).unwrap(), [0x80]);
+
+        let value = I256::try_from(-2).unwrap();
+        let encoded = borsh::to_vec(&value).unwrap();
+        assert_eq!(encoded[0], 0xfe);
+        assert_eq!(borsh::from_slice::<I256>(&encoded).unwrap(), value);
+        assert!(borsh::from_slice::<I256>(&encoded[1..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(value: I256)| {
+            proptest::prop_assert_eq!(
+                borsh::from_slice::<I256>(&borsh::to_vec(&value).unwrap()).unwrap(),
+                value
+            );
+        });
+    }
+}
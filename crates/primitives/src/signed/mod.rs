@@ -18,6 +18,14 @@ mod ops;
 mod sign;
 pub use sign::Sign;
 
+/// Borsh support.
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// SCALE codec support.
+#[cfg(feature = "scale")]
+mod scale;
+
 /// Serde support.
 #[cfg(feature = "serde")]
 mod serde;
@@ -0,0 +1,92 @@
+use super::Signed;
+use crate::scale::{decode_uint, encode_uint};
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+/// Encodes as exactly [`Signed::BYTES`] little-endian two's complement bytes,
+/// without a length prefix, like Substrate's `I256`.
+impl<const BITS: usize, const LIMBS: usize> Encode for Signed<BITS, LIMBS> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        Self::BYTES
+    }
+
+    #[inline]
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        encode_uint(&self.0, dest);
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> EncodeLike for Signed<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> Decode for Signed<BITS, LIMBS> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        decode_uint(input).map(Self)
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Self::BYTES)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> MaxEncodedLen for Signed<BITS, LIMBS> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        Self::BYTES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, I256, U256};
+    use alloc::vec::Vec;
+    use parity_scale_codec::DecodeAll;
+
+    #[test]
+    fn twos_complement_layout() {
+        let value = I256::try_from(-2).unwrap();
+        let encoded = value.encode();
+        assert_eq!(encoded, [&[0xfe][..], &[0xff; 31]].concat());
+        assert_eq!(encoded.len(), I256::max_encoded_len());
+        assert_eq!(I256::decode(&mut &encoded[..]).unwrap(), value);
+
+        // a truncated negative value is not decoded as a positive one
+        assert!(I256::decode(&mut &encoded[..31]).is_err());
+        assert!(I256::decode_all(&mut &[encoded.as_slice(), &[0]].concat()[..]).is_err());
+    }
+
+    #[test]
+    fn substrate_layout() {
+        // `primitive_types::U256::from(0x0102030405060708u64).encode()`
+        let encoded = hex!("0807060504030201000000000000000000000000000000000000000000000000");
+        let value = I256::try_from(0x0102030405060708u64).unwrap();
+        assert_eq!(value.encode(), encoded);
+        assert_eq!(I256::decode(&mut &encoded[..]).unwrap(), value);
+        assert_eq!(I256::max_encoded_len(), encoded.len());
+
+        // same layout as `Uint`
+        let mut uint = Vec::new();
+        encode_uint(&U256::from(0x0102030405060708u64), &mut uint);
+        assert_eq!(uint, encoded);
+
+        assert_eq!(
+            I256::MIN.encode(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000080")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip() {
+        proptest::proptest!(|(signed: I256)| {
+            proptest::prop_assert_eq!(I256::decode(&mut &signed.encode()[..]).unwrap(), signed);
+        });
+    }
+}
@@ -0,0 +1,446 @@
+//! Support for the [`sqlx`](https://crates.io/crates/sqlx) crate.
+//!
+//! Column types are chosen as follows:
+//! - [`FixedBytes`], [`Address`](crate::Address), [`Bloom`](crate::Bloom) and
+//!   [`Bytes`] are stored as raw bytes (`BYTEA` in Postgres, `BLOB` in SQLite)
+//!   in every database that supports `Vec<u8>`. Decoding a fixed-size type
+//!   fails if the stored length differs.
+//! - [`Uint`](crate::Uint) support is provided by [`ruint`], which stores
+//!   values as fixed-width big-endian bytes, so that byte order is numeric
+//!   order.
+//! - [`Signed`] is stored as `NUMERIC` in Postgres with the `sqlx-postgres`
+//!   feature, and as decimal `TEXT` in SQLite with the `sqlx-sqlite` feature.
+//!   SQLite `INTEGER` values can be decoded as well.
+//!
+//! Decoding a number that does not fit the target type fails with
+//! [`DecodeError::Overflow`].
+//!
+//! **WARNING**: this module depends entirely on [`sqlx`](https://crates.io/crates/sqlx), which is
+//! not yet stable, therefore this module is exempt from the semver guarantees of this crate.
+
+use crate::{Bytes, FixedBytes, Signed};
+use derive_more::{Display, Error};
+use sqlx_core::{
+    database::{Database, HasArguments, HasValueRef},
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+/// Error when decoding a value from a database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display, Error)]
+pub enum DecodeError {
+    /// The stored bytes have the wrong length for the fixed-size type.
+    #[display(fmt = "expected {expected} bytes, got {got}")]
+    InvalidLength {
+        /// The length of the type.
+        expected: usize,
+        /// The length of the stored value.
+        got: usize,
+    },
+    /// The stored number is too large for the type.
+    #[display(fmt = "value does not fit in a {_0}-bit integer")]
+    Overflow(#[error(not(source))] usize),
+    /// The stored number is not an integer.
+    #[display(fmt = "value is not an integer")]
+    NotAnInteger,
+    /// The stored value is malformed.
+    #[display(fmt = "invalid {_0} value")]
+    Invalid(#[error(not(source))] &'static str),
+}
+
+impl<const N: usize, DB: Database> Type<DB> for FixedBytes<N>
+where
+    Vec<u8>: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Encode<'a, DB> for FixedBytes<N>
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'a>>::ArgumentBuffer) -> IsNull {
+        self.to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Decode<'a, DB> for FixedBytes<N>
+where
+    Vec<u8>: Decode<'a, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'a>>::ValueRef) -> Result<Self, BoxDynError> {
+        let bytes = Vec::<u8>::decode(value)?;
+        Self::try_from(bytes.as_slice())
+            .map_err(|_| DecodeError::InvalidLength { expected: N, got: bytes.len() }.into())
+    }
+}
+
+impl<DB: Database> Type<DB> for Bytes
+where
+    Vec<u8>: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, DB: Database> Encode<'a, DB> for Bytes
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'a>>::ArgumentBuffer) -> IsNull {
+        self.to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, DB: Database> Decode<'a, DB> for Bytes
+where
+    Vec<u8>: Decode<'a, DB>,
+{
+    #[inline]
+    fn decode(value: <DB as HasValueRef<'a>>::ValueRef) -> Result<Self, BoxDynError> {
+        Vec::<u8>::decode(value).map(Into::into)
+    }
+}
+
+/// Parses a decimal integer, allowing a zero fractional part.
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+fn parse_decimal<const BITS: usize, const LIMBS: usize>(
+    s: &str,
+) -> Result<Signed<BITS, LIMBS>, BoxDynError> {
+    let int = match s.split_once('.') {
+        Some((int, frac)) if frac.bytes().all(|b| b == b'0') => int,
+        Some(_) => return Err(DecodeError::NotAnInteger.into()),
+        None => s,
+    };
+    Signed::from_dec_str(int).map_err(|e| match e {
+        crate::ParseSignedError::IntegerOverflow => DecodeError::Overflow(BITS).into(),
+        e => e.into(),
+    })
+}
+
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use super::{parse_decimal, DecodeError};
+    use crate::{Sign, Signed, Uint};
+    use sqlx_core::{
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+    use sqlx_postgres::{
+        types::Oid, PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+    };
+
+    /// The `NUMERIC` type OID.
+    const NUMERIC: PgTypeInfo = PgTypeInfo::with_oid(Oid(1700));
+
+    /// `NUMERIC` digits are stored in base 10000.
+    const BASE: u64 = 10000;
+
+    const SIGN_POSITIVE: u16 = 0x0000;
+    const SIGN_NEGATIVE: u16 = 0x4000;
+
+    impl<const BITS: usize, const LIMBS: usize> Type<Postgres> for Signed<BITS, LIMBS> {
+        #[inline]
+        fn type_info() -> PgTypeInfo {
+            NUMERIC
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize> Encode<'_, Postgres> for Signed<BITS, LIMBS> {
+        #[inline]
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+            encode_numeric(self, buf);
+            IsNull::No
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize> Decode<'_, Postgres> for Signed<BITS, LIMBS> {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => decode_numeric(value.as_bytes()?).map_err(Into::into),
+                PgValueFormat::Text => parse_decimal(value.as_str()?),
+            }
+        }
+    }
+
+    /// Encodes an integer in the binary `NUMERIC` format: a header of digit
+    /// count, weight of the first digit, sign and display scale, followed by
+    /// big-endian base 10000 digits without trailing zeros.
+    ///
+    /// See [`numeric.c`](https://github.com/postgres/postgres/blob/05a5a1775c89f6beb326725282e7eea1373cbec8/src/backend/utils/adt/numeric.c#L1082).
+    pub(super) fn encode_numeric<const BITS: usize, const LIMBS: usize>(
+        value: &Signed<BITS, LIMBS>,
+        out: &mut Vec<u8>,
+    ) {
+        let (sign, abs) = value.into_sign_and_abs();
+        let mut digits: Vec<u64> = abs.to_base_be(BASE).skip_while(|&d| d == 0).collect();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let weight = digits.len().saturating_sub(1) as i16;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let sign = if sign.is_negative() && !abs.is_zero() { SIGN_NEGATIVE } else { SIGN_POSITIVE };
+
+        #[allow(clippy::cast_possible_truncation)]
+        out.extend_from_slice(&(digits.len() as u16).to_be_bytes());
+        out.extend_from_slice(&weight.to_be_bytes());
+        out.extend_from_slice(&sign.to_be_bytes());
+        out.extend_from_slice(&0u16.to_be_bytes());
+        for digit in digits {
+            #[allow(clippy::cast_possible_truncation)] // 10000 < u16::MAX
+            out.extend_from_slice(&(digit as u16).to_be_bytes());
+        }
+    }
+
+    /// Decodes an integer from the binary `NUMERIC` format.
+    pub(super) fn decode_numeric<const BITS: usize, const LIMBS: usize>(
+        raw: &[u8],
+    ) -> Result<Signed<BITS, LIMBS>, DecodeError> {
+        let word = |i: usize| u16::from_be_bytes([raw[2 * i], raw[2 * i + 1]]);
+        if raw.len() < 8 {
+            return Err(DecodeError::Invalid("NUMERIC"));
+        }
+        let ndigits = usize::from(word(0));
+        #[allow(clippy::cast_possible_wrap)]
+        let weight = word(1) as i16;
+        let sign = match word(2) {
+            SIGN_POSITIVE => Sign::Positive,
+            SIGN_NEGATIVE => Sign::Negative,
+            // NaN and infinities
+            _ => return Err(DecodeError::NotAnInteger),
+        };
+        if raw.len() != 8 + 2 * ndigits {
+            return Err(DecodeError::Invalid("NUMERIC"));
+        }
+        if ndigits == 0 {
+            return Ok(Signed::ZERO);
+        }
+        // Trailing zero digits are never stored, so any digit after the
+        // decimal point is a non-zero fraction.
+        let int_digits = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+        if ndigits > int_digits {
+            return Err(DecodeError::NotAnInteger);
+        }
+
+        let digits = (4..4 + ndigits).map(|i| u64::from(word(i)));
+        if digits.clone().any(|d| d >= BASE) {
+            return Err(DecodeError::Invalid("NUMERIC"));
+        }
+        let digits = digits.chain(core::iter::repeat(0).take(int_digits - ndigits));
+        let abs = Uint::from_base_be(BASE, digits).map_err(|_| DecodeError::Overflow(BITS))?;
+        Signed::checked_from_sign_and_abs(sign, abs).ok_or(DecodeError::Overflow(BITS))
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use super::{parse_decimal, DecodeError};
+    use crate::Signed;
+    use sqlx_core::{
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+        value::ValueRef,
+    };
+    use sqlx_sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+    impl<const BITS: usize, const LIMBS: usize> Type<Sqlite> for Signed<BITS, LIMBS> {
+        #[inline]
+        fn type_info() -> SqliteTypeInfo {
+            <str as Type<Sqlite>>::type_info()
+        }
+
+        #[inline]
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <str as Type<Sqlite>>::compatible(ty) || <i64 as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q, const BITS: usize, const LIMBS: usize> Encode<'q, Sqlite> for Signed<BITS, LIMBS> {
+        #[inline]
+        fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+            <String as Encode<'q, Sqlite>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r, const BITS: usize, const LIMBS: usize> Decode<'r, Sqlite> for Signed<BITS, LIMBS> {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+            if <i64 as Type<Sqlite>>::compatible(&value.type_info()) {
+                let value = <i64 as Decode<'r, Sqlite>>::decode(value)?;
+                Self::try_from(value).map_err(|_| DecodeError::Overflow(BITS).into())
+            } else {
+                parse_decimal(<&str as Decode<'r, Sqlite>>::decode(value)?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "sqlx-postgres")]
+    fn numeric() {
+        use super::postgres::{decode_numeric, encode_numeric};
+        use crate::{hex, I256};
+
+        #[track_caller]
+        fn roundtrip(value: I256, encoded: &[u8]) {
+            let mut out = Vec::new();
+            encode_numeric(&value, &mut out);
+            assert_eq!(hex::encode(&out), hex::encode(encoded), "{value}");
+            assert_eq!(decode_numeric::<256, 4>(&out), Ok(value));
+        }
+
+        roundtrip(I256::ZERO, &hex!("0000 0000 0000 0000"));
+        roundtrip(I256::ONE, &hex!("0001 0000 0000 0000 0001"));
+        roundtrip(I256::MINUS_ONE, &hex!("0001 0000 4000 0000 0001"));
+        // 12345678 = [1234, 5678]
+        roundtrip("12345678".parse().unwrap(), &hex!("0002 0001 0000 0000 04d2 162e"));
+        // 100000000 = [1, 0, 0], trailing zeros are trimmed
+        roundtrip("100000000".parse().unwrap(), &hex!("0001 0002 0000 0000 0001"));
+        roundtrip("-10000".parse().unwrap(), &hex!("0001 0001 4000 0000 0001"));
+
+        for value in [I256::MAX, I256::MIN, I256::MAX - I256::ONE, I256::MIN + I256::ONE] {
+            let mut out = Vec::new();
+            encode_numeric(&value, &mut out);
+            assert_eq!(decode_numeric::<256, 4>(&out), Ok(value));
+            assert_eq!(decode_numeric::<128, 2>(&out), Err(DecodeError::Overflow(128)));
+        }
+
+        // 1.5 with dscale 1
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0002 0000 0000 0001 0001 1388")),
+            Err(DecodeError::NotAnInteger)
+        );
+        // 0.5
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0001 ffff 0000 0001 1388")),
+            Err(DecodeError::NotAnInteger)
+        );
+        // 2.00 with dscale 2
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0001 0000 0000 0002 0002")),
+            Ok(I256::try_from(2).unwrap())
+        );
+        // NaN
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0000 0000 c000 0000")),
+            Err(DecodeError::NotAnInteger)
+        );
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0001 0000")),
+            Err(DecodeError::Invalid("NUMERIC"))
+        );
+        assert_eq!(
+            decode_numeric::<256, 4>(&hex!("0001 0000 0000 0000 2710")),
+            Err(DecodeError::Invalid("NUMERIC"))
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+    fn parse() {
+        use crate::I256;
+
+        assert_eq!(parse_decimal::<256, 4>("-42").unwrap(), I256::try_from(-42).unwrap());
+        assert_eq!(parse_decimal::<256, 4>("42.000").unwrap(), I256::try_from(42).unwrap());
+        assert!(parse_decimal::<256, 4>("42.5").is_err());
+        assert!(parse_decimal::<256, 4>("abc").is_err());
+        let err = parse_decimal::<8, 1>("128").unwrap_err();
+        assert_eq!(err.to_string(), "value does not fit in a 8-bit integer");
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    mod sqlite {
+        use crate::{Address, Bloom, Bytes, FixedBytes, I256, I8, U256};
+        use sqlx_core::{
+            connection::Connection, query::query, query_as::query_as, query_scalar::query_scalar,
+        };
+        use sqlx_sqlite::SqliteConnection;
+
+        async fn connect() -> SqliteConnection {
+            SqliteConnection::connect("sqlite::memory:").await.unwrap()
+        }
+
+        #[test]
+        fn roundtrip() {
+            futures_executor::block_on(async {
+                let conn = &mut connect().await;
+                query("CREATE TABLE t (address, hash, bloom, data, amount, delta)")
+                    .execute(&mut *conn)
+                    .await
+                    .unwrap();
+
+                let address = Address::repeat_byte(0x11);
+                let hash = FixedBytes::<32>::repeat_byte(0x22);
+                let bloom = Bloom::repeat_byte(0x33);
+                let data = Bytes::from_static(&[1, 2, 3]);
+                let amount = U256::MAX;
+                let delta = I256::MIN;
+                query("INSERT INTO t VALUES (?, ?, ?, ?, ?, ?)")
+                    .bind(address)
+                    .bind(hash)
+                    .bind(bloom)
+                    .bind(data.clone())
+                    .bind(amount)
+                    .bind(delta)
+                    .execute(&mut *conn)
+                    .await
+                    .unwrap();
+
+                let row: (Address, FixedBytes<32>, Bloom, Bytes, U256, I256) =
+                    query_as("SELECT * FROM t").fetch_one(&mut *conn).await.unwrap();
+                assert_eq!(row, (address, hash, bloom, data, amount, delta));
+
+                let text: String =
+                    query_scalar("SELECT delta FROM t").fetch_one(&mut *conn).await.unwrap();
+                assert_eq!(text, delta.to_string());
+            });
+        }
+
+        #[test]
+        fn errors() {
+            futures_executor::block_on(async {
+                let conn = &mut connect().await;
+
+                let err = query_scalar::<_, Address>("SELECT x'1234'")
+                    .fetch_one(&mut *conn)
+                    .await
+                    .unwrap_err();
+                assert!(err.to_string().contains("expected 20 bytes, got 2"), "{err}");
+
+                for sql in ["SELECT '1000'", "SELECT 1000"] {
+                    let err = query_scalar::<_, I8>(sql).fetch_one(&mut *conn).await.unwrap_err();
+                    assert!(err.to_string().contains("does not fit in a 8-bit integer"), "{err}");
+                }
+
+                let value: I256 = query_scalar("SELECT -5").fetch_one(&mut *conn).await.unwrap();
+                assert_eq!(value, I256::try_from(-5).unwrap());
+            });
+        }
+    }
+}
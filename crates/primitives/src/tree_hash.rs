@@ -0,0 +1,313 @@
+//! [SSZ merkleization][spec]: `hash_tree_root` for primitive types, and
+//! lists and vectors of them.
+//!
+//! Basic types (`bool`, unsigned integers) are packed into 32-byte chunks,
+//! while composite types (byte vectors such as [`FixedBytes`] and
+//! [`Address`](crate::Address)) are merkleized from their own chunks. Lists
+//! have a maximum length, and mix their actual length into the root; use
+//! [`list_root`] or [`Bytes::hash_tree_root`] for them.
+//!
+//! [spec]: https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+
+use crate::{Bytes, FixedBytes, B256, U128, U16, U256, U32, U64, U8};
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// Size of a merkleization chunk, in bytes.
+pub const CHUNK_SIZE: usize = 32;
+
+/// An SSZ type that can be merkleized.
+pub trait HashTreeRoot {
+    /// The size of the type in bytes when packed, if it is an SSZ basic type.
+    /// `None` for composite types.
+    const PACKED_LEN: Option<usize> = None;
+
+    /// Appends the packed, little-endian serialization of the value to `buf`.
+    /// Only called for basic types, i.e. if [`PACKED_LEN`](Self::PACKED_LEN)
+    /// is `Some`.
+    #[inline]
+    fn append_packed(&self, buf: &mut Vec<u8>) {
+        let _ = buf;
+    }
+
+    /// Returns the SSZ `hash_tree_root` of the value.
+    fn hash_tree_root(&self) -> B256;
+}
+
+/// Hashes two nodes together.
+#[inline]
+fn hash_nodes(a: &B256, b: &B256) -> B256 {
+    let mut hasher = Sha256::new();
+    hasher.update(a);
+    hasher.update(b);
+    B256::new(hasher.finalize().into())
+}
+
+/// Splits `bytes` into chunks, right-padding the last one with zeros.
+pub fn pack(bytes: &[u8]) -> Vec<B256> {
+    bytes.chunks(CHUNK_SIZE).map(B256::right_padding_from).collect()
+}
+
+/// Merkleizes `chunks` into a root.
+///
+/// The tree is padded with zero chunks to `limit` leaves if given, or to the
+/// number of chunks otherwise, rounded up to the next power of two.
+///
+/// # Panics
+///
+/// Panics if there are more chunks than `limit`.
+#[track_caller]
+pub fn merkleize(chunks: &[B256], limit: Option<usize>) -> B256 {
+    let leaves = match limit {
+        Some(limit) => {
+            assert!(chunks.len() <= limit, "{} chunks exceed limit of {limit}", chunks.len());
+            limit
+        }
+        None => chunks.len(),
+    };
+    let depth = leaves.max(1).next_power_of_two().trailing_zeros();
+
+    let mut layer = chunks.to_vec();
+    let mut zero = B256::ZERO;
+    for _ in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        for i in 0..layer.len() / 2 {
+            layer[i] = hash_nodes(&layer[2 * i], &layer[2 * i + 1]);
+        }
+        layer.truncate(layer.len() / 2);
+        zero = hash_nodes(&zero, &zero);
+    }
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Mixes a list length into its root.
+#[inline]
+pub fn mix_in_length(root: &B256, len: usize) -> B256 {
+    hash_nodes(root, &U256::from(len).to_le_bytes::<32>().into())
+}
+
+/// Returns the chunks of a sequence of values, and the number of chunks
+/// needed to hold `len` values.
+fn chunks_of<T: HashTreeRoot>(items: &[T], len: usize) -> (Vec<B256>, usize) {
+    match T::PACKED_LEN {
+        Some(size) => {
+            let mut buf = Vec::with_capacity(items.len() * size);
+            for item in items {
+                item.append_packed(&mut buf);
+            }
+            (pack(&buf), (len * size + CHUNK_SIZE - 1) / CHUNK_SIZE)
+        }
+        None => (items.iter().map(HashTreeRoot::hash_tree_root).collect(), len),
+    }
+}
+
+/// Returns the root of an SSZ `Vector[T, N]`, where `N` is the number of
+/// items.
+pub fn vector_root<T: HashTreeRoot>(items: &[T]) -> B256 {
+    let (chunks, _) = chunks_of(items, items.len());
+    merkleize(&chunks, None)
+}
+
+/// Returns the root of an SSZ `List[T, max_len]`.
+///
+/// # Panics
+///
+/// Panics if there are more than `max_len` items.
+#[track_caller]
+pub fn list_root<T: HashTreeRoot>(items: &[T], max_len: usize) -> B256 {
+    assert!(items.len() <= max_len, "{} items exceed list limit of {max_len}", items.len());
+    let (chunks, limit) = chunks_of(items, max_len);
+    mix_in_length(&merkleize(&chunks, Some(limit)), items.len())
+}
+
+macro_rules! impl_basic {
+    ($($t:ty => $size:literal, |$v:ident| $bytes:expr;)*) => {$(
+        impl HashTreeRoot for $t {
+            const PACKED_LEN: Option<usize> = Some($size);
+
+            #[inline]
+            fn append_packed(&self, buf: &mut Vec<u8>) {
+                let $v = self;
+                buf.extend_from_slice(&$bytes);
+            }
+
+            #[inline]
+            fn hash_tree_root(&self) -> B256 {
+                let $v = self;
+                B256::right_padding_from(&$bytes)
+            }
+        }
+    )*};
+}
+
+impl_basic! {
+    bool => 1, |v| [u8::from(*v)];
+    u8 => 1, |v| v.to_le_bytes();
+    u16 => 2, |v| v.to_le_bytes();
+    u32 => 4, |v| v.to_le_bytes();
+    u64 => 8, |v| v.to_le_bytes();
+    u128 => 16, |v| v.to_le_bytes();
+    U8 => 1, |v| v.to_le_bytes::<1>();
+    U16 => 2, |v| v.to_le_bytes::<2>();
+    U32 => 4, |v| v.to_le_bytes::<4>();
+    U64 => 8, |v| v.to_le_bytes::<8>();
+    U128 => 16, |v| v.to_le_bytes::<16>();
+    U256 => 32, |v| v.to_le_bytes::<32>();
+}
+
+/// `Vector[T, N]`.
+impl<T: HashTreeRoot, const N: usize> HashTreeRoot for [T; N] {
+    #[inline]
+    fn hash_tree_root(&self) -> B256 {
+        vector_root(self)
+    }
+}
+
+/// `ByteVector[N]`.
+impl<const N: usize> HashTreeRoot for FixedBytes<N> {
+    #[inline]
+    fn hash_tree_root(&self) -> B256 {
+        if N <= CHUNK_SIZE {
+            B256::right_padding_from(&self.0)
+        } else {
+            merkleize(&pack(&self.0), None)
+        }
+    }
+}
+
+impl Bytes {
+    /// Returns the SSZ `hash_tree_root` of the bytes as a
+    /// `ByteList[max_len]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are longer than `max_len`.
+    #[inline]
+    #[track_caller]
+    pub fn hash_tree_root(&self, max_len: usize) -> B256 {
+        list_root(self, max_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, Bloom};
+
+    const ZERO_HASH_1: B256 =
+        b256!("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b");
+    const ZERO_HASH_2: B256 =
+        b256!("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71");
+
+    #[test]
+    fn zero_hashes() {
+        assert_eq!(merkleize(&[], None), B256::ZERO);
+        assert_eq!(merkleize(&[], Some(1)), B256::ZERO);
+        assert_eq!(merkleize(&[], Some(2)), ZERO_HASH_1);
+        assert_eq!(merkleize(&[], Some(3)), ZERO_HASH_2);
+        assert_eq!(merkleize(&[B256::ZERO; 4], None), ZERO_HASH_2);
+        assert_eq!(hash_nodes(&ZERO_HASH_1, &ZERO_HASH_1), ZERO_HASH_2);
+    }
+
+    #[test]
+    fn merkleize_padding() {
+        let a = B256::repeat_byte(1);
+        let b = B256::repeat_byte(2);
+        let c = B256::repeat_byte(3);
+        assert_eq!(merkleize(&[a], None), a);
+        assert_eq!(merkleize(&[a, b], None), hash_nodes(&a, &b));
+        let ab = hash_nodes(&a, &b);
+        let c0 = hash_nodes(&c, &B256::ZERO);
+        assert_eq!(merkleize(&[a, b, c], None), hash_nodes(&ab, &c0));
+        assert_eq!(
+            merkleize(&[a], Some(4)),
+            hash_nodes(&hash_nodes(&a, &B256::ZERO), &ZERO_HASH_1)
+        );
+    }
+
+    #[test]
+    #[should_panic = "3 chunks exceed limit of 2"]
+    fn merkleize_over_limit() {
+        merkleize(&[B256::ZERO; 3], Some(2));
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(true.hash_tree_root(), B256::right_padding_from(&[1]));
+        assert_eq!(0x0102u16.hash_tree_root(), B256::right_padding_from(&[2, 1]));
+        assert_eq!(U64::from(0x0102).hash_tree_root(), B256::right_padding_from(&[2, 1]));
+        assert_eq!(
+            U256::from_be_bytes(B256::repeat_byte(0xab).0).hash_tree_root(),
+            B256::repeat_byte(0xab)
+        );
+        assert_eq!(U256::from(1u8).hash_tree_root(), 1u64.hash_tree_root());
+    }
+
+    #[test]
+    fn byte_vectors() {
+        let hash = B256::repeat_byte(0x42);
+        assert_eq!(hash.hash_tree_root(), hash);
+
+        let address = Address::repeat_byte(0x11);
+        assert_eq!(address.hash_tree_root(), B256::right_padding_from(address.as_slice()));
+        assert_eq!(address.0.hash_tree_root(), address.hash_tree_root());
+
+        // 256 bytes = 8 chunks
+        let bloom = Bloom::from_slice(&(0..=255u8).collect::<Vec<_>>());
+        let chunks: Vec<B256> = bloom.0.chunks(32).map(B256::from_slice).collect();
+        let l1: Vec<B256> = chunks.chunks(2).map(|c| hash_nodes(&c[0], &c[1])).collect();
+        let l2: Vec<B256> = l1.chunks(2).map(|c| hash_nodes(&c[0], &c[1])).collect();
+        assert_eq!(bloom.hash_tree_root(), hash_nodes(&l2[0], &l2[1]));
+        assert_eq!(bloom.0 .0.hash_tree_root(), bloom.hash_tree_root());
+
+        // 48 bytes = 2 chunks, e.g. a BLS public key
+        let key = FixedBytes::<48>::repeat_byte(0xff);
+        let mut second = [0u8; 32];
+        second[..16].fill(0xff);
+        assert_eq!(key.hash_tree_root(), hash_nodes(&B256::repeat_byte(0xff), &second.into()));
+    }
+
+    #[test]
+    fn lists() {
+        // empty `ByteList[32]`
+        let empty = Bytes::new();
+        assert_eq!(empty.hash_tree_root(32), hash_nodes(&B256::ZERO, &B256::ZERO));
+        // empty `ByteList[128]` has 4 chunks
+        assert_eq!(empty.hash_tree_root(128), hash_nodes(&ZERO_HASH_2, &B256::ZERO));
+
+        let data = Bytes::from_static(&[1, 2, 3]);
+        let mut len = B256::ZERO;
+        len[0] = 3;
+        assert_eq!(
+            data.hash_tree_root(64),
+            hash_nodes(&hash_nodes(&B256::right_padding_from(&[1, 2, 3]), &B256::ZERO), &len)
+        );
+
+        // `List[uint64, 8]` packs 4 values per chunk
+        let values = [1u64, 2, 3, 4, 5];
+        let mut packed = Vec::new();
+        values.iter().for_each(|v| packed.extend_from_slice(&v.to_le_bytes()));
+        let root = merkleize(&pack(&packed), Some(2));
+        let mut len = B256::ZERO;
+        len[0] = 5;
+        assert_eq!(list_root(&values, 8), hash_nodes(&root, &len));
+
+        // `List[Bytes32, 4]`
+        let hashes = [B256::repeat_byte(1), B256::repeat_byte(2)];
+        let mut len = B256::ZERO;
+        len[0] = 2;
+        assert_eq!(
+            list_root(&hashes, 4),
+            hash_nodes(&hash_nodes(&hash_nodes(&hashes[0], &hashes[1]), &ZERO_HASH_1), &len)
+        );
+        assert_eq!(hashes.hash_tree_root(), hash_nodes(&hashes[0], &hashes[1]));
+    }
+
+    #[test]
+    #[should_panic = "3 items exceed list limit of 2"]
+    fn list_over_limit() {
+        list_root(&[0u8; 3], 2);
+    }
+}
@@ -0,0 +1,317 @@
+//! [ENS] name utilities.
+//!
+//! [ENS]: https://docs.ens.domains
+
+use super::keccak256;
+use crate::B256;
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// The maximum length of a single label in a DNS-encoded name.
+const MAX_DNS_LABEL_LEN: usize = 255;
+
+/// Error type for ENS name operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnsError {
+    /// A label in the name is empty, e.g. `"a..eth"`.
+    EmptyLabel,
+    /// A label is too long to be DNS-encoded. Contains the label's length in
+    /// bytes.
+    LabelTooLong(usize),
+    /// The name contains a disallowed ASCII character.
+    DisallowedCharacter(char),
+    /// The name contains a non-ASCII character, which is not supported by
+    /// [`normalize_ascii`].
+    UnsupportedCharacter(char),
+    /// A label contains an underscore that is not part of a leading sequence
+    /// of underscores.
+    UnderscoreNotAtStart,
+    /// A label has hyphens as its third and fourth characters, which is
+    /// reserved for label extensions such as punycode (`xn--`).
+    InvalidLabelExtension,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EnsError {}
+
+impl fmt::Display for EnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyLabel => f.write_str("empty label"),
+            Self::LabelTooLong(len) => {
+                write!(f, "label is {len} bytes long, which exceeds {MAX_DNS_LABEL_LEN} bytes")
+            }
+            Self::DisallowedCharacter(c) => write!(f, "disallowed character {c:?}"),
+            Self::UnsupportedCharacter(c) => write!(f, "unsupported non-ASCII character {c:?}"),
+            Self::UnderscoreNotAtStart => f.write_str("underscore allowed only at start"),
+            Self::InvalidLabelExtension => f.write_str("invalid label extension"),
+        }
+    }
+}
+
+/// Computes the [ENS labelhash] of a single label: `keccak256(label)`.
+///
+/// Labels in the encoded labelhash form, `[<64 hex digits>]`, are decoded
+/// instead of hashed, as specified by ENS.
+///
+/// This function does not normalize the label; use [`normalize_ascii`] first.
+///
+/// [ENS labelhash]: https://docs.ens.domains/resolution/names#labelhash
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, utils::labelhash};
+///
+/// assert_eq!(
+///     labelhash("eth"),
+///     b256!("4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0")
+/// );
+/// ```
+pub fn labelhash(label: &str) -> B256 {
+    encoded_labelhash(label).unwrap_or_else(|| keccak256(label))
+}
+
+/// Computes the [ENS namehash] of a name, as specified in [EIP-137].
+///
+/// The empty name hashes to [`B256::ZERO`].
+///
+/// This function does not normalize the name; use [`normalize_ascii`] first.
+///
+/// [ENS namehash]: https://docs.ens.domains/resolution/names#namehash
+/// [EIP-137]: https://eips.ethereum.org/EIPS/eip-137
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, utils::namehash, B256};
+///
+/// assert_eq!(namehash(""), B256::ZERO);
+/// assert_eq!(
+///     namehash("foo.eth"),
+///     b256!("de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+/// );
+/// ```
+pub fn namehash(name: &str) -> B256 {
+    if name.is_empty() {
+        return B256::ZERO;
+    }
+
+    let mut buf = [0u8; 64];
+    for label in name.rsplit('.') {
+        buf[32..].copy_from_slice(labelhash(label).as_slice());
+        let node = keccak256(buf);
+        buf[..32].copy_from_slice(node.as_slice());
+    }
+    B256::from_slice(&buf[..32])
+}
+
+/// Normalizes an ASCII ENS name.
+///
+/// Each label is lowercased and then validated:
+/// - labels must not be empty;
+/// - characters are limited to `a-z`, `0-9`, `-`, `_` and `$`;
+/// - underscores are only allowed at the start of a label;
+/// - the third and fourth characters of a label must not both be hyphens.
+///
+/// These are the rules that [ENSIP-15] applies to ASCII names.
+///
+/// # Limitations
+///
+/// This is **not** a full ENSIP-15 implementation. Names that contain any
+/// non-ASCII character, such as `"ΞTH.eth"`, emoji, or characters that
+/// ENSIP-15 maps to ASCII, are rejected with
+/// [`EnsError::UnsupportedCharacter`], even if they are valid ENS names.
+/// Normalizing them requires the Unicode data tables of the specification;
+/// use a dedicated ENSIP-15 implementation for such names.
+///
+/// The empty name normalizes to itself.
+///
+/// [ENSIP-15]: https://docs.ens.domains/ensip/15
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::utils::{normalize_ascii, EnsError};
+///
+/// assert_eq!(normalize_ascii("Nick.ETH").unwrap(), "nick.eth");
+/// assert_eq!(normalize_ascii("a_b.eth"), Err(EnsError::UnderscoreNotAtStart));
+/// assert_eq!(normalize_ascii("raffy🚴.eth"), Err(EnsError::UnsupportedCharacter('🚴')));
+/// ```
+pub fn normalize_ascii(name: &str) -> Result<String, EnsError> {
+    if name.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut out = String::with_capacity(name.len());
+    for (i, label) in name.split('.').enumerate() {
+        if i > 0 {
+            out.push('.');
+        }
+        normalize_label(label, &mut out)?;
+    }
+    Ok(out)
+}
+
+/// Normalizes a single label, appending it to `out`.
+fn normalize_label(label: &str, out: &mut String) -> Result<(), EnsError> {
+    if label.is_empty() {
+        return Err(EnsError::EmptyLabel);
+    }
+
+    let mut leading_underscores = true;
+    for c in label.chars() {
+        let c = c.to_ascii_lowercase();
+        match c {
+            '_' if !leading_underscores => return Err(EnsError::UnderscoreNotAtStart),
+            '_' => {}
+            'a'..='z' | '0'..='9' | '-' | '$' => leading_underscores = false,
+            c if c.is_ascii() => return Err(EnsError::DisallowedCharacter(c)),
+            c => return Err(EnsError::UnsupportedCharacter(c)),
+        }
+        out.push(c);
+    }
+
+    let mut chars = label.chars();
+    if chars.nth(2) == Some('-') && chars.next() == Some('-') {
+        return Err(EnsError::InvalidLabelExtension);
+    }
+
+    Ok(())
+}
+
+/// Decodes a label in the encoded labelhash form, `[<64 hex digits>]`.
+fn encoded_labelhash(label: &str) -> Option<B256> {
+    let hex = label.strip_prefix('[')?.strip_suffix(']')?;
+    if hex.len() != 64 {
+        return None;
+    }
+    hex.parse().ok()
+}
+
+/// DNS wire-format encoding of ENS names, as used by [ENSIP-10] wildcard
+/// resolution (`resolve(bytes name, bytes data)`).
+///
+/// Each label is prefixed by its length in bytes, and the name is terminated
+/// by a zero-length label. The name is not normalized; use [`normalize_ascii`]
+/// first.
+///
+/// [ENSIP-10]: https://docs.ens.domains/ensip/10
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{hex, utils::DnsEncode};
+///
+/// assert_eq!("vitalik.eth".dns_encode().unwrap(), hex!("07766974616c696b0365746800"));
+/// assert_eq!("".dns_encode().unwrap(), [0]);
+/// ```
+pub trait DnsEncode {
+    /// Encodes `self` in the DNS wire format.
+    fn dns_encode(&self) -> Result<Vec<u8>, EnsError>;
+}
+
+impl DnsEncode for str {
+    fn dns_encode(&self) -> Result<Vec<u8>, EnsError> {
+        let mut out = Vec::with_capacity(self.len() + 2);
+        if !self.is_empty() {
+            for label in self.split('.') {
+                let len = label.len();
+                if len == 0 {
+                    return Err(EnsError::EmptyLabel);
+                }
+                if len > MAX_DNS_LABEL_LEN {
+                    return Err(EnsError::LabelTooLong(len));
+                }
+                out.push(len as u8);
+                out.extend_from_slice(label.as_bytes());
+            }
+        }
+        out.push(0);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    // https://eips.ethereum.org/EIPS/eip-137#namehash-algorithm
+    #[test]
+    fn namehash_eip137() {
+        assert_eq!(namehash(""), B256::ZERO);
+        assert_eq!(
+            namehash("eth"),
+            b256!("93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            b256!("de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+        );
+    }
+
+    #[test]
+    fn labelhash_encoded() {
+        let eth = b256!("4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0");
+        assert_eq!(labelhash("eth"), eth);
+        assert_eq!(labelhash(&format!("[{}]", hex::encode(eth))), eth);
+        assert_eq!(namehash(&format!("foo.[{}]", hex::encode(eth))), namehash("foo.eth"));
+
+        // not a valid encoded labelhash, hashed as-is
+        assert_eq!(labelhash("[eth]"), keccak256("[eth]"));
+    }
+
+    // https://docs.ens.domains/ensip/15
+    #[test]
+    fn normalize_ascii_names() {
+        let ok = [
+            ("", ""),
+            ("eth", "eth"),
+            ("Nick.ETH", "nick.eth"),
+            ("vitalik.eth", "vitalik.eth"),
+            ("_dao.eth", "_dao.eth"),
+            ("__a.eth", "__a.eth"),
+            ("$abc.eth", "$abc.eth"),
+            ("a-b-c.eth", "a-b-c.eth"),
+            ("-a.eth", "-a.eth"),
+            ("ab-.eth", "ab-.eth"),
+            ("a--.eth", "a--.eth"),
+        ];
+        for (input, expected) in ok {
+            assert_eq!(normalize_ascii(input).as_deref(), Ok(expected), "{input:?}");
+        }
+
+        let err = [
+            ("a..eth", EnsError::EmptyLabel),
+            (".eth", EnsError::EmptyLabel),
+            ("eth.", EnsError::EmptyLabel),
+            ("a_b.eth", EnsError::UnderscoreNotAtStart),
+            ("ab--c.eth", EnsError::InvalidLabelExtension),
+            ("xn--ls8h.eth", EnsError::InvalidLabelExtension),
+            ("a b.eth", EnsError::DisallowedCharacter(' ')),
+            ("a!.eth", EnsError::DisallowedCharacter('!')),
+            ("a\0.eth", EnsError::DisallowedCharacter('\0')),
+            ("raffy🚴\u{200D}♂\u{FE0F}.eth", EnsError::UnsupportedCharacter('🚴')),
+            ("ΞTH.eth", EnsError::UnsupportedCharacter('Ξ')),
+            ("soft\u{00AD}hyphen.eth", EnsError::UnsupportedCharacter('\u{00AD}')),
+            ("a\u{3002}eth", EnsError::UnsupportedCharacter('\u{3002}')),
+        ];
+        for (input, expected) in err {
+            assert_eq!(normalize_ascii(input), Err(expected), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn dns_encode() {
+        assert_eq!("".dns_encode().unwrap(), [0]);
+        assert_eq!("eth".dns_encode().unwrap(), hex!("0365746800"));
+        assert_eq!("vitalik.eth".dns_encode().unwrap(), hex!("07766974616c696b0365746800"));
+        assert_eq!("a..eth".dns_encode(), Err(EnsError::EmptyLabel));
+
+        let long = "a".repeat(256);
+        assert_eq!(long.dns_encode(), Err(EnsError::LabelTooLong(256)));
+        assert_eq!(long[1..].dns_encode().unwrap().len(), 257);
+    }
+}
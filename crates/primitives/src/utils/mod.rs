@@ -5,9 +5,13 @@ use alloc::{boxed::Box, collections::TryReserveError, vec::Vec};
 use cfg_if::cfg_if;
 use core::{fmt, mem::MaybeUninit};
 
+mod ens;
+pub use ens::{labelhash, namehash, normalize_ascii, DnsEncode, EnsError};
+
 mod units;
 pub use units::{
-    format_ether, format_units, parse_ether, parse_units, ParseUnits, Unit, UnitsError,
+    format_ether, format_units, parse_ether, parse_sol_int, parse_sol_uint, parse_units,
+    ParseUnits, Unit, UnitsError, UnitsFormatter,
 };
 
 cfg_if! {
@@ -192,6 +196,10 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
 /// Note that the "native-keccak" feature is not supported for this struct, and will default to the
 /// [`tiny_keccak`] implementation.
 ///
+/// The hasher implements [`std::io::Write`] with the `std` feature, and the [`digest`] traits with
+/// the `digest` feature, so it can be passed to generic code expecting either.
+///
+/// [`digest`]: https://docs.rs/digest/0.10
 /// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
 #[derive(Clone)]
 pub struct Keccak256 {
@@ -279,6 +287,66 @@ impl Keccak256 {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::io::Write for Keccak256 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::HashMarker for Keccak256 {}
+
+#[cfg(feature = "digest")]
+impl digest::OutputSizeUser for Keccak256 {
+    type OutputSize = digest::consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl digest::Update for Keccak256 {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Self::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::FixedOutput for Keccak256 {
+    #[inline]
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        Self::finalize_into(self, out);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::Reset for Keccak256 {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::FixedOutputReset for Keccak256 {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+        core::mem::take(self).finalize_into(out);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +390,42 @@ mod tests {
         assert_eq!(hash, expected);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn keccak256_hasher_io_write() {
+        use std::io::Write;
+
+        let mut hasher = Keccak256::new();
+        let name = "world";
+        write!(hasher, "hello {name}").unwrap();
+        hasher.flush().unwrap();
+        assert_eq!(hasher.finalize(), keccak256("hello world"));
+
+        let mut hasher = Keccak256::new();
+        let n = std::io::copy(&mut &b"hello world"[..], &mut hasher).unwrap();
+        assert_eq!(n, 11);
+        assert_eq!(hasher.finalize(), keccak256("hello world"));
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn keccak256_hasher_digest() {
+        use digest::{Digest, FixedOutputReset};
+
+        fn hash<D: Digest>(data: &[u8]) -> Vec<u8> {
+            D::digest(data).to_vec()
+        }
+
+        let expected = keccak256("hello world");
+        assert_eq!(hash::<Keccak256>(b"hello world"), expected.to_vec());
+
+        let mut hasher = <Keccak256 as Digest>::new();
+        Digest::update(&mut hasher, b"hello");
+        Digest::update(&mut hasher, b" world");
+        assert_eq!(&hasher.finalize_fixed_reset()[..], expected);
+        assert_eq!(&Digest::finalize(hasher)[..], keccak256([]));
+    }
+
     #[test]
     fn test_try_boxing() {
         let x = Box::new(42);
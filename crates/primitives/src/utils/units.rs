@@ -1,4 +1,4 @@
-use crate::{ParseSignedError, I256, U256};
+use crate::{ParseSignedError, Rounding, Sign, UintMath, I256, U256};
 use alloc::string::{String, ToString};
 use core::fmt;
 
@@ -89,20 +89,436 @@ where
     units.try_into().map(|units| amount.into().format_units(units)).map_err(UnitsError::from)
 }
 
+/// Parses a Solidity number literal into a [`U256`].
+///
+/// The accepted syntax follows Solidity:
+/// - decimal integers and fractions: `1000`, `1.5`, `.5`;
+/// - hexadecimal integers with the `0x` prefix: `0xff`;
+/// - scientific notation: `2e18`, `15e-1`, `1.5e3`;
+/// - `_` separators between digits: `1_000_000`, `0xdead_beef`;
+/// - an optional unit suffix, separated by optional whitespace: `wei`, `gwei`,
+///   `ether`, `seconds`, `minutes`, `hours`, `days` or `weeks`. Hexadecimal
+///   literals cannot have a unit.
+///
+/// Fractions are allowed as long as the resulting value is an integer;
+/// otherwise [`UnitsError::PrecisionLoss`] is returned. Negative values are
+/// rejected, see [`parse_sol_int`] for signed integers.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::parse_sol_uint, U256};
+///
+/// assert_eq!(parse_sol_uint("1.5 ether")?, U256::from(1_500_000_000_000_000_000u128));
+/// assert_eq!(parse_sol_uint("2e9")?, U256::from(2_000_000_000u64));
+/// assert_eq!(parse_sol_uint("0x1_000")?, U256::from(0x1000));
+/// assert_eq!(parse_sol_uint("2 weeks")?, U256::from(1_209_600));
+/// assert!(parse_sol_uint("1.5 wei").is_err());
+/// # Ok::<(), alloy_primitives::utils::UnitsError>(())
+/// ```
+pub fn parse_sol_uint(literal: &str) -> Result<U256, UnitsError> {
+    match parse_sol_literal(literal)? {
+        (Sign::Negative, abs) if !abs.is_zero() => {
+            Err(UnitsError::InvalidLiteral(literal.to_string()))
+        }
+        (_, abs) => Ok(abs),
+    }
+}
+
+/// Parses a Solidity number literal, optionally preceded by `-`, into an
+/// [`I256`].
+///
+/// See [`parse_sol_uint`] for the accepted syntax.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::parse_sol_int, I256};
+///
+/// assert_eq!(parse_sol_int("-1.5 gwei")?, I256::try_from(-1_500_000_000i64).unwrap());
+/// assert_eq!(parse_sol_int("-0x80")?, I256::try_from(-128).unwrap());
+/// assert_eq!(parse_sol_int("25e-1 ether")?, I256::try_from(2_500_000_000_000_000_000i128).unwrap());
+/// # Ok::<(), alloy_primitives::utils::UnitsError>(())
+/// ```
+pub fn parse_sol_int(literal: &str) -> Result<I256, UnitsError> {
+    let (sign, abs) = parse_sol_literal(literal)?;
+    I256::checked_from_sign_and_abs(sign, abs).ok_or(UnitsError::Overflow)
+}
+
+/// Parses a Solidity number literal into its sign and absolute value.
+fn parse_sol_literal(literal: &str) -> Result<(Sign, U256), UnitsError> {
+    let invalid = || UnitsError::InvalidLiteral(literal.to_string());
+    let overflow = || UnitsError::Overflow;
+
+    let s = literal.trim();
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (Sign::Negative, s),
+        None => (Sign::Positive, s),
+    };
+
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        let digits = strip_digit_separators(hex, 16)
+            .filter(|digits| !digits.is_empty())
+            .ok_or_else(invalid)?;
+        let abs = U256::from_str_radix(&digits, 16).map_err(|_| overflow())?;
+        return Ok((sign, abs));
+    }
+
+    // Split the literal into `int.fract e exp` and the unit.
+    let bytes = s.as_bytes();
+    let scan = |mut i: usize| {
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+            i += 1;
+        }
+        i
+    };
+    let int_end = scan(0);
+    let (fract_start, fract_end) = if bytes.get(int_end) == Some(&b'.') {
+        (int_end + 1, scan(int_end + 1))
+    } else {
+        (int_end, int_end)
+    };
+    let mut exp_start = fract_end;
+    let mut exp_end = fract_end;
+    if matches!(bytes.get(fract_end), Some(b'e' | b'E')) {
+        let digits_start = fract_end + 1 + (bytes.get(fract_end + 1) == Some(&b'-')) as usize;
+        if bytes.get(digits_start).map_or(false, u8::is_ascii_digit) {
+            exp_start = fract_end + 1;
+            exp_end = scan(digits_start);
+        }
+    }
+
+    let int = strip_digit_separators(&s[..int_end], 10).ok_or_else(invalid)?;
+    let fract = if fract_start == int_end {
+        String::new()
+    } else {
+        strip_digit_separators(&s[fract_start..fract_end], 10)
+            .filter(|fract| !fract.is_empty())
+            .ok_or_else(invalid)?
+    };
+    if int.is_empty() && fract.is_empty() {
+        return Err(invalid());
+    }
+    let exp = if exp_start == exp_end {
+        0
+    } else {
+        let exp = &s[exp_start..exp_end];
+        let (negative, digits) = match exp.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, exp),
+        };
+        let digits = strip_digit_separators(digits, 10).ok_or_else(invalid)?;
+        let exp = digits.parse::<i64>().map_err(|_| overflow())?;
+        if negative {
+            -exp
+        } else {
+            exp
+        }
+    };
+
+    let multiplier: u64 = match s[exp_end..].trim_start() {
+        "" | "wei" | "seconds" => 1,
+        "gwei" => 1_000_000_000,
+        "ether" => 1_000_000_000_000_000_000,
+        "minutes" => 60,
+        "hours" => 3_600,
+        "days" => 86_400,
+        "weeks" => 604_800,
+        unit if unit.bytes().all(|b| b.is_ascii_alphabetic()) => {
+            return Err(UnitsError::InvalidUnit(unit.to_string()))
+        }
+        _ => return Err(invalid()),
+    };
+
+    // The value is `digits * 10^scale * multiplier`, where trailing zeros are
+    // moved from the digits to the scale to keep the mantissa small.
+    let mut digits = int;
+    digits.push_str(&fract);
+    let digits = digits.trim_start_matches('0');
+    let mantissa = digits.trim_end_matches('0');
+    if mantissa.is_empty() {
+        return Ok((sign, U256::ZERO));
+    }
+    let scale = exp
+        .checked_sub(fract.len() as i64)
+        .and_then(|scale| scale.checked_add((digits.len() - mantissa.len()) as i64))
+        .ok_or_else(overflow)?;
+
+    let value = U256::from_str_radix(mantissa, 10)
+        .ok()
+        .and_then(|mantissa| mantissa.checked_mul(U256::from(multiplier)))
+        .ok_or_else(overflow)?;
+    let abs = if scale >= 0 {
+        usize::try_from(scale)
+            .ok()
+            .and_then(pow10)
+            .and_then(|exp10| value.checked_mul(exp10))
+            .ok_or_else(overflow)?
+    } else {
+        // `value` is not zero, so it can only be divisible by a power of ten
+        // that fits in a `U256`.
+        usize::try_from(scale.unsigned_abs())
+            .ok()
+            .and_then(pow10)
+            .filter(|exp10| (value % exp10).is_zero())
+            .map(|exp10| value / exp10)
+            .ok_or_else(|| UnitsError::PrecisionLoss(literal.to_string()))?
+    };
+    Ok((sign, abs))
+}
+
+/// Removes the `_` separators from a sequence of digits in the given radix.
+///
+/// Returns `None` if the input contains other characters, or if a separator is
+/// not placed between two digits.
+fn strip_digit_separators(s: &str, radix: u32) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut digits = String::with_capacity(s.len());
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'_' {
+            let is_digit = |j: Option<usize>| {
+                j.and_then(|j| bytes.get(j)).map_or(false, |&b| (b as char).is_digit(radix))
+            };
+            if !is_digit(i.checked_sub(1)) || !is_digit(Some(i + 1)) {
+                return None;
+            }
+        } else if (b as char).is_digit(radix) {
+            digits.push(b as char);
+        } else {
+            return None;
+        }
+    }
+    Some(digits)
+}
+
+/// A configurable formatter for numbers of Wei.
+///
+/// By default, this formats numbers like [`format_units`]: with all the
+/// decimals of the unit, and without separators or symbol.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     utils::{Unit, UnitsFormatter},
+///     Rounding, U256,
+/// };
+///
+/// let amount = U256::from(1_234_567_891_000_000_000_000_u128);
+///
+/// let formatter = UnitsFormatter::new(Unit::ETHER).max_decimals(2).symbol("ETH");
+/// assert_eq!(formatter.format(amount), "1234.56 ETH");
+///
+/// let formatter = formatter.rounding(Rounding::HalfUp).thousands_separator(',');
+/// assert_eq!(formatter.format(amount), "1,234.57 ETH");
+///
+/// let formatter = UnitsFormatter::new(Unit::ETHER).max_decimals(1).compact(true);
+/// assert_eq!(formatter.format(amount), "1.2k");
+///
+/// let formatter = UnitsFormatter::new(Unit::GWEI).trim_trailing_zeros(true);
+/// assert_eq!(formatter.format(1_500_000_000_u64), "1.5");
+/// assert_eq!(formatter.format(-2_000_000_000_i64), "-2");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnitsFormatter {
+    unit: Unit,
+    max_decimals: Option<usize>,
+    rounding: Rounding,
+    trim_trailing_zeros: bool,
+    thousands_separator: Option<char>,
+    compact: bool,
+    symbol: Option<String>,
+}
+
+impl Default for UnitsFormatter {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Unit::ETHER)
+    }
+}
+
+impl UnitsFormatter {
+    /// The suffixes used by [`compact`](Self::compact) formatting, for each
+    /// power of 1000.
+    const COMPACT_SUFFIXES: [&'static str; 5] = ["", "k", "M", "B", "T"];
+
+    /// Creates a new formatter for the given unit.
+    #[inline]
+    pub const fn new(unit: Unit) -> Self {
+        Self {
+            unit,
+            max_decimals: None,
+            rounding: Rounding::Trunc,
+            trim_trailing_zeros: false,
+            thousands_separator: None,
+            compact: false,
+            symbol: None,
+        }
+    }
+
+    /// Sets the unit, i.e. the number of decimals of the formatted numbers.
+    #[inline]
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the maximum number of decimals to display.
+    ///
+    /// Numbers with more decimals are rounded according to
+    /// [`rounding`](Self::rounding).
+    #[inline]
+    pub const fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Sets the rounding mode used when truncating decimals. Defaults to
+    /// [`Rounding::Trunc`].
+    #[inline]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets whether to remove trailing zeros from the decimals, as well as the
+    /// decimal point if no decimals are left.
+    #[inline]
+    pub const fn trim_trailing_zeros(mut self, trim_trailing_zeros: bool) -> Self {
+        self.trim_trailing_zeros = trim_trailing_zeros;
+        self
+    }
+
+    /// Sets the separator inserted between groups of three digits of the
+    /// integer part.
+    #[inline]
+    pub const fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Sets whether to format large numbers with a compact suffix: `k`, `M`,
+    /// `B` or `T`.
+    ///
+    /// [`max_decimals`](Self::max_decimals) then applies to the scaled
+    /// number, e.g. `1.2k` for `1234` with one decimal.
+    #[inline]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the symbol appended to the formatted number, separated by a space.
+    #[inline]
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Formats the given number of Wei.
+    pub fn format<T: Into<ParseUnits>>(&self, amount: T) -> String {
+        let (negative, abs) = match amount.into() {
+            ParseUnits::U256(n) => (false, n),
+            ParseUnits::I256(n) => (n.is_negative(), n.unsigned_abs()),
+        };
+        // round the absolute value in the same direction as the signed value
+        let rounding = if negative { self.rounding.negated() } else { self.rounding };
+        let units = self.unit.get() as usize;
+
+        let max_scale = if self.compact { Self::COMPACT_SUFFIXES.len() - 1 } else { 0 };
+        let mut scale = 0;
+        while scale < max_scale && pow10(units + 3 * (scale + 1)).map_or(false, |p| abs >= p) {
+            scale += 1;
+        }
+
+        // `value` is the rounded number in units of `10^-decimals`
+        let (value, decimals) = loop {
+            let total_decimals = units + 3 * scale;
+            let decimals = self.max_decimals.map_or(total_decimals, |max| max.min(total_decimals));
+            let value = match pow10(total_decimals - decimals) {
+                Some(divisor) => abs.mul_div(U256::from(1), divisor, rounding).unwrap(),
+                // `abs` is less than half of the divisor
+                None if rounding == Rounding::Ceil && abs != U256::ZERO => U256::from(1),
+                None => U256::ZERO,
+            };
+
+            // rounding may carry over to the next suffix, e.g. `999.99k` -> `1.0M`
+            let next = pow10(decimals + 3);
+            if scale < max_scale && next.map_or(false, |next| value >= next) {
+                scale += 1;
+                continue;
+            }
+            break (value, decimals);
+        };
+
+        let exp10 = pow10(decimals).unwrap();
+        let integer = (value / exp10).to_string();
+        let mut fraction = if decimals == 0 {
+            String::new()
+        } else {
+            let fraction = (value % exp10).to_string();
+            format!("{fraction:0>decimals$}")
+        };
+        if self.trim_trailing_zeros {
+            fraction.truncate(fraction.trim_end_matches('0').len());
+        }
+
+        let mut s = String::with_capacity(integer.len() * 2 + fraction.len() + 8);
+        if negative && value != U256::ZERO {
+            s.push('-');
+        }
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    s.push(separator);
+                }
+            }
+            s.push(c);
+        }
+        if !fraction.is_empty() {
+            s.push('.');
+            s.push_str(&fraction);
+        }
+        s.push_str(Self::COMPACT_SUFFIXES[scale]);
+        if let Some(symbol) = &self.symbol {
+            s.push(' ');
+            s.push_str(symbol);
+        }
+        s
+    }
+}
+
+/// Returns `10^n`, or `None` if it overflows.
+#[inline]
+fn pow10(n: usize) -> Option<U256> {
+    U256::from(10).checked_pow(U256::from(n))
+}
+
 /// Error type for [`Unit`]-related operations.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum UnitsError {
     /// The provided units are not recognized.
     InvalidUnit(String),
     /// Overflow when parsing a signed number.
     ParseSigned(ParseSignedError),
+    /// The input is not a valid Solidity number literal.
+    InvalidLiteral(String),
+    /// The number literal does not represent an integer.
+    PrecisionLoss(String),
+    /// The number does not fit in 256 bits. Returned by [`parse_sol_uint`] and
+    /// [`parse_sol_int`].
+    Overflow,
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for UnitsError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InvalidUnit(_) => None,
+            Self::InvalidUnit(_)
+            | Self::InvalidLiteral(_)
+            | Self::PrecisionLoss(_)
+            | Self::Overflow => None,
             Self::ParseSigned(e) => Some(e),
         }
     }
@@ -113,6 +529,9 @@ impl fmt::Display for UnitsError {
         match self {
             Self::InvalidUnit(s) => write!(f, "{s:?} is not a valid unit"),
             Self::ParseSigned(e) => e.fmt(f),
+            Self::InvalidLiteral(s) => write!(f, "{s:?} is not a valid number literal"),
+            Self::PrecisionLoss(s) => write!(f, "{s:?} is not an integer"),
+            Self::Overflow => f.write_str("number does not fit in 256 bits"),
         }
     }
 }
@@ -259,6 +678,14 @@ impl ParseUnits {
         }
     }
 
+    /// Formats the number of Wei using the given formatter.
+    ///
+    /// See [`UnitsFormatter`] for more information.
+    #[inline]
+    pub fn format_with(self, formatter: &UnitsFormatter) -> String {
+        formatter.format(self)
+    }
+
     /// Returns `true` if the number is signed.
     #[inline]
     pub const fn is_signed(&self) -> bool {
@@ -616,6 +1043,103 @@ mod tests {
         assert_eq!(eth, "-9.223372036854775808");
     }
 
+    #[test]
+    fn formatter_default() {
+        let formatter = UnitsFormatter::default();
+        for amount in [
+            ParseUnits::U256(U256::from(1395633240123456789_u128)),
+            ParseUnits::U256(U256::MAX),
+            ParseUnits::I256(I256::from_dec_str("-1395633240123456789").unwrap()),
+            ParseUnits::I256(I256::MIN),
+        ] {
+            assert_eq!(formatter.format(amount), format_ether(amount));
+            assert_eq!(amount.format_with(&formatter), format_ether(amount));
+        }
+    }
+
+    #[test]
+    fn formatter_rounding() {
+        let amount = U256::from(1_995_000_000_000_000_000_u128);
+        let formatter = UnitsFormatter::new(Unit::ETHER).max_decimals(2);
+        assert_eq!(formatter.format(amount), "1.99");
+        assert_eq!(formatter.clone().rounding(Rounding::Floor).format(amount), "1.99");
+        assert_eq!(formatter.clone().rounding(Rounding::Ceil).format(amount), "2.00");
+        assert_eq!(formatter.clone().rounding(Rounding::HalfUp).format(amount), "2.00");
+        assert_eq!(formatter.clone().max_decimals(0).format(amount), "1");
+        assert_eq!(
+            formatter.clone().max_decimals(0).rounding(Rounding::HalfUp).format(amount),
+            "2"
+        );
+
+        let negative = I256::from_dec_str("-1995000000000000000").unwrap();
+        assert_eq!(formatter.format(negative), "-1.99");
+        assert_eq!(formatter.clone().rounding(Rounding::Floor).format(negative), "-2.00");
+        assert_eq!(formatter.clone().rounding(Rounding::Ceil).format(negative), "-1.99");
+        assert_eq!(formatter.clone().rounding(Rounding::HalfUp).format(negative), "-2.00");
+
+        // no negative zero
+        assert_eq!(formatter.format(-1_i64), "0.00");
+        assert_eq!(formatter.clone().rounding(Rounding::Floor).format(-1_i64), "-0.01");
+        assert_eq!(formatter.clone().rounding(Rounding::Ceil).format(1_u64), "0.01");
+
+        // more decimals than the unit
+        assert_eq!(UnitsFormatter::new(Unit::GWEI).max_decimals(20).format(1_u64), "0.000000001");
+    }
+
+    #[test]
+    fn formatter_trim_and_separator() {
+        let formatter = UnitsFormatter::new(Unit::ETHER).trim_trailing_zeros(true);
+        assert_eq!(formatter.format(Unit::ETHER.wei()), "1");
+        assert_eq!(formatter.format(U256::ZERO), "0");
+        assert_eq!(formatter.format(1_500_000_000_000_000_000_u128), "1.5");
+        assert_eq!(formatter.format(1_u64), "0.000000000000000001");
+
+        let formatter = formatter.thousands_separator('_');
+        assert_eq!(formatter.format(Unit::ETHER.wei() * U256::from(999)), "999");
+        assert_eq!(formatter.format(Unit::ETHER.wei() * U256::from(1000)), "1_000");
+        assert_eq!(formatter.format(Unit::ETHER.wei() * U256::from(1234567)), "1_234_567");
+        assert_eq!(
+            formatter.format(I256::from_dec_str("-123456500000000000000000").unwrap()),
+            "-123_456.5"
+        );
+        assert_eq!(
+            UnitsFormatter::new(Unit::WEI).thousands_separator(',').format(U256::MAX),
+            "115,792,089,237,316,195,423,570,985,008,687,907,853,269,984,665,640,564,039,457,584,007,913,129,639,935"
+        );
+    }
+
+    #[test]
+    fn formatter_compact() {
+        let formatter = UnitsFormatter::new(Unit::WEI).compact(true).max_decimals(1);
+        assert_eq!(formatter.format(0_u64), "0");
+        assert_eq!(formatter.format(999_u64), "999");
+        assert_eq!(formatter.format(1_000_u64), "1.0k");
+        assert_eq!(formatter.format(1_234_u64), "1.2k");
+        assert_eq!(formatter.format(3_456_789_u64), "3.4M");
+        assert_eq!(formatter.format(-3_456_789_i64), "-3.4M");
+        assert_eq!(formatter.format(7_000_000_000_u64), "7.0B");
+        assert_eq!(formatter.format(12_300_000_000_000_u64), "12.3T");
+        assert_eq!(formatter.format(12_300_000_000_000_000_u64), "12300.0T");
+
+        // rounding carries over to the next suffix
+        let formatter = formatter.rounding(Rounding::HalfUp);
+        assert_eq!(formatter.format(999_960_u64), "1.0M");
+        assert_eq!(formatter.format(999_940_u64), "999.9k");
+
+        let formatter = UnitsFormatter::new(Unit::ETHER)
+            .compact(true)
+            .max_decimals(2)
+            .trim_trailing_zeros(true)
+            .symbol("ETH");
+        assert_eq!(formatter.format(Unit::ETHER.wei() * U256::from(1_500_000)), "1.5M ETH");
+        assert_eq!(formatter.format(Unit::ETHER.wei() / U256::from(4)), "0.25 ETH");
+        assert_eq!(
+            formatter.format(U256::MAX),
+            "115792089237316195423570985008687907853269984665.64T ETH"
+        );
+        assert_eq!(formatter.clone().unit(Unit::MAX).format(U256::MAX), "1.15 ETH");
+    }
+
     #[test]
     fn test_format_units_unsigned() {
         let gwei_in_ether = format_units(Unit::ETHER.wei(), 9).unwrap();
@@ -761,6 +1285,13 @@ mod tests {
         assert_eq!(n, U256::from(123), "truncate too many decimals");
 
         assert!(parse_units("1", 80).is_err(), "overflow");
+        assert!(
+            matches!(
+                parse_units("-1", 77),
+                Err(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))
+            ),
+            "signed overflow"
+        );
 
         let two_e30 = U256::from(2) * U256::from_limbs([0x4674edea40000000, 0xc9f2c9cd0, 0x0, 0x0]);
         let n: U256 = parse_units("2", 30).unwrap().into();
@@ -824,4 +1355,95 @@ mod tests {
         let n: I256 = parse_units("-", 3).unwrap().into();
         assert_eq!(n, I256::ZERO, "empty");
     }
+
+    #[test]
+    fn parse_sol_literals() {
+        let ether = Unit::ETHER.wei();
+        let cases = [
+            ("0", U256::ZERO),
+            ("1_000_000", U256::from(1_000_000)),
+            ("0x1_0", U256::from(16)),
+            ("0XfF", U256::from(255)),
+            (".5 ether", ether / U256::from(2)),
+            ("1.5ether", ether * U256::from(3) / U256::from(2)),
+            ("  2.000 gwei  ", U256::from(2_000_000_000u64)),
+            ("1e18", ether),
+            ("1E18 wei", ether),
+            ("15e-1 ether", ether * U256::from(3) / U256::from(2)),
+            ("0.01e2", U256::from(1)),
+            ("1_0e1_0", U256::from(100_000_000_000u64)),
+            ("0.0e-100", U256::ZERO),
+            ("100e-2", U256::from(1)),
+            ("1 seconds", U256::from(1)),
+            ("2 minutes", U256::from(120)),
+            ("1.5 hours", U256::from(5400)),
+            ("1 days", U256::from(86_400)),
+            ("1 weeks", U256::from(604_800)),
+            ("-0", U256::ZERO),
+        ];
+        for (literal, expected) in cases {
+            assert_eq!(parse_sol_uint(literal).unwrap(), expected, "{literal}");
+            assert_eq!(parse_sol_int(literal).unwrap(), I256::from_raw(expected), "{literal}");
+        }
+
+        assert_eq!(parse_sol_int("-1 ether").unwrap(), -I256::from_raw(ether));
+        assert_eq!(parse_sol_int("-0x80").unwrap(), I256::try_from(-128).unwrap());
+        assert_eq!(parse_sol_int(&format!("-{}", I256::MIN.unsigned_abs())).unwrap(), I256::MIN);
+        assert_eq!(parse_sol_uint(&U256::MAX.to_string()).unwrap(), U256::MAX);
+    }
+
+    #[test]
+    fn parse_sol_literal_errors() {
+        let overflow = |literal: &str| matches!(parse_sol_uint(literal), Err(UnitsError::Overflow));
+        assert!(overflow(&format!("{}0", U256::MAX)));
+        assert!(overflow("1e78"));
+        assert!(overflow("1e99999999999999999999"));
+        assert!(overflow("0x1_0000000000000000000000000000000000000000000000000000000000000000"));
+        assert!(matches!(
+            parse_sol_int(&I256::MIN.unsigned_abs().to_string()),
+            Err(UnitsError::Overflow)
+        ));
+
+        for literal in ["1.5", "1.5 wei", "1e-1", "1.0000000000000000001 ether", "1e-100"] {
+            assert!(
+                matches!(parse_sol_uint(literal), Err(UnitsError::PrecisionLoss(_))),
+                "{literal}"
+            );
+        }
+
+        for literal in [
+            "",
+            "-",
+            ".",
+            "1.",
+            "1..2",
+            "1.2.3",
+            "_1",
+            "1_",
+            "1__0",
+            "1._5",
+            "0x",
+            "0x_1",
+            "0xg",
+            "0x1 ether",
+            "1e",
+            "1e-",
+            "1e1.5",
+            "- 1",
+            "--1",
+            "+1",
+            "1 ether wei",
+            "-1",
+        ] {
+            assert!(
+                matches!(
+                    parse_sol_uint(literal),
+                    Err(UnitsError::InvalidLiteral(_) | UnitsError::InvalidUnit(_))
+                ),
+                "{literal}"
+            );
+        }
+        assert!(matches!(parse_sol_uint("1 eth"), Err(UnitsError::InvalidUnit(u)) if u == "eth"));
+        assert!(matches!(parse_sol_uint("1 finney"), Err(UnitsError::InvalidUnit(_))));
+    }
 }
@@ -143,6 +143,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
                 const SOL_NAME: &'static str = #uint8_st::SOL_NAME;
                 const ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::ENCODED_SIZE;
+                const PACKED_ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::PACKED_ENCODED_SIZE;
 
                 #[inline]
                 fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -163,6 +164,25 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                         #uint8_st::detokenize(token)
                     ).#detokenize_unwrap
                 }
+
+                #[inline]
+                fn abi_decode_packed_from(data: &mut &[u8]) -> alloy_sol_types::Result<Self::RustType> {
+                    <Self as ::core::convert::TryFrom<u8>>::try_from(
+                        #uint8_st::abi_decode_packed_from(data)?
+                    )
+                }
+            }
+
+            #[automatically_derived]
+            impl alloy_sol_types::SolTypeRef for #name {
+                type RustRef<'de> = #name;
+
+                #[inline]
+                fn decode_ref<'de>(
+                    dec: &mut alloy_sol_types::abi::Decoder<'de>,
+                ) -> alloy_sol_types::Result<Self::RustRef<'de>> {
+                    dec.decode::<Self::Token<'de>>().map(<Self as alloy_sol_types::SolType>::detokenize)
+                }
             }
 
             #[automatically_derived]
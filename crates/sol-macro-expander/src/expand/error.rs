@@ -1,6 +1,6 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
+use super::{expand_fields, expand_from_into_tuples, expand_param_names, expand_tokenize, ExpCtxt};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::ItemError;
 use proc_macro2::TokenStream;
@@ -36,6 +36,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
 
     let converts = expand_from_into_tuples(&name.0, params, cx);
     let fields = expand_fields(params, cx);
+    let param_names = expand_param_names(params);
     let doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
         mk_doc(format!(
@@ -91,6 +92,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
                 fn tokenize(&self) -> Self::Token<'_> {
                     #tokenize_impl
                 }
+
+                #[inline]
+                fn abi_decode_raw(data: &[u8], validate: bool) -> alloy_sol_types::Result<Self> {
+                    <Self::Parameters<'_> as alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                        .map(Self::new)
+                        .map_err(|e| e.name_member(&#param_names))
+                }
             }
 
             #abi
@@ -1,6 +1,9 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_event_tokenize, expand_tuple_types, expand_type, ty, ExpCtxt};
+use super::{
+    anon_name, expand_event_tokenize, expand_ref_phantom, expand_ref_struct, expand_tuple_types,
+    expand_type, ty, ExpCtxt,
+};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
@@ -17,6 +20,17 @@ use syn::Result;
 /// impl SolEvent for #name {
 ///     ...
 /// }
+///
+/// #if borrowed
+/// pub struct #{name}Ref<'de> {
+///     #(pub #parameter_name: <#parameter_type as SolTypeRef>::RustRef<'de>,)*
+/// }
+///
+/// impl<'de> #{name}Ref<'de> {
+///     pub fn decode_raw_log(topics, data: &'de [u8], validate: bool) -> Result<Self> { ... }
+///     pub fn decode_log_data(log: &'de LogData, validate: bool) -> Result<Self> { ... }
+/// }
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream> {
     let params = event.params();
@@ -25,6 +39,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     cx.derives(&mut attrs, &params, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let borrowed = sol_attrs.borrowed.or(cx.attrs.borrowed).unwrap_or(false);
 
     cx.assert_resolved(&params)?;
     event.assert_valid()?;
@@ -58,6 +73,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         }
         quote!(#name: #param)
     });
+    let new_impl: Vec<_> = new_impl.collect();
 
     // NOTE: We need to enumerate before filtering.
     let topic_tuple_names = event
@@ -100,6 +116,69 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
 
     let tokenize_body_impl = expand_event_tokenize(&event.parameters, cx);
 
+    let (ref_struct, ref_impl) = if borrowed {
+        let ref_name = cx.raw_ref_name(&name.0);
+        let ref_fields = event.parameters.iter().enumerate().map(|(i, p)| {
+            let name = anon_name((i, p.name.as_ref()));
+            let ty = if p.indexed_as_hash() {
+                let alloy_sol_types = &cx.crates.sol_types;
+                quote_spanned! {p.ty.span()=> #alloy_sol_types::sol_data::FixedBytes<32> }
+            } else {
+                expand_type(&p.ty, &cx.crates)
+            };
+            (name, ty)
+        });
+        let ref_struct = expand_ref_struct(
+            cx,
+            &name.0,
+            &ref_name,
+            ref_fields,
+            cx.ref_derives(params.types()),
+            docs,
+        );
+        let ref_phantom = expand_ref_phantom(&params);
+        let ref_impl = quote! {
+            #[automatically_derived]
+            impl<'de> #ref_name<'de> {
+                /// Decodes the borrowed event from the given log info.
+                ///
+                /// See [`SolEvent::decode_raw_log`](alloy_sol_types::SolEvent::decode_raw_log).
+                #[allow(unused_variables)]
+                #[inline]
+                pub fn decode_raw_log<I, D>(
+                    topics: I,
+                    data: &'de [u8],
+                    validate: bool,
+                ) -> alloy_sol_types::Result<Self>
+                where
+                    I: IntoIterator<Item = D>,
+                    D: Into<alloy_sol_types::abi::token::WordToken>,
+                {
+                    let topics = <#name as alloy_sol_types::SolEvent>::decode_topics(topics)?;
+                    let data = <<#name as alloy_sol_types::SolEvent>::DataTuple<'de> as alloy_sol_types::SolTypeRef>::abi_decode_sequence_ref(data, validate)?;
+                    Ok(Self {
+                        #(#new_impl,)*
+                        #ref_phantom
+                    })
+                }
+
+                /// Decodes the borrowed event from the given log object.
+                ///
+                /// See [`SolEvent::decode_log_data`](alloy_sol_types::SolEvent::decode_log_data).
+                #[inline]
+                pub fn decode_log_data(
+                    log: &'de alloy_sol_types::private::LogData,
+                    validate: bool,
+                ) -> alloy_sol_types::Result<Self> {
+                    Self::decode_raw_log(log.topics(), &log.data, validate)
+                }
+            }
+        };
+        (Some(ref_struct), Some(ref_impl))
+    } else {
+        (None, None)
+    };
+
     let encode_topics_impl = encode_first_topic
         .into_iter()
         .chain(encode_topics_impl)
@@ -145,6 +224,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
             )*
         }
 
+        #ref_struct
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             use #alloy_sol_types as alloy_sol_types;
@@ -196,6 +277,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                 }
             }
 
+            #ref_impl
+
             impl From<&#name> for alloy_sol_types::private::LogData {
                 #[inline]
                 fn from(this: &#name) -> alloy_sol_types::private::LogData {
@@ -1,6 +1,9 @@
 //! [`ItemFunction`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types, ExpCtxt};
+use super::{
+    anon_name, expand_fields, expand_from_into_tuples, expand_param_names, expand_ref_phantom,
+    expand_ref_struct, expand_tokenize, expand_tuple_types, expand_type, ExpCtxt,
+};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{FunctionKind, ItemFunction, Spanned};
 use proc_macro2::TokenStream;
@@ -22,6 +25,17 @@ use syn::Result;
 ///     type Return = #{name}Return;
 ///     ...
 /// }
+///
+/// #if borrowed
+/// pub struct #{name}CallRef<'de> {
+///     #(pub #argument_name: <#argument_type as SolTypeRef>::RustRef<'de>,)*
+/// }
+///
+/// impl<'de> #{name}CallRef<'de> {
+///     pub fn abi_decode_raw(data: &'de [u8], validate: bool) -> Result<Self> { ... }
+///     pub fn abi_decode(data: &'de [u8], validate: bool) -> Result<Self> { ... }
+/// }
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenStream> {
     let ItemFunction { parameters, returns, name, kind, .. } = function;
@@ -50,12 +64,15 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     }
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let borrowed = sol_attrs.borrowed.or(cx.attrs.borrowed).unwrap_or(false);
 
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
 
     let call_fields = expand_fields(parameters, cx);
     let return_fields = expand_fields(returns, cx);
+    let param_names = expand_param_names(parameters);
+    let return_names = expand_param_names(returns);
 
     let call_tuple = expand_tuple_types(parameters.types(), cx).0;
     let return_tuple = expand_tuple_types(returns.types(), cx).0;
@@ -67,6 +84,60 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let selector = crate::utils::selector(&signature);
     let tokenize_impl = expand_tokenize(parameters, cx);
 
+    let (ref_struct, ref_impl) = if borrowed {
+        let ref_name = cx.raw_ref_name(&call_name);
+        let ref_fields: Vec<_> = parameters
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (anon_name((i, p.name.as_ref())), expand_type(&p.ty, &cx.crates)))
+            .collect();
+        let ref_names = ref_fields.iter().map(|(name, _)| name);
+        let ref_idxs = (0..parameters.len()).map(syn::Index::from);
+        let ref_phantom = expand_ref_phantom(parameters);
+        let ref_struct = expand_ref_struct(
+            cx,
+            &call_name,
+            &ref_name,
+            ref_fields.clone(),
+            cx.ref_derives(parameters.types()),
+            docs,
+        );
+        let ref_impl = quote! {
+            #[automatically_derived]
+            impl<'de> #ref_name<'de> {
+                /// ABI-decodes the borrowed call arguments from the given slice,
+                /// **without** the selector.
+                ///
+                /// See [`SolCall::abi_decode_raw`](alloy_sol_types::SolCall::abi_decode_raw).
+                #[allow(unused_variables)]
+                #[inline]
+                pub fn abi_decode_raw(data: &'de [u8], validate: bool) -> alloy_sol_types::Result<Self> {
+                    <#call_tuple as alloy_sol_types::SolTypeRef>::abi_decode_sequence_ref(data, validate)
+                        .map(|tuple| Self { #(#ref_names: tuple.#ref_idxs,)* #ref_phantom })
+                        .map_err(|e| e.name_member(&#param_names))
+                }
+
+                /// ABI-decodes the borrowed call arguments from the given slice,
+                /// **with** the selector.
+                ///
+                /// See [`SolCall::abi_decode`](alloy_sol_types::SolCall::abi_decode).
+                #[inline]
+                pub fn abi_decode(data: &'de [u8], validate: bool) -> alloy_sol_types::Result<Self> {
+                    let data = data
+                        .strip_prefix(&<#call_name as alloy_sol_types::SolCall>::SELECTOR)
+                        .ok_or_else(|| alloy_sol_types::Error::type_check_fail_sig(
+                            data,
+                            <#call_name as alloy_sol_types::SolCall>::SIGNATURE,
+                        ))?;
+                    Self::abi_decode_raw(data, validate)
+                }
+            }
+        };
+        (Some(ref_struct), Some(ref_impl))
+    } else {
+        (None, None)
+    };
+
     let call_doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
         mk_doc(format!(
@@ -116,6 +187,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
             #(#return_fields),*
         }
 
+        #ref_struct
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             use #alloy_sol_types as alloy_sol_types;
@@ -146,12 +219,27 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                     #tokenize_impl
                 }
 
+                #[inline]
+                fn abi_decode_raw_with_limits(
+                    data: &[u8],
+                    validate: bool,
+                    limits: alloy_sol_types::abi::DecodeLimits,
+                ) -> alloy_sol_types::Result<Self> {
+                    <Self::Parameters<'_> as alloy_sol_types::SolType>::abi_decode_sequence_with_limits(data, validate, limits)
+                        .map(Self::new)
+                        .map_err(|e| e.name_member(&#param_names))
+                }
+
                 #[inline]
                 fn abi_decode_returns(data: &[u8], validate: bool) -> alloy_sol_types::Result<Self::Return> {
-                    <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(data, validate).map(Into::into)
+                    <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                        .map(Into::into)
+                        .map_err(|e| e.name_member(&#return_names))
                 }
             }
 
+            #ref_impl
+
             #abi
         };
     };
@@ -5,7 +5,7 @@ use crate::{
     expand::ty::expand_rust_type,
     utils::{self, ExprArray},
 };
-use alloy_sol_macro_input::{ContainsSolAttrs, SolAttrs};
+use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs, SolAttrs};
 use ast::{
     EventParameter, File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath,
     Spanned, Type, VariableDeclaration, Visit,
@@ -396,6 +396,14 @@ impl<'ast> ExpCtxt<'ast> {
         Ident::new(&new_ident, function_name.span())
     }
 
+    /// Formats the given Rust struct name as its borrowed `*Ref` struct name.
+    fn raw_ref_name(&self, name: &Ident) -> Ident {
+        // Note: we want to strip the `r#` prefix when present since we are creating a new ident
+        // that will never be a keyword.
+        let new_ident = format!("{}Ref", name.unraw());
+        Ident::new(&new_ident, name.span())
+    }
+
     fn function_signature(&self, function: &ItemFunction) -> String {
         self.signature(function.name().as_string(), &function.parameters)
     }
@@ -493,6 +501,25 @@ impl<'ast> ExpCtxt<'ast> {
         attrs.push(parse_quote! { #[derive(#(#derives),*)] });
     }
 
+    /// Returns the `derive` attribute of a borrowed `*Ref` struct with the given
+    /// field types.
+    ///
+    /// Only `Clone`, and `Debug` with `all_derives`, are derived, as the lazy
+    /// arrays of borrowed structs do not implement the other traits.
+    fn ref_derives<T, I>(&self, types: I) -> Attribute
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Type>,
+    {
+        let debug = self.attrs.all_derives == Some(true)
+            && types.into_iter().all(|ty| ty::can_derive_builtin_traits(self, ty.borrow()));
+        if debug {
+            parse_quote!(#[derive(Clone, Debug)])
+        } else {
+            parse_quote!(#[derive(Clone)])
+        }
+    }
+
     /// Returns an error if any of the types in the parameters are unresolved.
     ///
     /// Provides a better error message than an `unwrap` or `expect` when we
@@ -567,6 +594,69 @@ fn expand_fields<'a, P>(
     })
 }
 
+/// Expands the names of the given parameters into an array of string literals,
+/// used to name the members of decoding error paths. Unnamed parameters are
+/// expanded to empty strings.
+fn expand_param_names<P>(params: &Parameters<P>) -> TokenStream {
+    let names = params.names().map(|name| name.map(SolIdent::as_string).unwrap_or_default());
+    quote!([#(#names),*])
+}
+
+/// Expands the borrowed `*Ref` struct of an item, with the given field names
+/// and Solidity types:
+///
+/// ```ignore (pseudo-code)
+/// pub struct #{name}Ref<'de> {
+///     #(pub #field_name: <#field_type as SolTypeRef>::RustRef<'de>,)*
+/// }
+/// ```
+///
+/// Structs without fields hold a `PhantomData` instead, which must be
+/// initialized with [`expand_ref_phantom`].
+fn expand_ref_struct<I>(
+    cx: &ExpCtxt<'_>,
+    name: &Ident,
+    ref_name: &Ident,
+    fields: I,
+    derives: Attribute,
+    docs: bool,
+) -> TokenStream
+where
+    I: IntoIterator<Item = (Ident, TokenStream)>,
+{
+    let alloy_sol_types = &cx.crates.sol_types;
+    let (names, types): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+    let phantom = names.is_empty().then(|| {
+        quote! {
+            _lifetime: #alloy_sol_types::private::PhantomData<&'de ()>,
+        }
+    });
+    let doc = docs.then(|| {
+        mk_doc(format!(
+            "Borrowed version of [`{name}`], which is decoded without copying from the \
+             ABI-encoded input. See `SolTypeRef` for more details."
+        ))
+    });
+    quote! {
+        #doc
+        #derives
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        pub struct #ref_name<'de> {
+            #(
+                #[allow(missing_docs)]
+                pub #names: <#types as #alloy_sol_types::SolTypeRef>::RustRef<'de>,
+            )*
+            #phantom
+        }
+    }
+}
+
+/// Initializes the `PhantomData` of a `*Ref` struct without fields. See
+/// [`expand_ref_struct`].
+fn expand_ref_phantom<P>(fields: &Parameters<P>) -> Option<TokenStream> {
+    fields.is_empty().then(|| quote!(_lifetime: alloy_sol_types::private::PhantomData,))
+}
+
 /// Generates an anonymous name from an integer. Used in [`anon_name`].
 #[inline]
 pub fn generate_name(i: usize) -> Ident {
@@ -1,6 +1,9 @@
 //! [`ItemStruct`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_type, ExpCtxt};
+use super::{
+    expand_fields, expand_from_into_tuples, expand_ref_struct, expand_tokenize, expand_type,
+    ExpCtxt,
+};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{Item, ItemStruct, Spanned, Type};
 use proc_macro2::TokenStream;
@@ -15,6 +18,17 @@ use syn::Result;
 ///     #(pub #field_name: #field_type,)*
 /// }
 ///
+/// #if borrowed
+/// pub struct #{name}Ref<'de> {
+///     #(pub #field_name: <#field_type as SolTypeRef>::RustRef<'de>,)*
+/// }
+///
+/// impl SolTypeRef for #name {
+///     type RustRef<'de> = #{name}Ref<'de>;
+///     ...
+/// }
+/// #endif
+///
 /// impl SolStruct for #name {
 ///     ...
 /// }
@@ -31,9 +45,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
 
     cx.derives(&mut attrs, fields, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let borrowed = sol_attrs.borrowed.or(cx.attrs.borrowed).unwrap_or(false);
 
     let (field_types, field_names): (Vec<_>, Vec<_>) =
         fields.iter().map(|f| (expand_type(&f.ty, &cx.crates), f.name.as_ref().unwrap())).unzip();
+    let field_names_s = field_names.iter().map(|name| name.as_string());
 
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
@@ -55,6 +71,44 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
 
     let alloy_sol_types = &cx.crates.sol_types;
 
+    let (ref_struct, ref_impl) = if borrowed {
+        let ref_name = cx.raw_ref_name(&name.0);
+        let ref_struct = expand_ref_struct(
+            cx,
+            &name.0,
+            &ref_name,
+            field_names.iter().map(|&name| name.0.clone()).zip(field_types.iter().cloned()),
+            cx.ref_derives(fields.types()),
+            docs,
+        );
+        let ref_idxs: Vec<_> = (0..fields.len()).map(syn::Index::from).collect();
+        let ref_impl = quote! {
+            #[automatically_derived]
+            impl alloy_sol_types::SolTypeRef for #name {
+                type RustRef<'de> = #ref_name<'de>;
+
+                #[inline]
+                fn decode_ref<'de>(
+                    dec: &mut alloy_sol_types::abi::Decoder<'de>,
+                ) -> alloy_sol_types::Result<Self::RustRef<'de>> {
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolTypeRef>::decode_ref(dec)
+                        .map(|tuple| #ref_name { #(#field_names: tuple.#ref_idxs),* })
+                }
+
+                #[inline]
+                fn decode_ref_sequence<'de>(
+                    dec: &mut alloy_sol_types::abi::Decoder<'de>,
+                ) -> alloy_sol_types::Result<Self::RustRef<'de>> {
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolTypeRef>::decode_ref_sequence(dec)
+                        .map(|tuple| #ref_name { #(#field_names: tuple.#ref_idxs),* })
+                }
+            }
+        };
+        (Some(ref_struct), Some(ref_impl))
+    } else {
+        (None, None)
+    };
+
     let attrs = attrs.iter();
     let convert = expand_from_into_tuples(&name.0, fields, cx);
     let name_s = name.as_string();
@@ -70,6 +124,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
             #(#fields),*
         }
 
+        #ref_struct
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             use #alloy_sol_types as alloy_sol_types;
@@ -116,19 +172,42 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
                 const ENCODED_SIZE: Option<usize> =
                     <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
+                const PACKED_ENCODED_SIZE: Option<usize> =
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
 
                 #[inline]
                 fn valid_token(token: &Self::Token<'_>) -> bool {
                     <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
                 }
 
+                #[inline]
+                fn type_check(token: &Self::Token<'_>) -> alloy_sol_types::Result<()> {
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::type_check(token)
+                }
+
+                #[inline]
+                fn name_decode_path(path: &mut [alloy_sol_types::PathSegment]) {
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::name_decode_path(path);
+                    if let Some(segment) = path.first_mut() {
+                        segment.name_member(&[#(#field_names_s),*]);
+                    }
+                }
+
                 #[inline]
                 fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                     let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
                     <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
                 }
+
+                #[inline]
+                fn abi_decode_packed_from(data: &mut &[u8]) -> alloy_sol_types::Result<Self::RustType> {
+                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_decode_packed_from(data)
+                        .map(<Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from)
+                }
             }
 
+            #ref_impl
+
             #[automatically_derived]
             impl alloy_sol_types::SolStruct for #name {
                 const NAME: &'static str = #name_s;
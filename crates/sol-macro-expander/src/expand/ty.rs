@@ -54,6 +54,21 @@ pub fn rec_expand_type(ty: &Type, crates: &ExternCrates, tokens: &mut TokenStrea
 
             quote_spanned! {span=> #alloy_sol_types::sol_data::#name<#size> }
         }
+        Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+            let name = match ty {
+                Type::Fixed(..) => "Fixed",
+                Type::Ufixed(..) => "Ufixed",
+                _ => unreachable!(),
+            };
+            let name = Ident::new(name, span);
+
+            let (bits, decimals) = fixed_size(size);
+            assert!(bits <= 256 && bits % 8 == 0 && decimals <= 80);
+            let bits = Literal::u16_unsuffixed(bits);
+            let decimals = Literal::u8_unsuffixed(decimals);
+
+            quote_spanned! {span=> #alloy_sol_types::sol_data::#name<#bits, #decimals> }
+        }
 
         Type::Tuple(ref tuple) => {
             return tuple.paren_token.surround(tokens, |tokens| {
@@ -123,6 +138,18 @@ pub fn rec_expand_rust_type(ty: &Type, crates: &ExternCrates, tokens: &mut Token
                 _ => unreachable!(),
             }
         }
+        Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+            let decimals = Literal::u8_unsuffixed(fixed_size(size).1);
+            match ty {
+                Type::Fixed(..) => {
+                    quote_spanned! {span=> #alloy_sol_types::private::Fixed<256, 4, #decimals> }
+                }
+                Type::Ufixed(..) => {
+                    quote_spanned! {span=> #alloy_sol_types::private::Ufixed<256, 4, #decimals> }
+                }
+                _ => unreachable!(),
+            }
+        }
 
         Type::Tuple(ref tuple) => {
             return tuple.paren_token.surround(tokens, |tokens| {
@@ -159,6 +186,12 @@ pub fn rec_expand_rust_type(ty: &Type, crates: &ExternCrates, tokens: &mut Token
     tokens.extend(tts);
 }
 
+/// Returns the `(M, N)` of a `fixedMxN` or `ufixedMxN` type, defaulting to
+/// `128x18`.
+fn fixed_size(size: Option<(NonZeroU16, u8)>) -> (u16, u8) {
+    size.map_or((128, 18), |(bits, decimals)| (bits.get(), decimals))
+}
+
 /// Calculates the base ABI-encoded size of the given parameters in bytes.
 ///
 /// See [`type_base_data_size`] for more information.
@@ -178,6 +211,8 @@ pub(super) fn type_base_data_size(cx: &ExpCtxt<'_>, ty: &Type) -> usize {
         | Type::Bool(_)
         | Type::Int(..)
         | Type::Uint(..)
+        | Type::Fixed(..)
+        | Type::Ufixed(..)
         | Type::FixedBytes(..)
         | Type::Function(_) => 32,
 
@@ -303,6 +338,8 @@ impl fmt::Display for TypePrinter<'_> {
         match self.ty {
             Type::Int(_, None) => f.write_str("int256"),
             Type::Uint(_, None) => f.write_str("uint256"),
+            Type::Fixed(_, None) => f.write_str("fixed128x18"),
+            Type::Ufixed(_, None) => f.write_str("ufixed128x18"),
 
             Type::Array(array) => {
                 Self::new(self.cx, &array.ty).fmt(f)?;
@@ -97,6 +97,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
 
                 const SOL_NAME: &'static str = Self::NAME;
                 const ENCODED_SIZE: Option<usize> = <#underlying_sol as alloy_sol_types::SolType>::ENCODED_SIZE;
+                const PACKED_ENCODED_SIZE: Option<usize> = <#underlying_sol as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
 
                 #[inline]
                 fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -112,6 +113,30 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
                 fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                     <#underlying_sol as alloy_sol_types::SolType>::detokenize(token)
                 }
+
+                #[inline]
+                fn abi_decode_packed_from(data: &mut &[u8]) -> alloy_sol_types::Result<Self::RustType> {
+                    <#underlying_sol as alloy_sol_types::SolType>::abi_decode_packed_from(data)
+                }
+            }
+
+            #[automatically_derived]
+            impl alloy_sol_types::SolTypeRef for #name {
+                type RustRef<'de> = <#underlying_sol as alloy_sol_types::SolTypeRef>::RustRef<'de>;
+
+                #[inline]
+                fn decode_ref<'de>(
+                    dec: &mut alloy_sol_types::abi::Decoder<'de>,
+                ) -> alloy_sol_types::Result<Self::RustRef<'de>> {
+                    <#underlying_sol as alloy_sol_types::SolTypeRef>::decode_ref(dec)
+                }
+
+                #[inline]
+                fn decode_ref_sequence<'de>(
+                    dec: &mut alloy_sol_types::abi::Decoder<'de>,
+                ) -> alloy_sol_types::Result<Self::RustRef<'de>> {
+                    <#underlying_sol as alloy_sol_types::SolTypeRef>::decode_ref_sequence(dec)
+                }
             }
 
             #[automatically_derived]
@@ -82,6 +82,8 @@ pub struct SolAttrs {
     pub extra_methods: Option<bool>,
     /// `#[sol(docs)]`
     pub docs: Option<bool>,
+    /// `#[sol(borrowed)]`
+    pub borrowed: Option<bool>,
 
     /// `#[sol(alloy_sol_types = alloy_core::sol_types)]`
     pub alloy_sol_types: Option<Path>,
@@ -165,6 +167,7 @@ impl SolAttrs {
                     all_derives => bool()?,
                     extra_methods => bool()?,
                     docs => bool()?,
+                    borrowed => bool()?,
 
                     alloy_sol_types => path()?,
                     alloy_contract => path()?,
@@ -391,6 +394,10 @@ mod tests {
             #[sol(docs = true)] => Ok(sol_attrs! { docs: true }),
             #[sol(docs = false)] => Ok(sol_attrs! { docs: false }),
 
+            #[sol(borrowed)] => Ok(sol_attrs! { borrowed: true }),
+            #[sol(borrowed = true)] => Ok(sol_attrs! { borrowed: true }),
+            #[sol(borrowed = false)] => Ok(sol_attrs! { borrowed: false }),
+
             #[sol(abi)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = true)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = false)] => Ok(sol_attrs! { abi: false }),
@@ -142,6 +142,10 @@ use syn::parse_macro_input;
 ///   compile times due to all the extra generated code. This is the default behavior of [`abigen`]
 /// - `docs [ = <bool = true>]`: adds doc comments to all generated types. This is the default
 ///   behavior of [`abigen`]
+/// - `borrowed [ = <bool = false>]` (structs, functions and events only): generates a borrowed
+///   `<name>Ref<'de>` struct, which is decoded without copying from the ABI-encoded input. See
+///   `SolTypeRef` for more details. The structs used by a borrowed item must also be borrowed, so
+///   this is usually passed as an inner attribute: `#![sol(borrowed)]`
 /// - `bytecode = <hex string literal>` (contract-like only): specifies the creation/init bytecode
 ///   of a contract. This will emit a `static` item with the specified bytes.
 /// - `deployed_bytecode = <hex string literal>` (contract-like only): specifies the deployed
@@ -154,6 +158,10 @@ use syn::parse_macro_input;
 /// Structs and enums generate their corresponding Rust types. Enums are
 /// additionally annotated with `#[repr(u8)]`, and as such can have a maximum of
 /// 256 variants.
+///
+/// With the `borrowed` attribute, structs also generate a `<name>Ref<'de>`
+/// struct, which is decoded without copying from the ABI-encoded input. See
+/// `SolTypeRef` for more details.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/structs.rs"))]
 /// ```
@@ -177,7 +185,10 @@ use syn::parse_macro_input;
 /// ### Functions and errors
 ///
 /// Functions generate two structs that implement `SolCall`: `<name>Call` for
-/// the function arguments, and `<name>Return` for the return values.
+/// the function arguments, and `<name>Return` for the return values. With the
+/// `borrowed` attribute, the arguments can also be decoded without copying into
+/// a `<name>CallRef<'de>` struct, with its `abi_decode` and `abi_decode_raw`
+/// methods.
 ///
 /// In the case of overloaded functions, an underscore and the index of the
 /// function will be appended to `<name>` (like `foo_0`, `foo_1`...) for
@@ -192,7 +203,10 @@ use syn::parse_macro_input;
 /// 
 /// ### Events
 ///
-/// Events generate a struct that implements `SolEvent`.
+/// Events generate a struct that implements `SolEvent`. With the `borrowed`
+/// attribute, they also generate a `<name>Ref<'de>` struct that can be decoded
+/// from a log without copying the log data, with its `decode_raw_log` and
+/// `decode_log_data` methods.
 ///
 /// Note that events have special encoding rules in Solidity. For example,
 /// `string indexed` will be encoded in the topics as its `bytes32` Keccak-256
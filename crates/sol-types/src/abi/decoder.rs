@@ -9,16 +9,240 @@
 //
 
 use crate::{
-    abi::{encode_sequence, token::TokenSeq, Token},
-    utils, Error, Result, Word,
+    abi::{encode, encode_sequence, token::TokenSeq, Token},
+    utils, Error, PathSegment, Result, Word,
+};
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    slice::SliceIndex,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
 };
-use alloc::{borrow::Cow, vec::Vec};
-use core::{fmt, slice::SliceIndex};
 
-/// The decoder recursion limit.
-/// This is currently hardcoded, but may be parameterizable in the future.
+/// The default decoder recursion limit.
+///
+/// See [`DecodeLimits::with_max_depth`] to configure it.
 pub const RECURSION_LIMIT: u8 = 16;
 
+/// Limits on the resources that a [`Decoder`] may use while decoding.
+///
+/// ABI-encoded data can describe values that are much larger than the data
+/// itself, for example by making multiple offsets point to the same nested
+/// dynamic array. When decoding untrusted input, these limits put a hard bound
+/// on the work and memory that a single decoding operation may use. Exceeding
+/// any of them results in an error.
+///
+/// By default, only the nesting depth is limited, to [`RECURSION_LIMIT`].
+///
+/// Limits are enforced with a [`DecodeState`], which is shared by a decoder and
+/// all of its children. The `*_with_limits` methods of
+/// [`SolType`](crate::SolType) and [`SolCall`](crate::SolCall) create one for
+/// each decoding operation.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::U256;
+/// use alloy_sol_types::{abi::DecodeLimits, sol_data, Error, SolType};
+///
+/// type T = sol_data::Array<sol_data::Uint<256>>;
+/// let data = T::abi_encode(&vec![U256::ZERO; 4]);
+///
+/// let limits = DecodeLimits::new().with_max_array_len(2).with_max_offset_reuse(0);
+/// let res = T::abi_decode_with_limits(&data, true, limits);
+/// assert_eq!(res, Err(Error::ArrayLengthLimitExceeded(2)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeLimits {
+    max_depth: u8,
+    max_allocation: usize,
+    max_array_len: usize,
+    max_offset_reuse: usize,
+}
+
+impl Default for DecodeLimits {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecodeLimits {
+    /// Creates the default limits, which only limit the nesting depth to
+    /// [`RECURSION_LIMIT`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            max_depth: RECURSION_LIMIT,
+            max_allocation: usize::MAX,
+            max_array_len: usize::MAX,
+            max_offset_reuse: usize::MAX,
+        }
+    }
+
+    /// Sets the maximum nesting depth of dynamic values and sequences.
+    #[inline]
+    pub const fn with_max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum total number of bytes that may be allocated for the
+    /// elements of dynamic arrays, and for the contents of `bytes` and
+    /// `string` values.
+    ///
+    /// Array elements are accounted for with the in-memory size of their
+    /// tokens, so this is an approximation of the memory used by the decoded
+    /// value rather than an exact count.
+    #[inline]
+    pub const fn with_max_allocation(mut self, max_allocation: usize) -> Self {
+        self.max_allocation = max_allocation;
+        self
+    }
+
+    /// Sets the maximum number of elements of a single dynamic array.
+    #[inline]
+    pub const fn with_max_array_len(mut self, max_array_len: usize) -> Self {
+        self.max_array_len = max_array_len;
+        self
+    }
+
+    /// Sets the maximum number of times that the same location in the buffer
+    /// may be pointed to by an offset, in addition to the first time.
+    ///
+    /// Standard encoders never reuse offsets, so `0` is a good value for
+    /// untrusted input.
+    #[inline]
+    pub const fn with_max_offset_reuse(mut self, max_offset_reuse: usize) -> Self {
+        self.max_offset_reuse = max_offset_reuse;
+        self
+    }
+
+    /// Returns the maximum nesting depth.
+    #[inline]
+    pub const fn max_depth(&self) -> u8 {
+        self.max_depth
+    }
+
+    /// Returns the maximum total allocation in bytes.
+    #[inline]
+    pub const fn max_allocation(&self) -> usize {
+        self.max_allocation
+    }
+
+    /// Returns the maximum number of elements of a single dynamic array.
+    #[inline]
+    pub const fn max_array_len(&self) -> usize {
+        self.max_array_len
+    }
+
+    /// Returns the maximum number of times an offset target may be reused.
+    #[inline]
+    pub const fn max_offset_reuse(&self) -> usize {
+        self.max_offset_reuse
+    }
+
+    /// Returns `true` if any limit other than the depth is set, which requires
+    /// tracking state across child decoders.
+    #[inline]
+    const fn is_tracked(&self) -> bool {
+        self.max_allocation != usize::MAX
+            || self.max_array_len != usize::MAX
+            || self.max_offset_reuse != usize::MAX
+    }
+}
+
+/// The state shared by a decoder and all of its children, used to enforce
+/// [`DecodeLimits`] across a decoding operation.
+///
+/// Decoders borrow the state, so a state must outlive the tokens that are
+/// decoded with it. A state should not be reused for more than one operation,
+/// as it keeps track of the resources used so far.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::U256;
+/// use alloy_sol_types::{
+///     abi::{self, token::*, DecodeLimits, DecodeState},
+///     sol_data, Error, SolType,
+/// };
+///
+/// type T = sol_data::Array<sol_data::Uint<256>>;
+/// let data = T::abi_encode(&vec![U256::ZERO; 4]);
+///
+/// let state = DecodeState::new(DecodeLimits::new().with_max_array_len(2));
+/// let res = abi::decode_with_state::<DynSeqToken<WordToken>>(&data, true, &state);
+/// assert_eq!(res, Err(Error::ArrayLengthLimitExceeded(2)));
+/// ```
+#[derive(Debug)]
+pub struct DecodeState {
+    limits: DecodeLimits,
+    /// The total number of bytes accounted for so far.
+    allocated: AtomicUsize,
+    /// The number of times each buffer address has been pointed to.
+    offsets: SpinLock<BTreeMap<usize, usize>>,
+}
+
+impl DecodeState {
+    /// Creates a new state that enforces the given limits.
+    #[inline]
+    pub fn new(limits: DecodeLimits) -> Self {
+        Self { limits, allocated: AtomicUsize::new(0), offsets: SpinLock::new(BTreeMap::new()) }
+    }
+
+    /// Returns the limits enforced by this state.
+    #[inline]
+    pub const fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+}
+
+/// A minimal lock, so that [`DecodeState`] is `Sync` without `std`.
+///
+/// Decoders are not shared between threads while decoding, so the lock is
+/// never contended in practice.
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: access to `value` is serialized by `locked`.
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for SpinLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with(|value| value.fmt(f))
+    }
+}
+
+impl<T> SpinLock<T> {
+    const fn new(value: T) -> Self {
+        Self { locked: AtomicBool::new(false), value: UnsafeCell::new(value) }
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        struct Guard<'a>(&'a AtomicBool);
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                self.0.store(false, Ordering::Release);
+            }
+        }
+
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        let _guard = Guard(&self.locked);
+        // SAFETY: the lock is held until `_guard` is dropped.
+        f(unsafe { &mut *self.value.get() })
+    }
+}
+
 /// The [`Decoder`] wraps a byte slice with necessary info to progressively
 /// deserialize the bytes into a sequence of tokens.
 ///
@@ -32,10 +256,16 @@ pub struct Decoder<'de> {
     buf: &'de [u8],
     // The current offset in the buffer.
     offset: usize,
+    // The offset of the buffer in the input of the root decoder.
+    base: usize,
     // Whether to validate type correctness and blob re-encoding.
     validate: bool,
     /// The current recursion depth.
     depth: u8,
+    /// The maximum recursion depth.
+    max_depth: u8,
+    /// The state for enforcing the other limits, if any are set.
+    state: Option<&'de DecodeState>,
 }
 
 impl fmt::Debug for Decoder<'_> {
@@ -48,6 +278,7 @@ impl fmt::Debug for Decoder<'_> {
             .field("offset", &self.offset)
             .field("validate", &self.validate)
             .field("depth", &self.depth)
+            .field("limits", &self.limits())
             .finish()
     }
 }
@@ -77,7 +308,42 @@ impl<'de> Decoder<'de> {
     /// to an identical bytestring.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
-        Self { buf, offset: 0, validate, depth: 0 }
+        Self {
+            buf,
+            offset: 0,
+            base: 0,
+            validate,
+            depth: 0,
+            max_depth: RECURSION_LIMIT,
+            state: None,
+        }
+    }
+
+    /// Instantiate a new decoder from a byte slice, a validation flag, and
+    /// the state that enforces its resource limits.
+    ///
+    /// See [`new`](Self::new) and [`DecodeState`] for more information.
+    #[inline]
+    pub fn with_state(buf: &'de [u8], validate: bool, state: &'de DecodeState) -> Self {
+        let limits = state.limits;
+        Self {
+            buf,
+            offset: 0,
+            base: 0,
+            validate,
+            depth: 0,
+            max_depth: limits.max_depth,
+            state: limits.is_tracked().then_some(state),
+        }
+    }
+
+    /// Returns the resource limits of this decoder.
+    #[inline]
+    pub const fn limits(&self) -> DecodeLimits {
+        match self.state {
+            Some(state) => state.limits,
+            None => DecodeLimits::new().with_max_depth(self.max_depth),
+        }
     }
 
     /// Returns the current offset in the buffer.
@@ -86,6 +352,13 @@ impl<'de> Decoder<'de> {
         self.offset
     }
 
+    /// Returns the current offset in the input of the root decoder, which is
+    /// different from [`offset`](Self::offset) for child decoders.
+    #[inline]
+    pub const fn absolute_offset(&self) -> usize {
+        self.base + self.offset
+    }
+
     /// Returns the number of bytes in the remaining buffer.
     #[inline]
     pub const fn remaining(&self) -> Option<usize> {
@@ -143,17 +416,77 @@ impl<'de> Decoder<'de> {
     /// The child decoder shares the buffer and validation flag.
     #[inline]
     pub fn child(&self, offset: usize) -> Result<Decoder<'de>, Error> {
-        if self.depth >= RECURSION_LIMIT {
-            return Err(Error::RecursionLimitExceeded(RECURSION_LIMIT));
+        if self.depth >= self.max_depth {
+            return Err(Error::RecursionLimitExceeded(self.max_depth));
         }
         match self.buf.get(offset..) {
-            Some(buf) => {
-                Ok(Decoder { buf, offset: 0, validate: self.validate, depth: self.depth + 1 })
-            }
+            Some(buf) => Ok(Decoder {
+                buf,
+                offset: 0,
+                base: self.base + offset,
+                validate: self.validate,
+                depth: self.depth + 1,
+                max_depth: self.max_depth,
+                state: self.state,
+            }),
             None => Err(Error::Overrun),
         }
     }
 
+    /// Checks the length of a dynamic sequence against the decoder's
+    /// [limits](DecodeLimits), and accounts for the allocation of `len`
+    /// elements of `size` bytes each.
+    ///
+    /// This must be called before allocating the sequence.
+    #[inline]
+    pub fn check_seq_len(&self, len: usize, size: usize) -> Result<()> {
+        let Some(state) = self.state else { return Ok(()) };
+        let max = state.limits.max_array_len;
+        if len > max {
+            return Err(Error::ArrayLengthLimitExceeded(max));
+        }
+        self.check_allocation(len.saturating_mul(size))
+    }
+
+    /// Accounts for the allocation of `size` bytes, checking it against the
+    /// decoder's [limits](DecodeLimits).
+    ///
+    /// This must be called before allocating.
+    #[inline]
+    pub fn check_allocation(&self, size: usize) -> Result<()> {
+        let Some(state) = self.state else { return Ok(()) };
+        let max = state.limits.max_allocation;
+        state
+            .allocated
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |allocated| {
+                allocated.checked_add(size).filter(|&allocated| allocated <= max)
+            })
+            .map(drop)
+            .map_err(|_| Error::AllocationLimitExceeded(max))
+    }
+
+    /// Records that `offset` has been followed as a pointer, checking the
+    /// number of times its target has been reused against the decoder's
+    /// [limits](DecodeLimits).
+    fn check_offset_reuse(&self, offset: usize) -> Result<()> {
+        let Some(state) = self.state else { return Ok(()) };
+        let max = state.limits.max_offset_reuse;
+        if max == usize::MAX {
+            return Ok(());
+        }
+        // Child decoders share the same underlying buffer, so the address
+        // uniquely identifies the target.
+        let target = (self.buf.as_ptr() as usize).wrapping_add(offset);
+        state.offsets.with(|offsets| {
+            let uses = offsets.entry(target).or_insert(0);
+            if *uses > max {
+                return Err(Error::OffsetReuseLimitExceeded(max));
+            }
+            *uses += 1;
+            Ok(())
+        })
+    }
+
     /// Advance the offset by `len` bytes.
     #[inline]
     fn increase_offset(&mut self, len: usize) {
@@ -217,7 +550,9 @@ impl<'de> Decoder<'de> {
     /// pointer, and following it.
     #[inline]
     pub fn take_indirection(&mut self) -> Result<Decoder<'de>, Error> {
-        self.take_offset().and_then(|offset| self.child(offset))
+        let offset = self.take_offset()?;
+        self.check_offset_reuse(offset)?;
+        self.child(offset)
     }
 
     /// Takes a `usize` offset from the buffer by consuming a word.
@@ -269,6 +604,16 @@ impl<'de> Decoder<'de> {
         T::decode_from(self)
     }
 
+    /// Decodes a single token from the underlying buffer, locating any error
+    /// at the given path segment and the current offset.
+    ///
+    /// See [`Error::Located`].
+    #[inline]
+    pub fn decode_at<T: Token<'de>>(&mut self, segment: PathSegment) -> Result<T> {
+        let offset = self.absolute_offset();
+        T::decode_from(self).map_err(|e| e.at(segment, Some(offset)))
+    }
+
     /// Decodes a sequence of tokens from the underlying buffer.
     #[inline]
     pub fn decode_sequence<T: Token<'de> + TokenSeq<'de>>(&mut self) -> Result<T> {
@@ -285,7 +630,7 @@ impl<'de> Decoder<'de> {
 /// See the [`abi`](super) module for more information.
 #[inline(always)]
 pub fn decode<'de, T: Token<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
-    decode_sequence::<(T,)>(data, validate).map(|(t,)| t)
+    decode_from(Decoder::new(data, validate), data)
 }
 
 /// ABI-decodes top-level function args.
@@ -317,9 +662,68 @@ pub fn decode_params<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) ->
 /// See the [`abi`](super) module for more information.
 #[inline]
 pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
-    let mut decoder = Decoder::new(data, validate);
+    decode_sequence_from(Decoder::new(data, validate), data)
+}
+
+/// ABI-decodes a token by wrapping it in a single-element tuple, enforcing the
+/// resource limits of the given state.
+///
+/// See [`decode`] and [`DecodeState`] for more information.
+#[inline]
+pub fn decode_with_state<'de, T: Token<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    state: &'de DecodeState,
+) -> Result<T> {
+    decode_from(Decoder::with_state(data, validate, state), data)
+}
+
+/// ABI-decodes top-level function args, enforcing the resource limits of the
+/// given state.
+///
+/// See [`decode_params`] and [`DecodeState`] for more information.
+#[inline]
+pub fn decode_params_with_state<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    state: &'de DecodeState,
+) -> Result<T> {
+    if T::IS_TUPLE {
+        decode_sequence_with_state(data, validate, state)
+    } else {
+        decode_with_state(data, validate, state)
+    }
+}
+
+/// Decodes ABI compliant vector of bytes into vector of tokens described by
+/// types param, enforcing the resource limits of the given state.
+///
+/// See [`decode_sequence`] and [`DecodeState`] for more information.
+#[inline]
+pub fn decode_sequence_with_state<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    state: &'de DecodeState,
+) -> Result<T> {
+    decode_sequence_from(Decoder::with_state(data, validate, state), data)
+}
+
+#[inline]
+fn decode_from<'de, T: Token<'de>>(mut decoder: Decoder<'de>, data: &[u8]) -> Result<T> {
+    let result = decoder.decode::<T>()?;
+    if decoder.validate() && encode(&result) != data {
+        return Err(Error::ReserMismatch);
+    }
+    Ok(result)
+}
+
+#[inline]
+fn decode_sequence_from<'de, T: TokenSeq<'de>>(
+    mut decoder: Decoder<'de>,
+    data: &[u8],
+) -> Result<T> {
     let result = decoder.decode_sequence::<T>()?;
-    if validate && encode_sequence(&result) != data {
+    if decoder.validate() && encode_sequence(&result) != data {
         return Err(Error::ReserMismatch);
     }
     Ok(result)
@@ -327,9 +731,81 @@ pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -
 
 #[cfg(test)]
 mod tests {
-    use crate::{sol, sol_data, utils::pad_usize, SolType, SolValue};
-    use alloc::string::ToString;
+    use crate::{
+        abi::{self, token::*, DecodeLimits, DecodeState, Decoder},
+        sol, sol_data,
+        utils::pad_usize,
+        Error, SolType, SolValue,
+    };
+    use alloc::{string::ToString, vec};
     use alloy_primitives::{address, bytes, hex, Address, B256, U256};
+    use core::mem;
+
+    #[test]
+    fn decode_limits() {
+        type MyTy = sol_data::Array<sol_data::Array<sol_data::Uint<256>>>;
+        // all three elements point to the same inner array
+        let encoded = hex!(
+            "
+    		0000000000000000000000000000000000000000000000000000000000000020
+    		0000000000000000000000000000000000000000000000000000000000000003
+    		0000000000000000000000000000000000000000000000000000000000000060
+    		0000000000000000000000000000000000000000000000000000000000000060
+    		0000000000000000000000000000000000000000000000000000000000000060
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		0000000000000000000000000000000000000000000000000000000000000007
+    	"
+        );
+        let decode = |limits| {
+            MyTy::abi_decode_with_limits(&encoded, false, limits)
+                .map_err(|e| e.root_cause().clone())
+        };
+        let expected = vec![vec![U256::from(7)]; 3];
+
+        assert_eq!(MyTy::abi_decode(&encoded, false).unwrap(), expected);
+        assert_eq!(decode(DecodeLimits::new()).unwrap(), expected);
+
+        let limits = DecodeLimits::new().with_max_offset_reuse(2);
+        assert_eq!(decode(limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_offset_reuse(1);
+        assert_eq!(decode(limits), Err(Error::OffsetReuseLimitExceeded(1)));
+
+        let limits = DecodeLimits::new().with_max_array_len(3);
+        assert_eq!(decode(limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_array_len(2);
+        assert_eq!(decode(limits), Err(Error::ArrayLengthLimitExceeded(2)));
+
+        let limits = DecodeLimits::new().with_max_depth(4);
+        assert_eq!(decode(limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_depth(3);
+        assert_eq!(decode(limits), Err(Error::RecursionLimitExceeded(3)));
+
+        // 3 outer tokens, 3 inner tokens
+        let size = 3 * mem::size_of::<DynSeqToken<WordToken>>() + 3 * mem::size_of::<WordToken>();
+        let limits = DecodeLimits::new().with_max_allocation(size);
+        assert_eq!(decode(limits).unwrap(), expected);
+        let limits = DecodeLimits::new().with_max_allocation(size - 1);
+        assert_eq!(decode(limits), Err(Error::AllocationLimitExceeded(size - 1)));
+
+        // bytes are accounted for by length
+        let encoded = sol_data::Bytes::abi_encode(&[0u8; 33]);
+        let state = DecodeState::new(DecodeLimits::new().with_max_allocation(32));
+        assert_eq!(
+            abi::decode_with_state::<PackedSeqToken<'_>>(&encoded, true, &state),
+            Err(Error::AllocationLimitExceeded(32))
+        );
+        let state = DecodeState::new(DecodeLimits::new().with_max_allocation(33));
+        let token = abi::decode_with_state::<PackedSeqToken<'_>>(&encoded, true, &state).unwrap();
+        assert_eq!(token.0, [0; 33]);
+        // the state keeps track of the resources used so far
+        assert_eq!(
+            abi::decode_with_state::<PackedSeqToken<'_>>(&encoded, true, &state),
+            Err(Error::AllocationLimitExceeded(33))
+        );
+
+        fn assert_copy_send_sync<T: Copy + Send + Sync>() {}
+        assert_copy_send_sync::<Decoder<'_>>();
+    }
 
     #[test]
     fn dynamic_array_of_dynamic_arrays() {
@@ -12,6 +12,7 @@ use crate::{
     utils, Word,
 };
 use alloc::vec::Vec;
+use alloy_primitives::bytes::BufMut;
 use core::{mem, ptr};
 
 /// An ABI encoder.
@@ -19,9 +20,13 @@ use core::{mem, ptr};
 /// This is not intended for public consumption. It should be used only by the
 /// token types. If you have found yourself here, you probably want to use the
 /// high-level [`crate::SolType`] interface (or its dynamic equivalent) instead.
+///
+/// By default, the encoder collects the encoded words into a `Vec<Word>`.
+/// Use [`Encoder::from_buf`] to write them directly into any other
+/// [`EncodeBuf`], such as a [`BufMut`].
 #[derive(Default, Clone, Debug)]
-pub struct Encoder {
-    buf: Vec<Word>,
+pub struct Encoder<B = Vec<Word>> {
+    buf: B,
     suffix_offset: Vec<usize>,
 }
 
@@ -64,6 +69,22 @@ impl Encoder {
             mem::transmute::<Vec<Word>, Vec<[u8; 32]>>(self.buf)
         })
     }
+}
+
+impl<B: EncodeBuf> Encoder<B> {
+    /// Instantiate a new encoder that writes into the given buffer.
+    #[inline]
+    pub fn from_buf(buf: B) -> Self {
+        Self { buf, suffix_offset: Vec::with_capacity(4) }
+    }
+
+    /// Finish the encoding process, returning the underlying buffer.
+    // https://github.com/rust-lang/rust-clippy/issues/4979
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_buf(self) -> B {
+        self.buf
+    }
 
     /// Determine the current suffix offset.
     ///
@@ -100,7 +121,7 @@ impl Encoder {
     /// Append a word to the encoder.
     #[inline]
     pub fn append_word(&mut self, word: Word) {
-        self.buf.push(word);
+        self.buf.put_word(word);
     }
 
     /// Append a pointer to the current suffix offset.
@@ -124,7 +145,7 @@ impl Encoder {
     #[inline]
     pub fn append_packed_seq(&mut self, bytes: &[u8]) {
         self.append_seq_len(bytes.len());
-        self.append_bytes(bytes);
+        self.buf.put_padded(bytes);
     }
 
     /// Shortcut for appending a token sequence.
@@ -132,25 +153,44 @@ impl Encoder {
     pub fn append_head_tail<'a, T: TokenSeq<'a>>(&mut self, token: &T) {
         token.encode_sequence(self);
     }
+}
+
+/// A buffer that an [`Encoder`] can write ABI-encoded words into.
+///
+/// This is implemented for `Vec<Word>`, which is the default buffer of the
+/// encoder, and for mutable references to any [`BufMut`], including
+/// `&mut [u8]` and `Vec<u8>`.
+pub trait EncodeBuf {
+    /// Write a single word.
+    fn put_word(&mut self, word: Word);
+
+    /// Write a sequence of bytes, padding with zeroes to the next word.
+    fn put_padded(&mut self, bytes: &[u8]);
+}
+
+impl EncodeBuf for Vec<Word> {
+    #[inline]
+    fn put_word(&mut self, word: Word) {
+        self.push(word);
+    }
 
-    /// Append a sequence of bytes, padding to the next word.
     #[inline(always)]
-    fn append_bytes(&mut self, bytes: &[u8]) {
+    fn put_padded(&mut self, bytes: &[u8]) {
         if bytes.is_empty() {
             return;
         }
 
         let n_words = utils::words_for(bytes);
-        self.buf.reserve(n_words);
+        self.reserve(n_words);
         unsafe {
             // set length before copying
             // this is fine because we reserved above and we don't panic below
-            let len = self.buf.len();
-            self.buf.set_len(len + n_words);
+            let len = self.len();
+            self.set_len(len + n_words);
 
             // copy
             let cnt = bytes.len();
-            let dst = self.buf.as_mut_ptr().add(len).cast::<u8>();
+            let dst = self.as_mut_ptr().add(len).cast::<u8>();
             ptr::copy_nonoverlapping(bytes.as_ptr(), dst, cnt);
 
             // set remaining bytes to zero if necessary
@@ -163,6 +203,22 @@ impl Encoder {
     }
 }
 
+impl<B: BufMut + ?Sized> EncodeBuf for &mut B {
+    #[inline]
+    fn put_word(&mut self, word: Word) {
+        self.put_slice(word.as_slice());
+    }
+
+    #[inline]
+    fn put_padded(&mut self, bytes: &[u8]) {
+        self.put_slice(bytes);
+        let rem = bytes.len() % 32;
+        if rem != 0 {
+            self.put_bytes(0, 32 - rem);
+        }
+    }
+}
+
 /// ABI-encodes a single token.
 ///
 /// You are probably looking for
@@ -206,6 +262,50 @@ pub fn encode_sequence<'a, T: TokenSeq<'a>>(token: &T) -> Vec<u8> {
     enc.into_bytes()
 }
 
+/// ABI-encodes a single token into the given buffer.
+///
+/// See [`encode`] for more information.
+///
+/// # Panics
+///
+/// Panics if the buffer does not have enough remaining capacity, like
+/// [`BufMut::put_slice`].
+#[inline(always)]
+pub fn encode_to<'a, T: Token<'a>, B: BufMut + ?Sized>(token: &T, out: &mut B) {
+    encode_sequence_to::<(T,), B>(tuple_from_ref(token), out)
+}
+
+/// ABI-encodes a tuple as ABI function params into the given buffer.
+///
+/// See [`encode_params`] for more information.
+///
+/// # Panics
+///
+/// Panics if the buffer does not have enough remaining capacity, like
+/// [`BufMut::put_slice`].
+#[inline(always)]
+pub fn encode_params_to<'a, T: TokenSeq<'a>, B: BufMut + ?Sized>(token: &T, out: &mut B) {
+    if T::IS_TUPLE {
+        encode_sequence_to(token, out)
+    } else {
+        encode_to(token, out)
+    }
+}
+
+/// ABI-encodes a token sequence into the given buffer, without an
+/// intermediate allocation for the encoded words.
+///
+/// See [`encode_sequence`] for more information.
+///
+/// # Panics
+///
+/// Panics if the buffer does not have enough remaining capacity, like
+/// [`BufMut::put_slice`].
+#[inline]
+pub fn encode_sequence_to<'a, T: TokenSeq<'a>, B: BufMut + ?Sized>(token: &T, out: &mut B) {
+    Encoder::from_buf(out).append_head_tail(token);
+}
+
 /// Converts a reference to `T` into a reference to a tuple of length 1 (without
 /// copying).
 ///
@@ -1134,4 +1234,45 @@ mod tests {
         );
         assert_eq!(encoded_params, expected);
     }
+
+    #[test]
+    fn encode_to_buf() {
+        type MyTy = sol! { (bytes, address, bytes[], (uint256, string)) };
+
+        let data = (
+            Vec::from(bytes!("09736b79736b79736b79026f7300")),
+            address!("B7b54cd129e6D8B24e6AE652a473449B273eE3E4"),
+            vec![vec![1u8; 33], Vec::new()],
+            (U256::from(42), "hello".to_string()),
+        );
+        let size = MyTy::abi_encoded_size(&data);
+
+        let encoded = MyTy::abi_encode(&data);
+        let mut vec = Vec::new();
+        MyTy::abi_encode_to(&data, &mut vec);
+        assert_eq!(vec, encoded);
+        let mut slice = vec![0u8; size];
+        MyTy::abi_encode_to(&data, &mut &mut slice[..]);
+        assert_eq!(slice, encoded);
+
+        let encoded = MyTy::abi_encode_params(&data);
+        let mut vec = Vec::new();
+        MyTy::abi_encode_params_to(&data, &mut vec);
+        assert_eq!(vec, encoded);
+
+        let encoded = MyTy::abi_encode_sequence(&data);
+        let mut vec = Vec::new();
+        MyTy::abi_encode_sequence_to(&data, &mut vec);
+        assert_eq!(vec, encoded);
+        let mut bytes = alloy_primitives::bytes::BytesMut::new();
+        MyTy::abi_encode_sequence_to(&data, &mut bytes);
+        assert_eq!(bytes[..], encoded[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_to_short_slice() {
+        let mut slice = [0u8; 63];
+        sol_data::String::abi_encode_to("a", &mut &mut slice[..]);
+    }
 }
@@ -37,10 +37,16 @@
 //! This is the least useful one. Most users will not need it.
 
 mod encoder;
-pub use encoder::{encode, encode_params, encode_sequence, Encoder};
+pub use encoder::{
+    encode, encode_params, encode_params_to, encode_sequence, encode_sequence_to, encode_to,
+    EncodeBuf, Encoder,
+};
 
 mod decoder;
-pub use decoder::{decode, decode_params, decode_sequence, Decoder, RECURSION_LIMIT};
+pub use decoder::{
+    decode, decode_params, decode_params_with_state, decode_sequence, decode_sequence_with_state,
+    decode_with_state, DecodeLimits, DecodeState, Decoder, RECURSION_LIMIT,
+};
 
 pub mod token;
 pub use token::{Token, TokenSeq};
@@ -12,12 +12,12 @@
 //! See [`Token`] for more details.
 
 use crate::{
-    abi::{Decoder, Encoder},
-    Result, Word,
+    abi::{Decoder, EncodeBuf, Encoder},
+    PathSegment, Result, Word,
 };
 use alloc::vec::Vec;
 use alloy_primitives::{utils::vec_try_with_capacity, Bytes, FixedBytes, I256, U256};
-use core::fmt;
+use core::{fmt, mem};
 
 mod sealed {
     pub trait Sealed {}
@@ -70,10 +70,10 @@ pub trait Token<'de>: Sealed + Sized {
     }
 
     /// Append head words to the encoder.
-    fn head_append(&self, enc: &mut Encoder);
+    fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>);
 
     /// Append tail words to the encoder.
-    fn tail_append(&self, enc: &mut Encoder);
+    fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>);
 }
 
 /// A token composed of a sequence of other tokens.
@@ -86,7 +86,7 @@ pub trait TokenSeq<'a>: Token<'a> {
     const IS_TUPLE: bool = false;
 
     /// ABI-encode the token sequence into the encoder.
-    fn encode_sequence(&self, enc: &mut Encoder);
+    fn encode_sequence<B: EncodeBuf>(&self, enc: &mut Encoder<B>);
 
     /// ABI-decode the token sequence from the encoder.
     fn decode_sequence(dec: &mut Decoder<'a>) -> Result<Self>;
@@ -200,12 +200,12 @@ impl<'a> Token<'a> for WordToken {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_word(self.0);
     }
 
     #[inline]
-    fn tail_append(&self, _enc: &mut Encoder) {}
+    fn tail_append<B: EncodeBuf>(&self, _enc: &mut Encoder<B>) {}
 }
 
 impl WordToken {
@@ -283,7 +283,7 @@ impl<'de, T: Token<'de>, const N: usize> Token<'de> for FixedSeqToken<T, N> {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         if Self::DYNAMIC {
             enc.append_indirection();
         } else {
@@ -294,7 +294,7 @@ impl<'de, T: Token<'de>, const N: usize> Token<'de> for FixedSeqToken<T, N> {
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         if Self::DYNAMIC {
             self.encode_sequence(enc);
         }
@@ -302,7 +302,7 @@ impl<'de, T: Token<'de>, const N: usize> Token<'de> for FixedSeqToken<T, N> {
 }
 
 impl<'de, T: Token<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
-    fn encode_sequence(&self, enc: &mut Encoder) {
+    fn encode_sequence<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.push_offset(self.0.iter().map(T::head_words).sum());
 
         for inner in &self.0 {
@@ -318,7 +318,7 @@ impl<'de, T: Token<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
 
     #[inline]
     fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-        crate::impl_core::try_from_fn(|_| T::decode_from(dec)).map(Self)
+        crate::impl_core::try_from_fn(|i| dec.decode_at(PathSegment::Index(i))).map(Self)
     }
 }
 
@@ -369,14 +369,15 @@ impl<'de, T: Token<'de>> Token<'de> for DynSeqToken<T> {
     fn decode_from(dec: &mut Decoder<'de>) -> Result<Self> {
         let mut child = dec.take_indirection()?;
         let len = child.take_offset()?;
+        child.check_seq_len(len, mem::size_of::<T>())?;
         // This appears to be an unclarity in the Solidity spec. The spec
         // specifies that offsets are relative to the first word of
         // `enc(X)`. But known-good test vectors are relative to the
         // word AFTER the array size
         let mut child = child.raw_child()?;
         let mut tokens = vec_try_with_capacity(len)?;
-        for _ in 0..len {
-            tokens.push(T::decode_from(&mut child)?);
+        for i in 0..len {
+            tokens.push(child.decode_at(PathSegment::Index(i))?);
         }
         Ok(Self(tokens))
     }
@@ -394,19 +395,19 @@ impl<'de, T: Token<'de>> Token<'de> for DynSeqToken<T> {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_indirection();
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_seq_len(self.0.len());
         self.encode_sequence(enc);
     }
 }
 
 impl<'de, T: Token<'de>> TokenSeq<'de> for DynSeqToken<T> {
-    fn encode_sequence(&self, enc: &mut Encoder) {
+    fn encode_sequence<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.push_offset(self.0.iter().map(T::head_words).sum());
 
         for inner in &self.0 {
@@ -470,6 +471,7 @@ impl<'de: 'a, 'a> Token<'de> for PackedSeqToken<'a> {
         let mut child = dec.take_indirection()?;
         let len = child.take_offset()?;
         let bytes = child.peek_len(len)?;
+        child.check_allocation(len)?;
         Ok(PackedSeqToken(bytes))
     }
 
@@ -486,12 +488,12 @@ impl<'de: 'a, 'a> Token<'de> for PackedSeqToken<'a> {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_indirection();
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_packed_seq(self.0);
     }
 }
@@ -560,7 +562,7 @@ macro_rules! tuple_impls {
             }
 
             #[inline]
-            fn head_append(&self, enc: &mut Encoder) {
+            fn head_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
                 if Self::DYNAMIC {
                     enc.append_indirection();
                 } else {
@@ -572,7 +574,7 @@ macro_rules! tuple_impls {
             }
 
             #[inline]
-            fn tail_append(&self, enc: &mut Encoder) {
+            fn tail_append<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
                 if Self::DYNAMIC {
                     self.encode_sequence(enc);
                 }
@@ -583,7 +585,7 @@ macro_rules! tuple_impls {
         impl<'de, $($ty: Token<'de>,)+> TokenSeq<'de> for ($($ty,)+) {
             const IS_TUPLE: bool = true;
 
-            fn encode_sequence(&self, enc: &mut Encoder) {
+            fn encode_sequence<B: EncodeBuf>(&self, enc: &mut Encoder<B>) {
                 let ($($ty,)+) = self;
                 enc.push_offset(0 $( + $ty.head_words() )+);
 
@@ -600,13 +602,17 @@ macro_rules! tuple_impls {
             }
 
             #[inline]
+            #[allow(unused_assignments)]
             fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-                Ok(($(
-                    match <$ty as Token>::decode_from(dec) {
+                let mut i = 0;
+                Ok(($({
+                    let t = match dec.decode_at::<$ty>(PathSegment::Member(i)) {
                         Ok(t) => t,
                         Err(e) => return Err(e),
-                    },
-                )+))
+                    };
+                    i += 1;
+                    t
+                },)+))
             }
         }
     };
@@ -631,17 +637,17 @@ impl<'de> Token<'de> for () {
     }
 
     #[inline]
-    fn head_append(&self, _enc: &mut Encoder) {}
+    fn head_append<B: EncodeBuf>(&self, _enc: &mut Encoder<B>) {}
 
     #[inline]
-    fn tail_append(&self, _enc: &mut Encoder) {}
+    fn tail_append<B: EncodeBuf>(&self, _enc: &mut Encoder<B>) {}
 }
 
 impl<'de> TokenSeq<'de> for () {
     const IS_TUPLE: bool = true;
 
     #[inline]
-    fn encode_sequence(&self, _enc: &mut Encoder) {}
+    fn encode_sequence<B: EncodeBuf>(&self, _enc: &mut Encoder<B>) {}
 
     #[inline]
     fn decode_sequence(_dec: &mut Decoder<'de>) -> Result<Self> {
@@ -8,7 +8,7 @@
 // except according to those terms.
 
 use crate::abi;
-use alloc::{borrow::Cow, boxed::Box, collections::TryReserveError, string::String};
+use alloc::{borrow::Cow, boxed::Box, collections::TryReserveError, string::String, vec::Vec};
 use alloy_primitives::LogData;
 use core::fmt;
 
@@ -16,7 +16,24 @@ use core::fmt;
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// ABI Encoding and Decoding errors.
+///
+/// Decoding errors that occur in a field or element of the decoded value are
+/// wrapped in [`Error::Located`], so they should be matched on through
+/// [`root_cause`](Error::root_cause):
+///
+/// ```
+/// use alloy_sol_types::{sol_data::*, Error, SolType};
+///
+/// // the offset of `bytes` points past the end of the data
+/// let mut data = [0; 64];
+/// data[63] = 0x40;
+/// let err = <(Uint<256>, Bytes)>::abi_decode_params(&data, true).unwrap_err();
+/// assert!(matches!(err, Error::Located { .. }));
+/// assert_eq!(err.path().unwrap().to_string(), "1");
+/// assert_eq!(*err.root_cause(), Error::Overrun);
+/// ```
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// A typecheck detected a word that does not match the data type.
     TypeCheckFail {
@@ -41,6 +58,15 @@ pub enum Error {
     /// ABI Decoding recursion limit exceeded.
     RecursionLimitExceeded(u8),
 
+    /// ABI Decoding allocation limit exceeded.
+    AllocationLimitExceeded(usize),
+
+    /// ABI Decoding array length limit exceeded.
+    ArrayLengthLimitExceeded(usize),
+
+    /// ABI Decoding offset reuse limit exceeded.
+    OffsetReuseLimitExceeded(usize),
+
     /// Invalid enum value.
     InvalidEnumValue {
         /// The name of the enum.
@@ -67,9 +93,28 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// Packed ABI data cannot be decoded unambiguously as the given type,
+    /// because a member with a dynamic packed size is not in the last
+    /// position.
+    AmbiguousPackedLayout(Cow<'static, str>),
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
+    /// An error that occurred while decoding a specific field or element of
+    /// a value.
+    Located {
+        /// The path to the field or element, from the decoded value.
+        path: DecodePath,
+        /// The byte offset of the field or element in the input, if known.
+        ///
+        /// Errors that are found when type-checking the decoded value do not
+        /// have an offset.
+        offset: Option<usize>,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+
     /// Other errors.
     Other(Cow<'static, str>),
 }
@@ -80,6 +125,7 @@ impl std::error::Error for Error {
         match self {
             Self::Reserve(e) => Some(e),
             Self::FromHexError(e) => Some(e),
+            Self::Located { source, .. } => source.source(),
             _ => None,
         }
     }
@@ -98,6 +144,15 @@ impl fmt::Display for Error {
             Self::RecursionLimitExceeded(limit) => {
                 write!(f, "recursion limit of {} exceeded during decoding", limit)
             }
+            Self::AllocationLimitExceeded(limit) => {
+                write!(f, "allocation limit of {limit} bytes exceeded during decoding")
+            }
+            Self::ArrayLengthLimitExceeded(limit) => {
+                write!(f, "array length limit of {limit} exceeded during decoding")
+            }
+            Self::OffsetReuseLimitExceeded(limit) => {
+                write!(f, "offset reuse limit of {limit} exceeded during decoding")
+            }
             Self::InvalidEnumValue { name, value, max } => {
                 write!(f, "`{value}` is not a valid {name} enum value (max: `{max}`)")
             }
@@ -107,7 +162,16 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "unknown selector `{selector}` for {name}")
             }
+            Self::AmbiguousPackedLayout(ty) => write!(
+                f,
+                "packed encoding of `{ty}` is ambiguous: \
+                 only the last member may have a dynamic size"
+            ),
             Self::FromHexError(e) => e.fmt(f),
+            Self::Located { path, offset: Some(offset), source } => {
+                write!(f, "{source} (at `{path}`, offset {offset})")
+            }
+            Self::Located { path, offset: None, source } => write!(f, "{source} (at `{path}`)"),
             Self::Other(e) => f.write_str(e),
         }
     }
@@ -147,6 +211,148 @@ impl Error {
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
         Self::UnknownSelector { name, selector: selector.into() }
     }
+
+    /// Locates this error in the field or element `segment`, which starts at
+    /// `offset` bytes into the input, if known.
+    ///
+    /// If this error is already [located](Error::Located), the segment is
+    /// prepended to its path and its offset is kept if it has one, so that it
+    /// points to the innermost field or element.
+    #[cold]
+    pub fn at(self, segment: PathSegment, offset: Option<usize>) -> Self {
+        match self {
+            Self::Located { mut path, offset: inner, source } => {
+                path.segments.insert(0, segment);
+                Self::Located { path, offset: inner.or(offset), source }
+            }
+            source => Self::Located {
+                path: DecodePath { segments: vec![segment] },
+                offset,
+                source: Box::new(source),
+            },
+        }
+    }
+
+    /// Applies `f` to the path of this error, if it is
+    /// [located](Error::Located).
+    ///
+    /// This is used to replace the positional [`PathSegment::Member`]s with
+    /// field names once the decoded type is known.
+    #[cold]
+    pub fn map_path(mut self, f: impl FnOnce(&mut [PathSegment])) -> Self {
+        if let Self::Located { path, .. } = &mut self {
+            f(&mut path.segments);
+        }
+        self
+    }
+
+    /// Names the outermost segment of this error's path after the members of
+    /// the decoded tuple, if it is [located](Error::Located).
+    ///
+    /// See [`PathSegment::name_member`].
+    #[cold]
+    pub fn name_member(self, names: &[&'static str]) -> Self {
+        self.map_path(|path| {
+            if let Some(segment) = path.first_mut() {
+                segment.name_member(names);
+            }
+        })
+    }
+
+    /// Returns the path to the field or element in which this error occurred,
+    /// if it is known.
+    #[inline]
+    pub const fn path(&self) -> Option<&DecodePath> {
+        match self {
+            Self::Located { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the byte offset in the input of the field or element in which
+    /// this error occurred, if it is known.
+    #[inline]
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            Self::Located { offset, .. } => *offset,
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error, without its location.
+    #[inline]
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::Located { source, .. } => source.root_cause(),
+            _ => self,
+        }
+    }
+}
+
+/// The path to a field or element of a decoded value, such as
+/// `orders[3].signature`.
+///
+/// Paths start at the members of the decoded value or parameter list, and do
+/// not include a segment for the value itself.
+///
+/// See [`Error::Located`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DecodePath {
+    segments: Vec<PathSegment>,
+}
+
+impl fmt::Display for DecodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 && !matches!(segment, PathSegment::Index(_)) {
+                f.write_str(".")?;
+            }
+            segment.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl DecodePath {
+    /// Returns the segments of the path, from the outermost to the innermost.
+    #[inline]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+/// A segment of a [`DecodePath`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A member of a tuple, by position.
+    Member(usize),
+    /// A named member of a struct, or a named function parameter.
+    Field(Cow<'static, str>),
+    /// An element of an array.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Member(index) => index.fmt(f),
+            Self::Field(name) => f.write_str(name),
+            Self::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+impl PathSegment {
+    /// Replaces this segment with the name at its position in `names`, if it
+    /// is a [`Member`](Self::Member) and the name is not empty.
+    #[inline]
+    pub fn name_member(&mut self, names: &[&'static str]) {
+        if let Self::Member(index) = *self {
+            if let Some(&name) = names.get(index).filter(|name| !name.is_empty()) {
+                *self = Self::Field(Cow::Borrowed(name));
+            }
+        }
+    }
 }
 
 impl From<hex::FromHexError> for Error {
@@ -174,7 +174,7 @@ mod macros;
 pub mod abi;
 
 mod errors;
-pub use errors::{Error, Result};
+pub use errors::{DecodePath, Error, PathSegment, Result};
 
 #[cfg(feature = "json")]
 mod ext;
@@ -185,9 +185,10 @@ mod impl_core;
 
 mod types;
 pub use types::{
-    data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
-    GenericRevertReason, Panic, PanicKind, Revert, Selectors, SolCall, SolConstructor, SolEnum,
-    SolError, SolEvent, SolEventInterface, SolInterface, SolStruct, SolType, SolValue, TopicList,
+    data_type as sol_data, decode_revert_reason, ArrayRef, ArrayRefIter, ContractError, EventTopic,
+    GenericContractError, GenericRevertReason, Panic, PanicKind, Revert, Selectors, SolCall,
+    SolConstructor, SolEnum, SolError, SolEvent, SolEventInterface, SolInterface, SolStruct,
+    SolType, SolTypeRef, SolValue, TopicList,
 };
 
 pub mod utils;
@@ -218,13 +219,14 @@ pub mod private {
         vec::Vec,
     };
     pub use alloy_primitives::{
-        bytes, keccak256, Address, Bytes, FixedBytes, Function, LogData, Signed, Uint, B256, I256,
-        U256,
+        bytes, keccak256, Address, Bytes, Fixed, FixedBytes, Function, LogData, Signed, Ufixed,
+        Uint, B256, I256, U256,
     };
     pub use core::{
         borrow::{Borrow, BorrowMut},
         convert::From,
         default::Default,
+        marker::PhantomData,
         option::Option,
         result::Result,
     };
@@ -0,0 +1,592 @@
+//! Borrowed (zero-copy) ABI decoding.
+//!
+//! See [`SolTypeRef`] for more details.
+
+use crate::{
+    abi::{self, token::*, DecodeState, Decoder},
+    sol_data::{
+        self, ByteCount, DecimalCount, IntBitCount, SupportedDecimals, SupportedFixedBytes,
+        SupportedInt,
+    },
+    Error, PathSegment, Result, SolType,
+};
+use core::{fmt, iter::FusedIterator, marker::PhantomData, ops::Range};
+
+/// A Solidity type that can be ABI-decoded without copying out of the input
+/// buffer.
+///
+/// [`SolType`]'s decoding functions always produce owned values, which means
+/// that every `bytes`, `string` and array is allocated. The borrowed decoding
+/// functions of this trait instead produce a [`RustRef`](Self::RustRef) which
+/// references the input:
+/// - `bytes` is decoded to `&[u8]`;
+/// - `string` is decoded to `&str`;
+/// - `T[]` is decoded to an [`ArrayRef`], which decodes its elements lazily;
+/// - `T[N]` and tuples are decoded to arrays and tuples of the borrowed
+///   values;
+/// - all other types are decoded to their owned [`RustType`](SolType::RustType).
+///
+/// With the `#[sol(borrowed)]` attribute, the [`sol!`] macro generates `*Ref`
+/// structs for structs, function calls and events, which use these types for
+/// their fields.
+///
+/// # Validation
+///
+/// When `validate` is set, the input is first checked exactly like
+/// [`SolType::abi_decode`], before the borrowed value is decoded:
+/// 1. the input is fully decoded into tokens, which allocates the tokens of
+///    every array;
+/// 2. the tokens are type-checked;
+/// 3. the tokens are re-encoded and the result is compared with the input, to
+///    reject non-canonical encodings. This allocates a copy of the entire
+///    encoding.
+///
+/// Validation is therefore not zero-copy: it costs about as much as an owned
+/// [`SolType::abi_decode`], on top of the borrowed decoding. It should only be
+/// enabled for untrusted input.
+///
+/// Unlike [`SolType::abi_decode`], which decodes strings lossily, a `string`
+/// that is not valid UTF-8 is always an error.
+///
+/// # Limits
+///
+/// The `*_with_state` functions enforce the [resource limits](abi::DecodeLimits)
+/// of a [`DecodeState`]. Since the elements of an [`ArrayRef`] are decoded
+/// lazily, the returned value borrows the state, and the elements are decoded
+/// with the same limits and at the same depth as the array.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Bytes as RustBytes, U256};
+/// use alloy_sol_types::{sol_data::*, SolType, SolTypeRef};
+///
+/// type Ty = (String, Bytes, Array<Uint<256>>);
+///
+/// let value = ("hello".to_string(), RustBytes::from_static(&[0x12, 0x34]), vec![U256::from(1), U256::from(2)]);
+/// let encoded = Ty::abi_encode(&value);
+///
+/// let (s, b, array) = Ty::abi_decode_ref(&encoded, true)?;
+/// assert_eq!(s, "hello");
+/// assert_eq!(b, [0x12, 0x34]);
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.iter().collect::<Result<Vec<_>, _>>()?, [U256::from(1), U256::from(2)]);
+/// # Ok::<(), alloy_sol_types::Error>(())
+/// ```
+///
+/// [`sol!`]: crate::sol
+pub trait SolTypeRef: SolType {
+    /// The borrowed Rust type, which may reference the decoded buffer.
+    type RustRef<'de>;
+
+    /// Decodes a borrowed value from the decoder's current position.
+    ///
+    /// Values are read from the head of the decoder, following indirections
+    /// like [`Token::decode_from`]. This does not type-check the decoded
+    /// values.
+    fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>>;
+
+    /// Decodes the elements of a borrowed sequence from the decoder's current
+    /// position.
+    ///
+    /// See [`TokenSeq::decode_sequence`].
+    #[inline]
+    fn decode_ref_sequence<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        Self::decode_ref(dec)
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as a single-element sequence.
+    ///
+    /// See [`SolType::abi_decode`] for more information.
+    #[inline]
+    fn abi_decode_ref(data: &[u8], validate: bool) -> Result<Self::RustRef<'_>> {
+        check::<Self>(validate, || abi::decode(data, true))
+            .and_then(|()| Self::decode_ref(&mut Decoder::new(data, false)))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as function parameters.
+    ///
+    /// See [`SolType::abi_decode_params`] for more information.
+    #[inline]
+    fn abi_decode_params_ref<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustRef<'de>>
+    where
+        Self::Token<'de>: TokenSeq<'de>,
+    {
+        if <Self::Token<'de> as TokenSeq<'de>>::IS_TUPLE {
+            Self::abi_decode_sequence_ref(data, validate)
+        } else {
+            Self::abi_decode_ref(data, validate)
+        }
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as a sequence.
+    ///
+    /// See [`SolType::abi_decode_sequence`] for more information.
+    #[inline]
+    fn abi_decode_sequence_ref<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustRef<'de>>
+    where
+        Self::Token<'de>: TokenSeq<'de>,
+    {
+        check::<Self>(validate, || abi::decode_sequence(data, true))
+            .and_then(|()| Self::decode_ref_sequence(&mut Decoder::new(data, false)))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as a single-element sequence, enforcing the resource limits of the
+    /// given state.
+    ///
+    /// See [`abi_decode_ref`](Self::abi_decode_ref).
+    #[inline]
+    fn abi_decode_ref_with_state<'de>(
+        data: &'de [u8],
+        validate: bool,
+        state: &'de DecodeState,
+    ) -> Result<Self::RustRef<'de>> {
+        let check_state = DecodeState::new(state.limits());
+        check::<Self>(validate, || abi::decode_with_state(data, true, &check_state))
+            .and_then(|()| Self::decode_ref(&mut Decoder::with_state(data, false, state)))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as function parameters, enforcing the resource limits of the given
+    /// state.
+    ///
+    /// See [`abi_decode_params_ref`](Self::abi_decode_params_ref).
+    #[inline]
+    fn abi_decode_params_ref_with_state<'de>(
+        data: &'de [u8],
+        validate: bool,
+        state: &'de DecodeState,
+    ) -> Result<Self::RustRef<'de>>
+    where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        if <Self::Token<'de> as TokenSeq<'de>>::IS_TUPLE {
+            Self::abi_decode_sequence_ref_with_state(data, validate, state)
+        } else {
+            Self::abi_decode_ref_with_state(data, validate, state)
+        }
+    }
+
+    /// Decodes this type's borrowed value from an ABI blob by interpreting it
+    /// as a sequence, enforcing the resource limits of the given state.
+    ///
+    /// See [`abi_decode_sequence_ref`](Self::abi_decode_sequence_ref).
+    #[inline]
+    fn abi_decode_sequence_ref_with_state<'de>(
+        data: &'de [u8],
+        validate: bool,
+        state: &'de DecodeState,
+    ) -> Result<Self::RustRef<'de>>
+    where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        let check_state = DecodeState::new(state.limits());
+        check::<Self>(validate, || abi::decode_sequence_with_state(data, true, &check_state))
+            .and_then(|()| Self::decode_ref_sequence(&mut Decoder::with_state(data, false, state)))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+}
+
+/// Type-checks the tokens returned by `decode` if `validate` is set.
+#[inline]
+fn check<'de, T: SolType>(
+    validate: bool,
+    decode: impl FnOnce() -> Result<T::Token<'de>>,
+) -> Result<()> {
+    if validate {
+        T::type_check(&decode()?)
+    } else {
+        Ok(())
+    }
+}
+
+/// Decodes a borrowed `T`, locating errors at `segment`.
+#[inline]
+fn decode_ref_at<'de, T: SolTypeRef>(
+    dec: &mut Decoder<'de>,
+    segment: PathSegment,
+) -> Result<T::RustRef<'de>> {
+    let offset = dec.absolute_offset();
+    T::decode_ref(dec).map_err(|e| e.at(segment, Some(offset)))
+}
+
+macro_rules! impl_word_types {
+    ($([$($gen:tt)*] $ty:ty [$($where:tt)*];)+) => {$(
+        impl<$($gen)*> SolTypeRef for $ty $($where)* {
+            type RustRef<'de> = Self::RustType;
+
+            #[inline]
+            fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+                dec.decode::<WordToken>().map(Self::detokenize)
+            }
+        }
+    )+};
+}
+
+impl_word_types! {
+    [] sol_data::Bool [];
+    [const BITS: usize] sol_data::Int<BITS> [where IntBitCount<BITS>: SupportedInt];
+    [const BITS: usize] sol_data::Uint<BITS> [where IntBitCount<BITS>: SupportedInt];
+    [const M: usize, const N: usize] sol_data::Fixed<M, N>
+        [where IntBitCount<M>: SupportedInt, DecimalCount<N>: SupportedDecimals];
+    [const M: usize, const N: usize] sol_data::Ufixed<M, N>
+        [where IntBitCount<M>: SupportedInt, DecimalCount<N>: SupportedDecimals];
+    [const N: usize] sol_data::FixedBytes<N> [where ByteCount<N>: SupportedFixedBytes];
+    [] sol_data::Address [];
+    [] sol_data::Function [];
+}
+
+impl SolTypeRef for sol_data::Bytes {
+    type RustRef<'de> = &'de [u8];
+
+    #[inline]
+    fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        dec.decode::<PackedSeqToken<'de>>().map(|token| token.0)
+    }
+}
+
+impl SolTypeRef for sol_data::String {
+    type RustRef<'de> = &'de str;
+
+    #[inline]
+    fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        let bytes = dec.decode::<PackedSeqToken<'de>>()?.0;
+        core::str::from_utf8(bytes).map_err(|_| Error::type_check_fail(bytes, Self::SOL_NAME))
+    }
+}
+
+impl<T: SolTypeRef> SolTypeRef for sol_data::Array<T> {
+    type RustRef<'de> = ArrayRef<'de, T>;
+
+    #[inline]
+    fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        let mut child = dec.take_indirection()?;
+        let len = child.take_offset()?;
+        // See `DynSeqToken::decode_from`: element offsets are relative to the
+        // word after the length
+        ArrayRef::new(child.raw_child()?, len)
+    }
+}
+
+impl<T: SolTypeRef, const N: usize> SolTypeRef for sol_data::FixedArray<T, N> {
+    type RustRef<'de> = [T::RustRef<'de>; N];
+
+    #[inline]
+    fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        if Self::DYNAMIC {
+            dec.take_indirection().and_then(|mut child| Self::decode_ref_sequence(&mut child))
+        } else {
+            Self::decode_ref_sequence(dec)
+        }
+    }
+
+    #[inline]
+    fn decode_ref_sequence<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        crate::impl_core::try_from_fn(|i| decode_ref_at::<T>(dec, PathSegment::Index(i)))
+    }
+}
+
+macro_rules! tuple_impls {
+    ($count:literal $($ty:ident),+) => {
+        impl<$($ty: SolTypeRef,)+> SolTypeRef for ($($ty,)+) {
+            type RustRef<'de> = ($($ty::RustRef<'de>,)+);
+
+            #[inline]
+            fn decode_ref<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+                // See `Token::decode_from` for tuples
+                if Self::DYNAMIC {
+                    dec.take_indirection().and_then(|mut child| Self::decode_ref_sequence(&mut child))
+                } else {
+                    Self::decode_ref_sequence(dec)
+                }
+            }
+
+            #[inline]
+            #[allow(unused_assignments)]
+            fn decode_ref_sequence<'de>(dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+                let mut i = 0;
+                Ok(($({
+                    let t = decode_ref_at::<$ty>(dec, PathSegment::Member(i))?;
+                    i += 1;
+                    t
+                },)+))
+            }
+        }
+    };
+}
+
+impl SolTypeRef for () {
+    type RustRef<'de> = ();
+
+    #[inline]
+    fn decode_ref<'de>(_dec: &mut Decoder<'de>) -> Result<Self::RustRef<'de>> {
+        Ok(())
+    }
+}
+
+all_the_tuples!(tuple_impls);
+
+/// A dynamic array (`T[]`) that is lazily decoded from an ABI-encoded buffer.
+///
+/// This is the [borrowed type](SolTypeRef::RustRef) of [`sol_data::Array`].
+/// The length of the array and the bounds of its elements' heads are checked
+/// when the array is decoded, but the elements themselves are only decoded
+/// when they are accessed, either by index with [`get`](Self::get) or by
+/// iterating over the array. Decoding an element may fail, so elements are
+/// returned as [`Result`]s.
+///
+/// The paths of the errors that are returned when decoding an element are
+/// relative to the array. See [`Error::path`].
+///
+/// Elements are decoded with the [limits](abi::DecodeLimits) of the decoder
+/// that decoded the array.
+pub struct ArrayRef<'de, T> {
+    /// The decoder of the array's elements, positioned at the first head.
+    dec: Decoder<'de>,
+    len: usize,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ArrayRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArrayRef<'_, T> {}
+
+impl<'de, T: SolTypeRef> fmt::Debug for ArrayRef<'de, T>
+where
+    T::RustRef<'de>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'de, T: SolTypeRef> IntoIterator for ArrayRef<'de, T> {
+    type Item = Result<T::RustRef<'de>>;
+    type IntoIter = ArrayRefIter<'de, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayRefIter { range: 0..self.len, array: self }
+    }
+}
+
+impl<'de, T: SolTypeRef> ArrayRef<'de, T> {
+    /// The size of the head of an element: the element itself if it is
+    /// static, or the offset to it otherwise.
+    const HEAD_SIZE: usize = match T::ENCODED_SIZE {
+        Some(size) => size,
+        None => 32,
+    };
+
+    /// Creates a new array of `len` elements, starting at the start of the
+    /// decoder's buffer.
+    fn new(dec: Decoder<'de>, len: usize) -> Result<Self> {
+        // no elements are allocated, but the length is still limited
+        dec.check_seq_len(len, 0)?;
+        // the heads of all elements must be in bounds, so that a bogus length
+        // is rejected up front
+        let size = len.checked_mul(Self::HEAD_SIZE).ok_or(Error::Overrun)?;
+        dec.peek_len(size)?;
+        Ok(Self { dec, len, _ty: PhantomData })
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the array has no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes the element at `index`, or returns `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Result<T::RustRef<'de>>> {
+        (index < self.len).then(|| self.decode(index))
+    }
+
+    /// Returns an iterator that decodes the elements of the array.
+    #[inline]
+    pub fn iter(&self) -> ArrayRefIter<'de, T> {
+        self.into_iter()
+    }
+
+    fn decode(&self, index: usize) -> Result<T::RustRef<'de>> {
+        let mut dec = self.dec;
+        dec.set_offset(index * Self::HEAD_SIZE);
+        decode_ref_at::<T>(&mut dec, PathSegment::Index(index))
+            .map_err(|e| e.map_path(sol_data::Array::<T>::name_decode_path))
+    }
+}
+
+/// An iterator over the lazily decoded elements of an [`ArrayRef`].
+pub struct ArrayRefIter<'de, T> {
+    array: ArrayRef<'de, T>,
+    range: Range<usize>,
+}
+
+impl<T> Clone for ArrayRefIter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { array: self.array, range: self.range.clone() }
+    }
+}
+
+impl<T> fmt::Debug for ArrayRefIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayRefIter").field("range", &self.range).finish_non_exhaustive()
+    }
+}
+
+impl<'de, T: SolTypeRef> Iterator for ArrayRefIter<'de, T> {
+    type Item = Result<T::RustRef<'de>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.array.decode(i))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|i| self.array.decode(i))
+    }
+}
+
+impl<T: SolTypeRef> DoubleEndedIterator for ArrayRefIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.array.decode(i))
+    }
+}
+
+impl<T: SolTypeRef> ExactSizeIterator for ArrayRefIter<'_, T> {}
+
+impl<T: SolTypeRef> FusedIterator for ArrayRefIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol_data::*;
+    use alloc::{string::ToString, vec, vec::Vec};
+    use alloy_primitives::{hex, Address as RustAddress, Bytes as RustBytes, U256};
+
+    #[test]
+    fn decode_borrowed() {
+        type Ty = (Address, Bytes, String, Array<(Uint<256>, String)>, FixedArray<Bytes, 2>);
+
+        let value = (
+            RustAddress::repeat_byte(0x11),
+            RustBytes::from(vec![1, 2, 3]),
+            "hello".to_string(),
+            vec![(U256::from(1), "a".to_string()), (U256::from(2), "b".to_string())],
+            [RustBytes::from(vec![4]), RustBytes::new()],
+        );
+        let encoded = Ty::abi_encode_params(&value);
+
+        for validate in [false, true] {
+            let (address, bytes, string, array, fixed) =
+                Ty::abi_decode_params_ref(&encoded, validate).unwrap();
+            assert_eq!(address, value.0);
+            assert_eq!(bytes, [1, 2, 3]);
+            assert_eq!(string, "hello");
+            assert_eq!(fixed, [&[4][..], &[]]);
+
+            assert_eq!(array.len(), 2);
+            let elements = array.iter().collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(elements, [(U256::from(1), "a"), (U256::from(2), "b")]);
+            assert_eq!(array.get(1).unwrap().unwrap(), (U256::from(2), "b"));
+            assert!(array.get(2).is_none());
+            assert_eq!(array.iter().next_back().unwrap().unwrap().1, "b");
+        }
+
+        // borrowed values point into the input
+        let encoded = Bytes::abi_encode(&vec![0x12u8; 3]);
+        let bytes = Bytes::abi_decode_ref(&encoded, false).unwrap();
+        assert_eq!(bytes.as_ptr(), encoded[64..].as_ptr());
+    }
+
+    #[test]
+    fn decode_borrowed_errors() {
+        // bogus length
+        let encoded = hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000020
+            0000000000000000000000000000000000000000000000000000000000000003
+            0000000000000000000000000000000000000000000000000000000000000001
+            0000000000000000000000000000000000000000000000000000000000000002
+            "
+        );
+        let err = Array::<Uint<256>>::abi_decode_ref(&encoded, false).unwrap_err();
+        assert_eq!(*err.root_cause(), Error::Overrun);
+
+        // elements are only decoded when accessed
+        let encoded = hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000020
+            0000000000000000000000000000000000000000000000000000000000000001
+            0000000000000000000000000000000000000000000000000000000000000020
+            0000000000000000000000000000000000000000000000000000000000000040
+            "
+        );
+        let array = Array::<Bytes>::abi_decode_ref(&encoded, false).unwrap();
+        assert_eq!(array.len(), 1);
+        let err = array.get(0).unwrap().unwrap_err();
+        assert_eq!(err.path().unwrap().to_string(), "[0]");
+        assert_eq!(err.offset(), Some(64));
+        assert_eq!(*err.root_cause(), Error::Overrun);
+        // validation decodes everything up front
+        assert!(Array::<Bytes>::abi_decode_ref(&encoded, true).is_err());
+
+        // invalid UTF-8
+        let encoded = Bytes::abi_encode(&[0xffu8]);
+        assert!(String::abi_decode(&encoded, false).is_ok());
+        assert_eq!(
+            String::abi_decode_ref(&encoded, false),
+            Err(Error::type_check_fail(&[0xff], "string"))
+        );
+    }
+
+    #[test]
+    fn decode_borrowed_with_limits() {
+        type Ty = Array<Array<Uint<256>>>;
+
+        let value = vec![vec![U256::from(1)], vec![U256::from(2), U256::from(3)]];
+        let encoded = Ty::abi_encode(&value);
+
+        let state = DecodeState::new(abi::DecodeLimits::new().with_max_array_len(1));
+        let err = Ty::abi_decode_ref_with_state(&encoded, false, &state).unwrap_err();
+        assert_eq!(*err.root_cause(), Error::ArrayLengthLimitExceeded(1));
+
+        // lazily decoded elements keep the limits of the array
+        let state = DecodeState::new(abi::DecodeLimits::new().with_max_array_len(2));
+        let array = Ty::abi_decode_ref_with_state(&encoded, true, &state).unwrap();
+        assert_eq!(array.get(1).unwrap().unwrap().len(), 2);
+        let state = DecodeState::new(abi::DecodeLimits::new().with_max_depth(2));
+        let array = Ty::abi_decode_ref_with_state(&encoded, false, &state).unwrap();
+        let err = array.get(0).unwrap().unwrap_err();
+        assert_eq!(err.path().unwrap().to_string(), "[0]");
+        assert_eq!(*err.root_cause(), Error::RecursionLimitExceeded(2));
+        assert!(Ty::abi_decode_ref(&encoded, false).unwrap().get(0).unwrap().is_ok());
+    }
+}
@@ -8,9 +8,12 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{abi::token::*, private::SolTypeValue, utils, SolType, Word};
+use crate::{
+    abi::token::*, private::SolTypeValue, utils, Error, PathSegment, Result, SolType, Word,
+};
 use alloc::{string::String as RustString, vec::Vec};
 use alloy_primitives::{
+    aliases::{Fixed256 as RustFixed, Ufixed256 as RustUfixed},
     keccak256, Address as RustAddress, Bytes as RustBytes, FixedBytes as RustFixedBytes,
     Function as RustFunction, I256, U256,
 };
@@ -45,6 +48,7 @@ impl SolType for Bool {
 
     const SOL_NAME: &'static str = "bool";
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(1);
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -55,6 +59,11 @@ impl SolType for Bool {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0 != Word::ZERO
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        take_packed(data, 1).map(|byte| byte[0] != 0)
+    }
 }
 
 /// Int - `intX`
@@ -90,6 +99,7 @@ where
 
     const SOL_NAME: &'static str = IntBitCount::<BITS>::INT_NAME;
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -108,6 +118,11 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         IntBitCount::<BITS>::detokenize_int(token)
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        packed_word(data, BITS / 8).map(Self::detokenize)
+    }
 }
 
 /// Uint - `uintX`
@@ -143,6 +158,7 @@ where
 
     const SOL_NAME: &'static str = IntBitCount::<BITS>::UINT_NAME;
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -153,6 +169,146 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         IntBitCount::<BITS>::detokenize_uint(token)
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        packed_word(data, BITS / 8).map(Self::detokenize)
+    }
+}
+
+/// Fixed - `fixedMxN`
+///
+/// The Rust representation is always a 256-bit [`Fixed`](alloy_primitives::Fixed)
+/// with `N` decimals. Like [`Int`], values are truncated to `M` bits when
+/// encoding.
+pub struct Fixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> SolTypeValue<Fixed<M, N>> for T
+where
+    T: Borrow<RustFixed<N>>,
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        let raw = self.borrow().into_raw();
+        let mut word = raw.to_be_bytes::<32>();
+        word[..32 - M / 8].fill(raw.is_negative() as u8 * 0xff);
+        WordToken::new(word)
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.borrow().into_raw().to_be_bytes::<32>()[32 - M / 8..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Fixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> SolType for Fixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    type RustType = RustFixed<N>;
+    type Token<'a> = WordToken;
+
+    const SOL_NAME: &'static str = NameBuffer::new()
+        .write_str("fixed")
+        .write_usize(M)
+        .write_byte(b'x')
+        .write_usize(N)
+        .as_str();
+    const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(M / 8);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        Int::<M>::valid_token(token)
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // sign extend bits to ignore
+        let msb = 32 - M / 8;
+        let is_negative = token.0[msb] & 0x80 == 0x80;
+        token.0[..msb].fill(is_negative as u8 * 0xff);
+        RustFixed::from_raw(I256::from_be_bytes(token.0 .0))
+    }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        packed_word(data, M / 8).map(Self::detokenize)
+    }
+}
+
+/// Ufixed - `ufixedMxN`
+///
+/// The Rust representation is always a 256-bit
+/// [`Ufixed`](alloy_primitives::Ufixed) with `N` decimals. Like [`Uint`],
+/// values are truncated to `M` bits when encoding.
+pub struct Ufixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> SolTypeValue<Ufixed<M, N>> for T
+where
+    T: Borrow<RustUfixed<N>>,
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        let mut word = self.borrow().into_raw().to_be_bytes::<32>();
+        word[..32 - M / 8].fill(0);
+        WordToken::new(word)
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.borrow().into_raw().to_be_bytes::<32>()[32 - M / 8..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Ufixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> SolType for Ufixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    type RustType = RustUfixed<N>;
+    type Token<'a> = WordToken;
+
+    const SOL_NAME: &'static str = NameBuffer::new()
+        .write_str("ufixed")
+        .write_usize(M)
+        .write_byte(b'x')
+        .write_usize(N)
+        .as_str();
+    const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(M / 8);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        utils::check_zeroes(&token.0[..32 - M / 8])
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // zero out bits to ignore
+        token.0[..32 - M / 8].fill(0);
+        RustUfixed::from_raw(U256::from_be_bytes(token.0 .0))
+    }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        packed_word(data, M / 8).map(Self::detokenize)
+    }
 }
 
 /// FixedBytes - `bytesX`
@@ -190,6 +346,7 @@ where
 
     const SOL_NAME: &'static str = <ByteCount<N>>::NAME;
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(N);
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -200,6 +357,11 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0[..N].try_into().unwrap()
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        take_packed(data, N).map(RustFixedBytes::from_slice)
+    }
 }
 
 /// Address - `address`
@@ -228,6 +390,7 @@ impl SolType for Address {
 
     const SOL_NAME: &'static str = "address";
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(20);
 
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
@@ -238,6 +401,11 @@ impl SolType for Address {
     fn valid_token(token: &Self::Token<'_>) -> bool {
         utils::check_zeroes(&token.0[..12])
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        take_packed(data, 20).map(RustAddress::from_slice)
+    }
 }
 
 /// Function - `function`
@@ -266,6 +434,7 @@ impl SolType for Function {
 
     const SOL_NAME: &'static str = "function";
     const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(24);
 
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
@@ -276,6 +445,11 @@ impl SolType for Function {
     fn valid_token(token: &Self::Token<'_>) -> bool {
         utils::check_zeroes(&token.0[24..])
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        take_packed(data, 24).map(RustFunction::from_slice)
+    }
 }
 
 /// Bytes - `bytes`
@@ -314,6 +488,7 @@ impl SolType for Bytes {
 
     const SOL_NAME: &'static str = "bytes";
     const ENCODED_SIZE: Option<usize> = None;
+    const PACKED_ENCODED_SIZE: Option<usize> = None;
 
     #[inline]
     fn valid_token(_token: &Self::Token<'_>) -> bool {
@@ -324,6 +499,11 @@ impl SolType for Bytes {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.into_bytes()
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        Ok(RustBytes::copy_from_slice(core::mem::take(data)))
+    }
 }
 
 /// String - `string`
@@ -362,6 +542,7 @@ impl SolType for String {
 
     const SOL_NAME: &'static str = "string";
     const ENCODED_SIZE: Option<usize> = None;
+    const PACKED_ENCODED_SIZE: Option<usize> = None;
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
@@ -376,6 +557,12 @@ impl SolType for String {
         // data.
         RustString::from_utf8_lossy(token.as_slice()).into_owned()
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        // NOTE: Lossy for the same reason as `detokenize`.
+        Ok(RustString::from_utf8_lossy(core::mem::take(data)).into_owned())
+    }
 }
 
 /// Array - `T[]`
@@ -412,7 +599,7 @@ where
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         for item in self {
-            T::stv_abi_encode_packed_to(item, out);
+            encode_packed_element::<T, U>(item, out);
         }
     }
 }
@@ -502,16 +689,45 @@ impl<T: SolType> SolType for Array<T> {
     const SOL_NAME: &'static str =
         NameBuffer::new().write_str(T::SOL_NAME).write_str("[]").as_str();
     const ENCODED_SIZE: Option<usize> = None;
+    const PACKED_ENCODED_SIZE: Option<usize> = None;
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
         token.0.iter().all(T::valid_token)
     }
 
+    #[inline]
+    fn type_check(token: &Self::Token<'_>) -> Result<()> {
+        token
+            .0
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, t)| T::type_check(t).map_err(|e| e.at(PathSegment::Index(i), None)))
+    }
+
+    #[inline]
+    fn name_decode_path(path: &mut [PathSegment]) {
+        if let [PathSegment::Index(_), rest @ ..] = path {
+            T::name_decode_path(rest);
+        }
+    }
+
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.into_iter().map(T::detokenize).collect()
     }
+
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        let size = match T::PACKED_ENCODED_SIZE {
+            Some(size) if size > 0 => size,
+            _ => return Err(Error::AmbiguousPackedLayout(Self::SOL_NAME.into())),
+        };
+        let mut values = Vec::with_capacity(data.len() / packed_slot_size(size));
+        while !data.is_empty() {
+            values.push(decode_packed_element::<T>(data, size)?);
+        }
+        Ok(values)
+    }
 }
 
 /// FixedArray - `T[M]`
@@ -556,7 +772,7 @@ where
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         for item in self {
-            T::stv_abi_encode_packed_to(item, out);
+            encode_packed_element::<T, U>(item, out);
         }
     }
 }
@@ -629,16 +845,46 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
             None => None,
         }
     };
+    const PACKED_ENCODED_SIZE: Option<usize> = {
+        match T::PACKED_ENCODED_SIZE {
+            Some(size) => Some(packed_slot_size(size) * N),
+            None => None,
+        }
+    };
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
         token.as_array().iter().all(T::valid_token)
     }
 
+    #[inline]
+    fn type_check(token: &Self::Token<'_>) -> Result<()> {
+        token
+            .as_array()
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, t)| T::type_check(t).map_err(|e| e.at(PathSegment::Index(i), None)))
+    }
+
+    #[inline]
+    fn name_decode_path(path: &mut [PathSegment]) {
+        if let [PathSegment::Index(_), rest @ ..] = path {
+            T::name_decode_path(rest);
+        }
+    }
+
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.map(T::detokenize)
     }
+
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        let Some(size) = T::PACKED_ENCODED_SIZE else {
+            return Err(Error::AmbiguousPackedLayout(Self::SOL_NAME.into()));
+        };
+        crate::impl_core::try_from_fn(|_| decode_packed_element::<T>(data, size))
+    }
 }
 
 macro_rules! tuple_encodable_impls {
@@ -712,18 +958,65 @@ macro_rules! tuple_impls {
                 )+
                 Some(acc)
             };
+            const PACKED_ENCODED_SIZE: Option<usize> = 'l: {
+                let mut acc = 0;
+                $(
+                    match <$ty as SolType>::PACKED_ENCODED_SIZE {
+                        Some(size) => acc += size,
+                        None => break 'l None,
+                    }
+                )+
+                Some(acc)
+            };
 
             fn valid_token(token: &Self::Token<'_>) -> bool {
                 let ($($ty,)+) = token;
                 $(<$ty as SolType>::valid_token($ty))&&+
             }
 
+            #[allow(unused_assignments)]
+            fn type_check(token: &Self::Token<'_>) -> Result<()> {
+                let ($($ty,)+) = token;
+                let mut i = 0;
+                $(
+                    <$ty as SolType>::type_check($ty)
+                        .map_err(|e| e.at(PathSegment::Member(i), None))?;
+                    i += 1;
+                )+
+                Ok(())
+            }
+
+            #[allow(unused_assignments)]
+            fn name_decode_path(path: &mut [PathSegment]) {
+                if let [PathSegment::Member(index), rest @ ..] = path {
+                    let index = *index;
+                    let mut i = 0;
+                    $(
+                        if i == index {
+                            return <$ty as SolType>::name_decode_path(rest);
+                        }
+                        i += 1;
+                    )+
+                }
+            }
+
             fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                 let ($($ty,)+) = token;
                 ($(
                     <$ty as SolType>::detokenize($ty),
                 )+)
             }
+
+            fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+                // only the last member may have a dynamic size
+                let sizes = [$(<$ty as SolType>::PACKED_ENCODED_SIZE,)+];
+                if sizes[..$count - 1].contains(&None) {
+                    return Err(Error::AmbiguousPackedLayout(Self::SOL_NAME.into()));
+                }
+                Ok(($(
+                    <$ty as SolType>::abi_decode_packed_from(data)?,
+                )+))
+            }
         }
     };
 }
@@ -749,6 +1042,7 @@ impl SolType for () {
 
     const SOL_NAME: &'static str = "()";
     const ENCODED_SIZE: Option<usize> = Some(0);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(0);
 
     #[inline]
     fn valid_token((): &()) -> bool {
@@ -757,10 +1051,81 @@ impl SolType for () {
 
     #[inline]
     fn detokenize((): ()) -> Self::RustType {}
+
+    #[inline]
+    fn abi_decode_packed_from(_data: &mut &[u8]) -> Result<Self::RustType> {
+        Ok(())
+    }
 }
 
 all_the_tuples!(tuple_impls);
 
+/// Splits `len` bytes off the front of packed data.
+#[inline]
+fn take_packed<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if data.len() < len {
+        return Err(Error::Overrun);
+    }
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+    Ok(bytes)
+}
+
+/// Returns the size of the slot of a packed array element of the given packed
+/// size. Array elements are left-padded to 32 bytes.
+#[inline]
+const fn packed_slot_size(size: usize) -> usize {
+    if size < 32 {
+        32
+    } else {
+        size
+    }
+}
+
+/// Returns `true` if array elements of `T` are packed-encoded as their
+/// standard single-word encoding: sign-extended for signed integers, and
+/// right-padded for fixed bytes.
+#[inline]
+const fn is_packed_word<T: SolType>() -> bool {
+    matches!(T::PACKED_ENCODED_SIZE, Some(size) if size < 32) && matches!(T::ENCODED_SIZE, Some(32))
+}
+
+/// Packed-encodes an array element into its slot.
+#[inline]
+fn encode_packed_element<T: SolTypeValue<U>, U: SolType>(item: &T, out: &mut Vec<u8>) {
+    if is_packed_word::<U>() {
+        out.extend_from_slice(&U::abi_encode(item));
+        return;
+    }
+
+    let start = out.len();
+    item.stv_abi_encode_packed_to(out);
+    let len = out.len() - start;
+    if len < 32 {
+        out.resize(start + 32, 0);
+        out[start..].rotate_right(32 - len);
+    }
+}
+
+/// Decodes a packed array element of the given packed size from its slot.
+#[inline]
+fn decode_packed_element<T: SolType>(data: &mut &[u8], size: usize) -> Result<T::RustType> {
+    let slot = take_packed(data, packed_slot_size(size))?;
+    if is_packed_word::<T>() {
+        T::abi_decode(slot, false)
+    } else {
+        T::abi_decode_packed(&slot[slot.len() - size..])
+    }
+}
+
+/// Reads `len` bytes of packed data into the low-order bytes of a word.
+#[inline]
+fn packed_word(data: &mut &[u8], len: usize) -> Result<WordToken> {
+    let mut word = Word::ZERO;
+    word[32 - len..].copy_from_slice(take_packed(data, len)?);
+    Ok(WordToken(word))
+}
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -795,6 +1160,33 @@ supported_fixed_bytes!(
     27, 28, 29, 30, 31, 32
 );
 
+/// Specifies the number of decimals in a [`Fixed`] or [`Ufixed`] as a type.
+pub struct DecimalCount<const N: usize>;
+
+impl<const N: usize> Sealed for DecimalCount<N> {}
+
+/// Statically guarantees that a [`Fixed`] or [`Ufixed`] decimal count is
+/// marked as supported.
+///
+/// This trait is *sealed*: the list of implementors below is total.
+///
+/// Users do not have the ability to mark additional [`DecimalCount<N>`] values
+/// as supported. Only decimal counts from 0 to 80 inclusive are supported.
+pub trait SupportedDecimals: Sealed {}
+
+macro_rules! supported_decimals {
+    ($($n:literal),+) => {$(
+        impl SupportedDecimals for DecimalCount<$n> {}
+    )+};
+}
+
+supported_decimals!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73,
+    74, 75, 76, 77, 78, 79, 80
+);
+
 /// Specifies the number of bits in an [`Int`] or [`Uint`] as a type.
 pub struct IntBitCount<const N: usize>;
 
@@ -1142,6 +1534,9 @@ mod tests {
         assert_name!(Int<8>, "int8");
         assert_name!(Int<16>, "int16");
         assert_name!(Int<32>, "int32");
+        assert_name!(Fixed<128, 18>, "fixed128x18");
+        assert_name!(Fixed<8, 0>, "fixed8x0");
+        assert_name!(Ufixed<256, 80>, "ufixed256x80");
         assert_name!(FixedBytes<1>, "bytes1");
         assert_name!(FixedBytes<16>, "bytes16");
         assert_name!(FixedBytes<32>, "bytes32");
@@ -1525,4 +1920,107 @@ mod tests {
         );
         assert_eq!(hex::encode(value.abi_encode_packed()), hex::encode(expected));
     }
+
+    #[test]
+    fn decode_packed() {
+        type T = sol! { (address, uint160, uint24, int24, uint32, int32) };
+        let value = (RustAddress::with_last_byte(1), U256::from(2), 3u32, -3i32, 3u32, -3i32);
+        let packed = T::abi_encode_packed(&value);
+        assert_eq!(T::PACKED_ENCODED_SIZE, Some(packed.len()));
+        assert_eq!(T::abi_decode_packed(&packed), Ok(value));
+        assert_eq!(T::abi_decode_packed(&packed[1..]), Err(Error::Overrun));
+        assert_eq!(<(Address, Uint<8>)>::abi_decode_packed(&packed), Err(Error::BufferNotEmpty));
+
+        // the last member may have a dynamic size
+        type Path = sol! { (address, uint24, address, bytes) };
+        let value = (
+            RustAddress::repeat_byte(0x11),
+            500,
+            RustAddress::repeat_byte(0x22),
+            RustBytes::from_static(b"hook"),
+        );
+        let packed = Path::abi_encode_packed(&value);
+        assert_eq!(Path::PACKED_ENCODED_SIZE, None);
+        assert_eq!(Path::abi_decode_packed(&packed), Ok(value));
+        assert_eq!(
+            <(FixedBytes<4>, String)>::abi_decode_packed(b"\x12\x34\x56\x78str"),
+            Ok((RustFixedBytes::new([0x12, 0x34, 0x56, 0x78]), "str".into()))
+        );
+        assert_eq!(<(Bool, Bytes)>::abi_decode_packed(&[1]), Ok((true, RustBytes::new())));
+
+        let values = vec![(1u8, -1i16), (2, -2)];
+        let packed = Array::<(Uint<8>, Int<16>)>::abi_encode_packed(&values);
+        assert_eq!(Array::<(Uint<8>, Int<16>)>::abi_decode_packed(&packed), Ok(values));
+        assert_eq!(
+            Array::<(Uint<8>, Int<16>)>::abi_decode_packed(&packed[1..]),
+            Err(Error::Overrun)
+        );
+
+        // array elements are left-padded to 32 bytes
+        let values = [1u16, 2];
+        let packed = FixedArray::<Uint<16>, 2>::abi_encode_packed(&values);
+        assert_eq!(
+            packed,
+            [U256::from(1).to_be_bytes::<32>(), U256::from(2).to_be_bytes()].concat()
+        );
+        assert_eq!(FixedArray::<Uint<16>, 2>::PACKED_ENCODED_SIZE, Some(64));
+        assert_eq!(FixedArray::<Uint<16>, 2>::abi_decode_packed(&packed), Ok(values));
+        assert_eq!(Array::<Uint<16>>::abi_decode_packed(&packed), Ok(values.to_vec()));
+
+        // signed elements are sign-extended, and fixed bytes are right-padded
+        let values = vec![-2i16, 3];
+        let packed = Array::<Int<16>>::abi_encode_packed(&values);
+        assert_eq!(
+            packed,
+            [I256::try_from(-2).unwrap().to_be_bytes::<32>(), U256::from(3).to_be_bytes()].concat()
+        );
+        assert_eq!(packed, Array::<Int<16>>::abi_encode_params(&values)[64..]);
+        assert_eq!(Array::<Int<16>>::abi_decode_packed(&packed), Ok(values));
+        let values = [alloy_primitives::FixedBytes([0xab, 0xcd])];
+        let packed = FixedArray::<FixedBytes<2>, 1>::abi_encode_packed(&values);
+        assert_eq!(packed, [[0xab, 0xcd].as_slice(), &[0; 30]].concat());
+        assert_eq!(FixedArray::<FixedBytes<2>, 1>::abi_decode_packed(&packed), Ok(values));
+
+        // only the last member may have a dynamic size
+        let ambiguous = |ty: &'static str| Error::AmbiguousPackedLayout(ty.into());
+        assert_eq!(
+            <(Bytes, Address)>::abi_decode_packed(&[0; 20]).unwrap_err(),
+            ambiguous("(bytes,address)")
+        );
+        assert_eq!(
+            <((Address, String), Bool)>::abi_decode_packed(&[0; 21]).unwrap_err(),
+            ambiguous("((address,string),bool)")
+        );
+        assert_eq!(Array::<String>::abi_decode_packed(b"ab").unwrap_err(), ambiguous("string[]"));
+        assert_eq!(
+            FixedArray::<Bytes, 1>::abi_decode_packed(b"ab").unwrap_err(),
+            ambiguous("bytes[1]")
+        );
+        assert_eq!(Array::<()>::abi_decode_packed(&[]).unwrap_err(), ambiguous("()[]"));
+    }
+
+    #[test]
+    fn fixed_point() {
+        let x: RustFixed<18> = "-1.25".parse().unwrap();
+        let encoded = Fixed::<128, 18>::abi_encode(&x);
+        assert_eq!(encoded, (-1_250_000_000_000_000_000i128).abi_encode());
+        assert_eq!(Fixed::<128, 18>::abi_decode(&encoded, true).unwrap(), x);
+        assert_eq!(
+            Fixed::<128, 18>::abi_encode_packed(&x),
+            (-1_250_000_000_000_000_000i128).to_be_bytes()
+        );
+
+        // out of range for the bit size
+        let mut word = [0u8; 32];
+        word[15] = 1;
+        assert!(Fixed::<128, 18>::abi_decode(&word, true).is_err());
+        assert!(Ufixed::<128, 18>::abi_decode(&word, true).is_err());
+
+        let y: RustUfixed<2> = "3.14".parse().unwrap();
+        let encoded = Ufixed::<16, 2>::abi_encode(&y);
+        assert_eq!(encoded, U256::from(314).abi_encode());
+        assert_eq!(Ufixed::<16, 2>::abi_decode(&encoded, true).unwrap(), y);
+        assert_eq!(Ufixed::<16, 2>::abi_encode_packed(&y), 314u16.to_be_bytes());
+        assert_eq!(y.abi_encode(), encoded);
+    }
 }
@@ -69,7 +69,7 @@ pub trait SolError: Sized {
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
         out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize()));
+        crate::abi::encode_sequence_to(&self.tokenize(), out);
     }
 
     /// ABI encode the error to the given buffer **with** its selector.
@@ -91,6 +91,22 @@ where
     word_impl!();
 }
 
+impl<const M: usize, const N: usize> EventTopic for Fixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    word_impl!();
+}
+
+impl<const M: usize, const N: usize> EventTopic for Ufixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+    DecimalCount<N>: SupportedDecimals,
+{
+    word_impl!();
+}
+
 impl<const N: usize> EventTopic for FixedBytes<N>
 where
     ByteCount<N>: SupportedFixedBytes,
@@ -1,9 +1,10 @@
 use crate::{
-    abi::{Token, TokenSeq},
+    abi::{DecodeLimits, DecodeState, Token, TokenSeq},
     private::SolTypeValue,
     Result, SolType, Word,
 };
 use alloc::vec::Vec;
+use alloy_primitives::bytes::BufMut;
 
 /// A Solidity function call.
 ///
@@ -60,7 +61,7 @@ pub trait SolCall: Sized {
     /// selector.
     #[inline]
     fn abi_decode_raw(data: &[u8], validate: bool) -> Result<Self> {
-        <Self::Parameters<'_> as SolType>::abi_decode_sequence(data, validate).map(Self::new)
+        Self::abi_decode_raw_with_limits(data, validate, DecodeLimits::new())
     }
 
     /// ABI decode this call's arguments from the given slice, **with** the
@@ -73,11 +74,44 @@ pub trait SolCall: Sized {
         Self::abi_decode_raw(data, validate)
     }
 
+    /// ABI decode this call's arguments from the given slice, **without** its
+    /// selector, enforcing the given resource limits.
+    ///
+    /// See [`DecodeLimits`] for more information.
+    #[inline]
+    fn abi_decode_raw_with_limits(
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Self> {
+        let state = DecodeState::new(limits);
+        crate::abi::decode_sequence_with_state::<Self::Token<'_>>(data, validate, &state)
+            .and_then(|token| {
+                if validate {
+                    <Self::Parameters<'_> as SolType>::type_check(&token)?;
+                }
+                Ok(Self::new(<Self::Parameters<'_> as SolType>::detokenize(token)))
+            })
+            .map_err(|e| e.map_path(<Self::Parameters<'_> as SolType>::name_decode_path))
+    }
+
+    /// ABI decode this call's arguments from the given slice, **with** the
+    /// selector, enforcing the given resource limits.
+    ///
+    /// See [`DecodeLimits`] for more information.
+    #[inline]
+    fn abi_decode_with_limits(data: &[u8], validate: bool, limits: DecodeLimits) -> Result<Self> {
+        let data = data
+            .strip_prefix(&Self::SELECTOR)
+            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE))?;
+        Self::abi_decode_raw_with_limits(data, validate, limits)
+    }
+
     /// ABI encode the call to the given buffer **without** its selector.
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
         out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize()));
+        crate::abi::encode_sequence_to(&self.tokenize(), out);
     }
 
     /// ABI encode the call to the given buffer **with** its selector.
@@ -89,6 +123,20 @@ pub trait SolCall: Sized {
         out
     }
 
+    /// ABI encode the call to the given buffer **with** its selector, without
+    /// any intermediate allocations for the encoded data.
+    ///
+    /// This writes exactly `4 + self.abi_encoded_size()` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer does not have enough remaining capacity.
+    #[inline]
+    fn abi_encode_to<B: ?Sized + BufMut>(&self, out: &mut B) {
+        out.put_slice(&Self::SELECTOR);
+        crate::abi::encode_sequence_to(&self.tokenize(), out);
+    }
+
     /// ABI decode this call's return values from the given slice.
     fn abi_decode_returns(data: &[u8], validate: bool) -> Result<Self::Return>;
 
@@ -1,5 +1,8 @@
 pub mod data_type;
 
+mod borrowed;
+pub use borrowed::{ArrayRef, ArrayRefIter, SolTypeRef};
+
 mod r#enum;
 pub use r#enum::SolEnum;
 
@@ -1,9 +1,10 @@
 use crate::{
-    abi::{self, Token, TokenSeq},
+    abi::{self, DecodeLimits, DecodeState, Token, TokenSeq},
     private::SolTypeValue,
-    Result, Word,
+    PathSegment, Result, Word,
 };
 use alloc::{borrow::Cow, vec::Vec};
+use alloy_primitives::bytes::BufMut;
 
 /// A Solidity type.
 ///
@@ -117,6 +118,13 @@ pub trait SolType: Sized {
     /// There should be no need to override the default implementation.
     const DYNAMIC: bool = Self::ENCODED_SIZE.is_none();
 
+    /// The statically-known packed-encoded size of the type.
+    ///
+    /// This is `None` for types whose packed encoding has a value-dependent
+    /// length, such as `bytes`, `string` and dynamic arrays. Defaults to
+    /// `None`.
+    const PACKED_ENCODED_SIZE: Option<usize> = None;
+
     /// Returns the name of this type in Solidity.
     #[deprecated(since = "0.6.3", note = "use `SOL_NAME` instead")]
     #[inline]
@@ -150,6 +158,17 @@ pub trait SolType: Sized {
     /// See the [`abi::token`] module for more information.
     fn detokenize(token: Self::Token<'_>) -> Self::RustType;
 
+    /// Replaces the positional [`PathSegment::Member`]s in the path of a
+    /// decoding error, relative to this type, with field names where they are
+    /// known.
+    ///
+    /// This is applied to the errors returned by the `abi_decode*` methods.
+    /// See [`Error::Located`](crate::Error::Located) for more information.
+    #[inline]
+    fn name_decode_path(path: &mut [PathSegment]) {
+        let _ = path;
+    }
+
     /// Tokenizes the given value into this type's token.
     ///
     /// See the [`abi::token`] module for more information.
@@ -184,7 +203,8 @@ pub trait SolType: Sized {
     /// This is different from normal ABI encoding:
     /// - types shorter than 32 bytes are concatenated directly, without padding or sign extension;
     /// - dynamic types are encoded in-place and without the length;
-    /// - array elements are padded, but still encoded in-place.
+    /// - array elements are padded to 32 bytes as in the standard encoding, but still encoded
+    ///   in-place.
     ///
     /// More information can be found in the [Solidity docs](https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode).
     #[inline]
@@ -194,6 +214,62 @@ pub trait SolType: Sized {
         out
     }
 
+    /// Non-standard Packed Mode ABI decoding, advancing `data` past the
+    /// decoded value.
+    ///
+    /// Types with a dynamic [packed size](SolType::PACKED_ENCODED_SIZE)
+    /// consume all of the remaining data.
+    ///
+    /// See [`abi_decode_packed`][SolType::abi_decode_packed] for more details.
+    ///
+    /// The default implementation returns an error, as packed decoding is not
+    /// supported unless it is implemented by the type.
+    #[inline]
+    fn abi_decode_packed_from(data: &mut &[u8]) -> Result<Self::RustType> {
+        let _ = data;
+        Err(crate::Error::custom(alloc::format!(
+            "packed decoding is not supported for `{}`",
+            Self::SOL_NAME
+        )))
+    }
+
+    /// Non-standard Packed Mode ABI decoding.
+    ///
+    /// This is the inverse of [`abi_encode_packed`][SolType::abi_encode_packed].
+    /// Since the packed encoding does not contain lengths, a value can only be
+    /// decoded if all of its members have a statically-known
+    /// [packed size](SolType::PACKED_ENCODED_SIZE), except for the very last
+    /// one. For example, `(address,uint24,bytes)` can be decoded, but
+    /// `(bytes,address)` and `bytes[]` cannot, and will return
+    /// [`Error::AmbiguousPackedLayout`](crate::Error::AmbiguousPackedLayout).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::address;
+    /// use alloy_sol_types::{sol_data::*, SolType};
+    ///
+    /// type HookData = (Address, Uint<24>, Bytes);
+    ///
+    /// let hook = address!("1111111111111111111111111111111111111111");
+    /// let value = (hook, 3000, b"data".into());
+    /// let packed = HookData::abi_encode_packed(&value);
+    /// assert_eq!(packed.len(), 20 + 3 + 4);
+    /// assert_eq!(HookData::abi_decode_packed(&packed)?, value);
+    ///
+    /// assert!(<(Bytes, Address)>::abi_decode_packed(&packed).is_err());
+    /// # Ok::<(), alloy_sol_types::Error>(())
+    /// ```
+    #[inline]
+    fn abi_decode_packed(mut data: &[u8]) -> Result<Self::RustType> {
+        let value = Self::abi_decode_packed_from(&mut data)?;
+        if data.is_empty() {
+            Ok(value)
+        } else {
+            Err(crate::Error::BufferNotEmpty)
+        }
+    }
+
     /// Tokenizes and ABI-encodes the given value by wrapping it in a
     /// single-element sequence.
     ///
@@ -203,6 +279,34 @@ pub trait SolType: Sized {
         abi::encode(&rust.stv_to_tokens())
     }
 
+    /// Tokenizes and ABI-encodes the given value into the given buffer, by
+    /// wrapping it in a single-element sequence.
+    ///
+    /// This writes exactly [`abi_encoded_size`](SolType::abi_encoded_size)
+    /// bytes, without any intermediate allocations for the encoded data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer does not have enough remaining capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::address;
+    /// use alloy_sol_types::{sol_data::*, SolType};
+    ///
+    /// type T = (Address, String);
+    /// let value = (address!("0000000000000000000000000000000000000001"), "hello".to_string());
+    ///
+    /// let mut buf = vec![0u8; T::abi_encoded_size(&value)];
+    /// T::abi_encode_to(&value, &mut &mut buf[..]);
+    /// assert_eq!(buf, T::abi_encode(&value));
+    /// ```
+    #[inline]
+    fn abi_encode_to<E: ?Sized + SolTypeValue<Self>, B: ?Sized + BufMut>(rust: &E, out: &mut B) {
+        abi::encode_to(&rust.stv_to_tokens(), out)
+    }
+
     /// Tokenizes and ABI-encodes the given value as function parameters.
     ///
     /// See the [`abi`] module for more information.
@@ -214,6 +318,20 @@ pub trait SolType: Sized {
         abi::encode_params(&rust.stv_to_tokens())
     }
 
+    /// Tokenizes and ABI-encodes the given value as function parameters into
+    /// the given buffer.
+    ///
+    /// See [`abi_encode_to`](SolType::abi_encode_to) for more information.
+    #[inline]
+    fn abi_encode_params_to<E: ?Sized + SolTypeValue<Self>, B: ?Sized + BufMut>(
+        rust: &E,
+        out: &mut B,
+    ) where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        abi::encode_params_to(&rust.stv_to_tokens(), out)
+    }
+
     /// Tokenizes and ABI-encodes the given value as a sequence.
     ///
     /// See the [`abi`] module for more information.
@@ -225,13 +343,29 @@ pub trait SolType: Sized {
         abi::encode_sequence(&rust.stv_to_tokens())
     }
 
+    /// Tokenizes and ABI-encodes the given value as a sequence into the given
+    /// buffer.
+    ///
+    /// See [`abi_encode_to`](SolType::abi_encode_to) for more information.
+    #[inline]
+    fn abi_encode_sequence_to<E: ?Sized + SolTypeValue<Self>, B: ?Sized + BufMut>(
+        rust: &E,
+        out: &mut B,
+    ) where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        abi::encode_sequence_to(&rust.stv_to_tokens(), out)
+    }
+
     /// Decodes this type's value from an ABI blob by interpreting it as a
     /// single-element sequence.
     ///
     /// See the [`abi`] module for more information.
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self::RustType> {
-        abi::decode::<Self::Token<'_>>(data, validate).and_then(check_decode::<Self>(validate))
+        abi::decode::<Self::Token<'_>>(data, validate)
+            .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as
@@ -245,6 +379,7 @@ pub trait SolType: Sized {
     {
         abi::decode_params::<Self::Token<'_>>(data, validate)
             .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as a
@@ -258,6 +393,64 @@ pub trait SolType: Sized {
     {
         abi::decode_sequence::<Self::Token<'_>>(data, validate)
             .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// single-element sequence, enforcing the given resource limits.
+    ///
+    /// See [`abi_decode`](SolType::abi_decode) and [`DecodeLimits`] for more
+    /// information.
+    #[inline]
+    fn abi_decode_with_limits(
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Self::RustType> {
+        let state = DecodeState::new(limits);
+        abi::decode_with_state::<Self::Token<'_>>(data, validate, &state)
+            .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as
+    /// function parameters, enforcing the given resource limits.
+    ///
+    /// See [`abi_decode_params`](SolType::abi_decode_params) and
+    /// [`DecodeLimits`] for more information.
+    #[inline]
+    fn abi_decode_params_with_limits(
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Self::RustType>
+    where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        let state = DecodeState::new(limits);
+        abi::decode_params_with_state::<Self::Token<'_>>(data, validate, &state)
+            .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// sequence, enforcing the given resource limits.
+    ///
+    /// See [`abi_decode_sequence`](SolType::abi_decode_sequence) and
+    /// [`DecodeLimits`] for more information.
+    #[inline]
+    fn abi_decode_sequence_with_limits(
+        data: &[u8],
+        validate: bool,
+        limits: DecodeLimits,
+    ) -> Result<Self::RustType>
+    where
+        for<'a> Self::Token<'a>: TokenSeq<'a>,
+    {
+        let state = DecodeState::new(limits);
+        abi::decode_sequence_with_state::<Self::Token<'_>>(data, validate, &state)
+            .and_then(check_decode::<Self>(validate))
+            .map_err(|e| e.map_path(Self::name_decode_path))
     }
 }
 
@@ -2,11 +2,15 @@ use super::SolType;
 use crate::{
     abi::TokenSeq,
     private::SolTypeValue,
-    sol_data::{self, ByteCount, SupportedFixedBytes},
+    sol_data::{self, ByteCount, DecimalCount, SupportedDecimals, SupportedFixedBytes},
     Result, Word,
 };
 use alloc::{borrow::Cow, string::String, vec::Vec};
-use alloy_primitives::{Address, Bytes, FixedBytes, Function, I256, U256};
+use alloy_primitives::{
+    aliases::{Fixed256, Ufixed256},
+    bytes::BufMut,
+    Address, Bytes, FixedBytes, Function, I256, U256,
+};
 
 /// A Solidity value.
 ///
@@ -107,6 +111,17 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
         out
     }
 
+    /// Non-standard Packed Mode ABI decoding.
+    ///
+    /// See [`SolType::abi_decode_packed`] for more information.
+    #[inline]
+    fn abi_decode_packed(data: &[u8]) -> Result<Self>
+    where
+        Self: From<<Self::SolType as SolType>::RustType>,
+    {
+        Self::SolType::abi_decode_packed(data).map(Self::from)
+    }
+
     /// ABI-encodes the value.
     ///
     /// See [`SolType::abi_encode`] for more information.
@@ -115,6 +130,14 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
         Self::SolType::abi_encode(self)
     }
 
+    /// ABI-encodes the value into the given buffer.
+    ///
+    /// See [`SolType::abi_encode_to`] for more information.
+    #[inline]
+    fn abi_encode_to<B: ?Sized + BufMut>(&self, out: &mut B) {
+        Self::SolType::abi_encode_to(self, out)
+    }
+
     /// Encodes an ABI sequence.
     ///
     /// See [`SolType::abi_encode_sequence`] for more information.
@@ -126,6 +149,17 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
         Self::SolType::abi_encode_sequence(self)
     }
 
+    /// Encodes an ABI sequence into the given buffer.
+    ///
+    /// See [`SolType::abi_encode_sequence_to`] for more information.
+    #[inline]
+    fn abi_encode_sequence_to<B: ?Sized + BufMut>(&self, out: &mut B)
+    where
+        for<'a> <Self::SolType as SolType>::Token<'a>: TokenSeq<'a>,
+    {
+        Self::SolType::abi_encode_sequence_to(self, out)
+    }
+
     /// Encodes an ABI sequence suitable for function parameters.
     ///
     /// See [`SolType::abi_encode_params`] for more information.
@@ -137,6 +171,18 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
         Self::SolType::abi_encode_params(self)
     }
 
+    /// Encodes an ABI sequence suitable for function parameters into the
+    /// given buffer.
+    ///
+    /// See [`SolType::abi_encode_params_to`] for more information.
+    #[inline]
+    fn abi_encode_params_to<B: ?Sized + BufMut>(&self, out: &mut B)
+    where
+        for<'a> <Self::SolType as SolType>::Token<'a>: TokenSeq<'a>,
+    {
+        Self::SolType::abi_encode_params_to(self, out)
+    }
+
     /// ABI-decode this type from the given data.
     ///
     /// See [`SolType::abi_decode`] for more information.
@@ -200,6 +246,9 @@ impl_sol_value! {
     [] u128 => sol_data::Uint::<128> [];
     [] U256 => sol_data::Uint::<256> [];
 
+    [const N: usize] Fixed256<N> => sol_data::Fixed<256, N> [where DecimalCount<N>: SupportedDecimals];
+    [const N: usize] Ufixed256<N> => sol_data::Ufixed<256, N> [where DecimalCount<N>: SupportedDecimals];
+
     [] Address => sol_data::Address [];
     [] Function => sol_data::Function [];
     [const N: usize] FixedBytes<N> => sol_data::FixedBytes<N> [where ByteCount<N>: SupportedFixedBytes];
@@ -112,6 +112,10 @@ fn function() {
         32 + (64 + 32) + (64 + 32 + 32) + (64 + 3 * 32) + 2 * 32 + (32 + 32) + (64 + 4 * (32 + 32))
     );
     assert_eq!(encoded.len(), 4 + call.abi_encoded_size());
+
+    let mut buf = vec![0u8; encoded.len()];
+    call.abi_encode_to(&mut &mut buf[..]);
+    assert_eq!(buf, encoded);
 }
 
 #[test]
@@ -798,6 +802,196 @@ fn decoder_fixed_array_before_dynamic() {
     assert_eq!(decoded, full_report);
 }
 
+#[test]
+fn decode_error_paths() {
+    sol! {
+        #[derive(Debug)]
+        struct Order {
+            address maker;
+            bytes signature;
+        }
+
+        #[derive(Debug)]
+        struct Pair {
+            address a;
+            bool b;
+        }
+
+        #[derive(Debug)]
+        function fill(uint256 id, Order[] orders);
+        #[derive(Debug)]
+        function pairs(uint256, Pair[2] pairs);
+    }
+
+    let order = Order { maker: Address::ZERO, signature: bytes![0; 65] };
+    let call = fillCall { id: U256::from(1), orders: vec![order; 4] };
+    let encoded = call.abi_encode();
+
+    // truncate the contents of `orders[3].signature`
+    let err = fillCall::abi_decode(&encoded[..encoded.len() - 32], false).unwrap_err();
+    assert_eq!(*err.root_cause(), alloy_sol_types::Error::Overrun);
+    assert_eq!(err.path().unwrap().to_string(), "orders[3].signature");
+    // id, orders offset, orders length, 4 order offsets, 3 orders, order maker
+    assert_eq!(err.offset(), Some(32 * (2 + 1 + 4 + 3 * 6 + 1)));
+    assert_eq!(
+        err.to_string(),
+        "buffer overrun while deserializing (at `orders[3].signature`, offset 832)"
+    );
+
+    let pair = Pair { a: Address::ZERO, b: true };
+    let call = pairsCall { _0: U256::from(1), pairs: [pair.clone(), pair] };
+    let mut encoded = call.abi_encode();
+    // dirty the high bytes of `pairs[1].b`
+    encoded[4 + 4 * 32] = 1;
+    assert!(pairsCall::abi_decode(&encoded, false).is_ok());
+    let err = pairsCall::abi_decode(&encoded, true).unwrap_err();
+    assert!(matches!(err.root_cause(), alloy_sol_types::Error::TypeCheckFail { .. }));
+    assert_eq!(err.path().unwrap().to_string(), "pairs[1].b");
+    assert_eq!(err.offset(), None);
+
+    // unnamed parameters are positional
+    encoded.truncate(4 + 32 + 16);
+    let err = pairsCall::abi_decode(&encoded, false).unwrap_err();
+    assert_eq!(err.path().unwrap().to_string(), "pairs[0].a");
+    let err = pairsCall::abi_decode(&encoded[..4 + 16], false).unwrap_err();
+    assert_eq!(err.path().unwrap().to_string(), "0");
+}
+
+#[test]
+fn decode_with_limits() {
+    use alloy_sol_types::{abi::DecodeLimits, Error};
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        struct Order {
+            address maker;
+            bytes signature;
+        }
+
+        #[derive(Debug, PartialEq)]
+        function fill(uint256 id, Order[] orders);
+    }
+
+    let order = Order { maker: Address::ZERO, signature: bytes![0; 65] };
+    let call = fillCall { id: U256::from(1), orders: vec![order; 4] };
+    let encoded = call.abi_encode();
+    assert_eq!(
+        fillCall::abi_decode_with_limits(&encoded, true, DecodeLimits::new()),
+        Ok(call.clone())
+    );
+
+    let limits = DecodeLimits::new().with_max_array_len(3);
+    let err = fillCall::abi_decode_with_limits(&encoded, true, limits).unwrap_err();
+    assert_eq!(*err.root_cause(), Error::ArrayLengthLimitExceeded(3));
+    assert_eq!(err.path().unwrap().to_string(), "orders");
+
+    let limits = DecodeLimits::new().with_max_depth(2);
+    let err = fillCall::abi_decode_raw_with_limits(&encoded[4..], true, limits).unwrap_err();
+    assert_eq!(*err.root_cause(), Error::RecursionLimitExceeded(2));
+    assert_eq!(err.path().unwrap().to_string(), "orders[0]");
+
+    let encoded = Order::abi_encode(&call.orders[0]);
+    let limits = DecodeLimits::new().with_max_allocation(64);
+    let err = Order::abi_decode_with_limits(&encoded, true, limits).unwrap_err();
+    assert_eq!(*err.root_cause(), Error::AllocationLimitExceeded(64));
+    assert_eq!(err.path().unwrap().to_string(), "signature");
+}
+
+#[test]
+fn decode_borrowed() {
+    sol! {
+        #![sol(all_derives, borrowed)]
+
+        enum Side {
+            Buy,
+            Sell
+        }
+
+        struct Order {
+            address maker;
+            Side side;
+            bytes signature;
+            string note;
+        }
+
+        function fill(uint256 id, Order[] orders, string[2] tags);
+        function ping();
+
+        event Filled(address indexed taker, string indexed tag, Order order, bytes data);
+    }
+
+    let order = Order {
+        maker: Address::repeat_byte(0x11),
+        side: Side::Sell,
+        signature: bytes![0x22; 65],
+        note: "gm".into(),
+    };
+    let call = fillCall {
+        id: U256::from(1),
+        orders: vec![order.clone(), order.clone()],
+        tags: ["a".into(), "b".into()],
+    };
+    let encoded = call.abi_encode();
+
+    for validate in [false, true] {
+        let decoded = fillCallRef::abi_decode(&encoded, validate).unwrap();
+        assert_eq!(decoded.id, call.id);
+        assert_eq!(decoded.tags, ["a", "b"]);
+        assert_eq!(decoded.orders.len(), 2);
+        for decoded_order in decoded.orders {
+            let decoded_order: OrderRef<'_> = decoded_order.unwrap();
+            assert_eq!(decoded_order.maker, order.maker);
+            assert_eq!(decoded_order.side, Side::Sell);
+            assert_eq!(decoded_order.signature, &order.signature[..]);
+            assert_eq!(decoded_order.note, "gm");
+        }
+        assert!(format!("{decoded:?}").contains("note: \"gm\""));
+    }
+    let err = fillCallRef::abi_decode(&encoded[..encoded.len() - 32], true).unwrap_err();
+    assert_eq!(err.path().unwrap().to_string(), "tags[1]");
+    assert!(fillCallRef::abi_decode(&pingCall {}.abi_encode(), false).is_err());
+    assert!(pingCallRef::abi_decode(&pingCall {}.abi_encode(), true).is_ok());
+
+    let event = Filled {
+        taker: Address::repeat_byte(0x33),
+        tag: keccak256("tag"),
+        order: order.clone(),
+        data: bytes![1, 2, 3],
+    };
+    let log = alloy_primitives::LogData::from(&event);
+    let decoded = FilledRef::decode_log_data(&log, true).unwrap();
+    assert_eq!(decoded.taker, event.taker);
+    assert_eq!(decoded.tag, event.tag);
+    assert_eq!(decoded.order.note, "gm");
+    assert_eq!(decoded.data, [1, 2, 3]);
+    // the borrowed data points into the log
+    assert!(log.data.as_ptr_range().contains(&decoded.data.as_ptr()));
+}
+
+// Borrowed structs are opt-in, so that they don't collide with existing items.
+#[test]
+fn borrowed_opt_in() {
+    sol! {
+        struct Order {
+            address maker;
+        }
+
+        struct OrderRef {
+            Order order;
+            bytes32 hash;
+        }
+
+        #[sol(borrowed)]
+        function cancel(bytes32 hash);
+    }
+
+    let order = OrderRef { order: Order { maker: Address::ZERO }, hash: keccak256("order") };
+    let encoded = OrderRef::abi_encode(&order);
+    assert_eq!(OrderRef::abi_decode(&encoded, true).unwrap().hash, order.hash);
+    let encoded = cancelCall { hash: order.hash }.abi_encode();
+    assert_eq!(cancelCallRef::abi_decode(&encoded, true).unwrap().hash, order.hash);
+}
+
 #[test]
 fn bytecode_attributes() {
     sol! {
@@ -808,3 +1002,25 @@ fn bytecode_attributes() {
     assert_eq!(Dummy::BYTECODE[..], hex::decode("1234").unwrap());
     assert_eq!(Dummy::DEPLOYED_BYTECODE[..], hex::decode("5678").unwrap());
 }
+
+#[test]
+fn fixed_point() {
+    use alloy_primitives::aliases::{Fixed256, Ufixed256};
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        function setRate(ufixed128x18 rate, fixed64x2 delta, fixed[] history);
+    }
+
+    assert_eq!(setRateCall::SIGNATURE, "setRate(ufixed128x18,fixed64x2,fixed128x18[])");
+
+    let call = setRateCall {
+        rate: "1.25".parse::<Ufixed256<18>>().unwrap(),
+        delta: "-0.5".parse::<Fixed256<2>>().unwrap(),
+        history: vec!["-1.5".parse().unwrap(), "2".parse().unwrap()],
+    };
+    let encoded = call.abi_encode();
+    assert_eq!(encoded[4..36], U256::from(1_250_000_000_000_000_000u64).to_be_bytes::<32>());
+    assert_eq!(encoded[36..68], I256::try_from(-50).unwrap().to_be_bytes::<32>());
+    assert_eq!(setRateCall::abi_decode(&encoded, true).unwrap(), call);
+}
@@ -278,6 +278,8 @@ macro_rules! make_visitor {
                         | Type::Bool(_)
                         | Type::Uint(..)
                         | Type::Int(..)
+                        | Type::Ufixed(..)
+                        | Type::Fixed(..)
                         | Type::String(_)
                         | Type::Bytes(_)
                         | Type::FixedBytes(..) => {},
@@ -31,7 +31,6 @@ pub use tuple::TypeTuple;
 /// <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.typeName>
 #[derive(Clone)]
 pub enum Type {
-    // TODO: `fixed` and `ufixed`
     /// `address $(payable)?`
     Address(Span, Option<kw::payable>),
     /// `bool`
@@ -49,6 +48,11 @@ pub enum Type {
     /// `uint[size]`
     Uint(Span, Option<NonZeroU16>),
 
+    /// `fixed[MxN]`
+    Fixed(Span, Option<(NonZeroU16, u8)>),
+    /// `ufixed[MxN]`
+    Ufixed(Span, Option<(NonZeroU16, u8)>),
+
     /// `$ty[$($size)?]`
     Array(TypeArray),
     /// `$(tuple)? ( $($types,)* )`
@@ -73,6 +77,8 @@ impl PartialEq for Type {
             (Self::FixedBytes(_, a), Self::FixedBytes(_, b)) => a == b,
             (Self::Int(_, a), Self::Int(_, b)) => a == b,
             (Self::Uint(_, a), Self::Uint(_, b)) => a == b,
+            (Self::Fixed(_, a), Self::Fixed(_, b)) => a == b,
+            (Self::Ufixed(_, a), Self::Ufixed(_, b)) => a == b,
 
             (Self::Tuple(a), Self::Tuple(b)) => a == b,
             (Self::Array(a), Self::Array(b)) => a == b,
@@ -96,6 +102,8 @@ impl Hash for Type {
             Self::FixedBytes(_, size) => size.hash(state),
             Self::Int(_, size) => size.hash(state),
             Self::Uint(_, size) => size.hash(state),
+            Self::Fixed(_, size) => size.hash(state),
+            Self::Ufixed(_, size) => size.hash(state),
 
             Self::Tuple(tuple) => tuple.hash(state),
             Self::Array(array) => array.hash(state),
@@ -119,6 +127,8 @@ impl fmt::Debug for Type {
             Self::FixedBytes(_, size) => f.debug_tuple("FixedBytes").field(size).finish(),
             Self::Int(_, size) => f.debug_tuple("Int").field(size).finish(),
             Self::Uint(_, size) => f.debug_tuple("Uint").field(size).finish(),
+            Self::Fixed(_, size) => f.debug_tuple("Fixed").field(size).finish(),
+            Self::Ufixed(_, size) => f.debug_tuple("Ufixed").field(size).finish(),
 
             Self::Tuple(tuple) => tuple.fmt(f),
             Self::Array(array) => array.fmt(f),
@@ -141,6 +151,8 @@ impl fmt::Display for Type {
             Self::FixedBytes(_, size) => write!(f, "bytes{size}"),
             Self::Int(_, size) => write_opt(f, "int", *size),
             Self::Uint(_, size) => write_opt(f, "uint", *size),
+            Self::Fixed(_, size) => write_fixed_opt(f, "fixed", *size),
+            Self::Ufixed(_, size) => write_fixed_opt(f, "ufixed", *size),
 
             Self::Tuple(tuple) => tuple.fmt(f),
             Self::Array(array) => array.fmt(f),
@@ -176,7 +188,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span,
             Self::Tuple(tuple) => tuple.span(),
             Self::Array(array) => array.span(),
             Self::Function(function) => function.span(),
@@ -198,7 +212,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span = new_span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span = new_span,
 
             Self::Tuple(tuple) => tuple.set_span(new_span),
             Self::Array(array) => array.set_span(new_span),
@@ -261,6 +277,16 @@ impl Type {
                         }
                         Some(size) => Self::Uint(span, size),
                     }
+                } else if let Some(s) = s.strip_prefix("fixed") {
+                    match parse_fixed_size(s, span)? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Fixed(span, size),
+                    }
+                } else if let Some(s) = s.strip_prefix("ufixed") {
+                    match parse_fixed_size(s, span)? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Ufixed(span, size),
+                    }
                 } else {
                     Self::custom(ident)
                 }
@@ -286,6 +312,8 @@ impl Type {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(..)
                 | Self::Function(_)
@@ -298,6 +326,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_) => false,
@@ -349,6 +379,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::String(_)
@@ -367,6 +399,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_)
@@ -466,7 +500,7 @@ impl Type {
         } else {
             Err(input.error(
                 "expected a Solidity type: \
-                 `address`, `bool`, `string`, `bytesN`, `intN`, `uintN`, \
+                 `address`, `bool`, `string`, `bytesN`, `intN`, `uintN`, `fixedMxN`, `ufixedMxN`, \
                  `tuple`, `function`, `mapping`, or a custom type name",
             ))
         }
@@ -481,6 +515,42 @@ fn write_opt(f: &mut fmt::Formatter<'_>, name: &str, size: Option<NonZeroU16>) -
     Ok(())
 }
 
+fn write_fixed_opt(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    size: Option<(NonZeroU16, u8)>,
+) -> fmt::Result {
+    f.write_str(name)?;
+    if let Some((bits, decimals)) = size {
+        write!(f, "{bits}x{decimals}")?;
+    }
+    Ok(())
+}
+
+// None => Custom
+// Some(None) => default size
+// Some(Some((M, N))) => MxN
+fn parse_fixed_size(s: &str, span: Span) -> Result<Option<Option<(NonZeroU16, u8)>>> {
+    if s.is_empty() {
+        return Ok(Some(None));
+    }
+    let (m, n) = match s.split_once('x') {
+        Some(mn) => mn,
+        None => return Ok(None),
+    };
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_number(m) || !is_number(n) {
+        return Ok(None);
+    }
+    match (m.parse::<NonZeroU16>(), n.parse::<u8>()) {
+        (Ok(m), Ok(n)) if m.get() <= 256 && m.get() % 8 == 0 && n <= 80 => Ok(Some(Some((m, n)))),
+        _ => Err(Error::new(
+            span,
+            "fixedMxN must have M a multiple of 8 up to 256, and N at most 80",
+        )),
+    }
+}
+
 // None => Custom
 // Some(size) => size
 fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
@@ -497,3 +567,32 @@ fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
     };
     Ok(opt)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn fixed_types() {
+        let ty: Type = parse_quote!(fixed);
+        assert_eq!(ty, Type::Fixed(Span::call_site(), None));
+        assert_eq!(ty.to_string(), "fixed");
+
+        let ty: Type = parse_quote!(ufixed128x18);
+        let size = (NonZeroU16::new(128).unwrap(), 18);
+        assert_eq!(ty, Type::Ufixed(Span::call_site(), Some(size)));
+        assert_eq!(ty.to_string(), "ufixed128x18");
+        assert!(ty.is_one_word() && !ty.is_abi_dynamic());
+
+        let ty: Type = parse_quote!(fixed8x0[]);
+        assert_eq!(ty.to_string(), "fixed8x0[]");
+
+        let ty: Type = parse_quote!(fixedPoint);
+        assert!(ty.is_custom());
+
+        assert!(syn::parse_str::<Type>("fixed7x1").is_err());
+        assert!(syn::parse_str::<Type>("ufixed264x1").is_err());
+        assert!(syn::parse_str::<Type>("fixed128x81").is_err());
+    }
+}